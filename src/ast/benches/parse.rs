@@ -0,0 +1,79 @@
+//! Parsing time of the generated modules of the growing size.
+//! The parser never backtracks, so the time per statement or per nesting level
+//! should stay the same for all sizes.
+//!
+//! Run with `cargo bench -p ast --bench parse`
+
+use ast::js_ast::Module;
+use std::time::{Duration, Instant};
+
+const RUNS: usize = 5;
+/// allowed growth of the time per unit between the smallest and the largest input
+const MAX_GROWTH: f64 = 3.0;
+
+/// Statements of all kinds, including the ones recognized by the lookahead,
+/// like the object at the beginning of the statement or the arrow function
+fn statements(count: usize) -> String {
+    let mut source = String::new();
+    for i in 0..count {
+        source.push_str(&match i % 8 {
+            0 => format!("let a{i} = {i} + b * (c - {i}) / -d;\n"),
+            1 => format!("a{i} = (x, y) => x ? y : {i};\n"),
+            2 => format!("a{i} += obj.field[{i}].method(a, b);\n"),
+            3 => format!("{{ name: \"{i}\", value: {i} }} = a{i};\n"),
+            4 => format!("{{ let b{i} = a{i}++; }}\n"),
+            5 => format!("label{i}: for (let i = 0; i < {i}; i++) {{ continue label{i}; }}\n"),
+            6 => format!("if (a{i}) {{ f(a{i}); }} else {{ g(`value: ${{a{i}}}`); }}\n"),
+            _ => format!("try {{ throw {i}; }} catch (e) {{ e; }}\n"),
+        });
+    }
+    source
+}
+
+/// Callbacks passed into each other, like "f(function() { f(function() { ... }); });"
+fn nested_callbacks(depth: usize) -> String {
+    let mut source = "f(function() {\n".repeat(depth);
+    source.push_str("g();\n");
+    source.push_str(&"});\n".repeat(depth));
+    source
+}
+
+/// Returns the best of several runs
+fn measure(source: &str) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let module = Module::new("bench".to_string(), source.as_bytes());
+            let time = start.elapsed();
+            assert!(module.is_ok(), "generated module should be valid");
+            time
+        })
+        .min()
+        .expect("runs count should not be zero")
+}
+
+fn bench(name: &str, sizes: &[usize], generate: fn(usize) -> String) {
+    println!("{name}");
+    let mut per_unit = Vec::new();
+    for &size in sizes {
+        let time = measure(&generate(size));
+        let unit_time = time.as_secs_f64() / size as f64;
+        println!(
+            "{size:>10} {:>12.3} ms {:>10.3} us per unit",
+            time.as_secs_f64() * 1e3,
+            unit_time * 1e6
+        );
+        per_unit.push(unit_time);
+    }
+    let growth = per_unit[per_unit.len() - 1] / per_unit[0];
+    assert!(
+        growth < MAX_GROWTH,
+        "{name}: time per unit has grown {growth:.1} times, the parsing is not linear"
+    );
+}
+
+fn main() {
+    bench("statements", &[1_000, 4_000, 16_000, 64_000], statements);
+    // the nesting depth is limited by the stack size
+    bench("nested callbacks", &[25, 50, 100, 200], nested_callbacks);
+}
@@ -27,13 +27,13 @@ impl ArrayExpression {
                     cur_token = match reader.next_token()? {
                         Token::Separator(Separator::CloseSquareBracket) => break,
                         Token::Separator(Separator::Comma) => reader.next_token()?,
-                        token => return Err(LexerError::UnexpectedToken(token)),
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
                     };
                 }
 
                 Ok(Self { values })
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 
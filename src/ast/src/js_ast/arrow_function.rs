@@ -0,0 +1,247 @@
+use super::{
+    function_expression::precompile_anonymous_function, return_statement::ReturnStatement,
+    BlockStatement, Expression, FunctionDeclaration, Identifier, VariableExpression,
+};
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Separator, Span, Token, TokenReader};
+use std::io::Read;
+
+/// Arrow function, like "(a, b) => { ... }" or "a => a + 1"
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArrowFunction {
+    pub args: Vec<Identifier>,
+    pub body: ArrowFunctionBody,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArrowFunctionBody {
+    BlockStatement(BlockStatement),
+    /// the value of the expression is returned
+    VariableExpression(Box<VariableExpression>),
+}
+
+impl ArrowFunction {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        let args = Self::parse_args(cur_token, reader)?;
+        let body = ArrowFunctionBody::parse(reader.next_token()?, reader)?;
+        Ok(Self { args, body })
+    }
+
+    /// Parses the arrow function, if the next tokens are the arrow function arguments followed by "=>"
+    pub fn try_parse<R: Read>(
+        cur_token: &Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Option<Self>, LexerError> {
+        if Self::is_arrow_function(cur_token, reader)? {
+            Ok(Some(Self::parse(cur_token.clone(), reader)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Looks ahead without consuming tokens, if `cur_token` starts the arguments followed by "=>".
+    /// Accepts the same arguments as `FunctionDeclaration::parse_args`, including "()" and "(a, b,)"
+    fn is_arrow_function<R: Read>(
+        cur_token: &Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<bool, LexerError> {
+        let arrow = match cur_token {
+            Token::Ident(_) => 0,
+            Token::Separator(Separator::OpenBrace) => {
+                let mut n = 0;
+                loop {
+                    match reader.peek_n(n)? {
+                        Token::Separator(Separator::CloseBrace) => break,
+                        Token::Ident(_) => n += 1,
+                        _ => return Ok(false),
+                    }
+                    match reader.peek_n(n)? {
+                        Token::Separator(Separator::CloseBrace) => break,
+                        Token::Separator(Separator::Comma) => n += 1,
+                        _ => return Ok(false),
+                    }
+                }
+                n + 1
+            }
+            _ => return Ok(false),
+        };
+        Ok(matches!(
+            reader.peek_n(arrow)?,
+            Token::Separator(Separator::Arrow)
+        ))
+    }
+
+    /// Parses arguments with the following "=>", like "(a, b) =>" or "a =>"
+    fn parse_args<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Vec<Identifier>, LexerError> {
+        let args = match cur_token {
+            Token::Ident(_) => vec![Identifier::parse(cur_token, reader)?],
+            cur_token => FunctionDeclaration::parse_args(cur_token, reader)?,
+        };
+        match reader.next_token()? {
+            Token::Separator(Separator::Arrow) => Ok(args),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl ArrowFunctionBody {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Separator(Separator::OpenCurlyBrace) => Ok(Self::BlockStatement(
+                BlockStatement::parse(cur_token, reader)?,
+            )),
+            cur_token => Ok(Self::VariableExpression(
+                VariableExpression::parse(cur_token, reader)?.into(),
+            )),
+        }
+    }
+}
+
+impl ArrowFunction {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::FunctionExpression, PrecompilerError> {
+        let body = match self.body {
+            ArrowFunctionBody::BlockStatement(body) => body,
+            ArrowFunctionBody::VariableExpression(ret) => BlockStatement {
+                body: vec![Expression::ReturnStatement(ReturnStatement {
+                    ret: *ret,
+                    span: Span::default(),
+                })],
+            },
+        };
+        precompile_anonymous_function(self.args, body, precompiler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{BinaryExpType, BinaryExpression, VariableValue};
+    use lexer::Position;
+
+    #[test]
+    fn parse_arrow_function_test() {
+        let mut reader = TokenReader::new("a => a + 1".as_bytes());
+        assert_eq!(
+            ArrowFunction::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ArrowFunction {
+                args: vec!["a".to_string().into()],
+                body: ArrowFunctionBody::VariableExpression(
+                    VariableExpression::BinaryExpression(
+                        BinaryExpression {
+                            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "a".to_string().into()
+                            )),
+                            right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                            exp_type: BinaryExpType::Add,
+                            span: Span::default(),
+                        }
+                        .into()
+                    )
+                    .into()
+                ),
+            })
+        );
+
+        let mut reader = TokenReader::new("(a, b) => { return a; }".as_bytes());
+        assert_eq!(
+            ArrowFunction::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ArrowFunction {
+                args: vec!["a".to_string().into(), "b".to_string().into()],
+                body: ArrowFunctionBody::BlockStatement(BlockStatement {
+                    body: vec![Expression::ReturnStatement(ReturnStatement {
+                        ret: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        span: Span::new(Position::new(1, 13), Position::new(1, 18)),
+                    })]
+                }),
+            })
+        );
+
+        let mut reader = TokenReader::new("() => {}".as_bytes());
+        assert_eq!(
+            ArrowFunction::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ArrowFunction {
+                args: vec![],
+                body: ArrowFunctionBody::BlockStatement(BlockStatement { body: vec![] }),
+            })
+        );
+
+        let mut reader = TokenReader::new("(a + b)".as_bytes());
+        assert_eq!(
+            ArrowFunction::try_parse(&reader.next_token().unwrap(), &mut reader),
+            Ok(None)
+        );
+        assert_eq!(reader.next_token(), Ok(Token::Ident("a".to_string())));
+
+        let mut reader = TokenReader::new("(a, b,) => {}".as_bytes());
+        assert_eq!(
+            ArrowFunction::try_parse(&reader.next_token().unwrap(), &mut reader),
+            Ok(Some(ArrowFunction {
+                args: vec!["a".to_string().into(), "b".to_string().into()],
+                body: ArrowFunctionBody::BlockStatement(BlockStatement { body: vec![] }),
+            }))
+        );
+
+        let mut reader = TokenReader::new("(a, b) + 1".as_bytes());
+        assert_eq!(
+            ArrowFunction::try_parse(&reader.next_token().unwrap(), &mut reader),
+            Ok(None)
+        );
+        assert_eq!(reader.next_token(), Ok(Token::Ident("a".to_string())));
+    }
+
+    #[test]
+    fn precompile_arrow_function_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+
+        let arrow_function = ArrowFunction {
+            args: vec!["a".to_string().into()],
+            body: ArrowFunctionBody::VariableExpression(
+                VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into(),
+                ))
+                .into(),
+            ),
+        };
+
+        assert_eq!(
+            arrow_function.precompile(&mut precompiler),
+            Ok(llvm_ast::FunctionExpression {
+                name: llvm_ast::Identifier::new("function.0".to_string(), 0),
+            })
+        );
+        assert_eq!(precompiler.variables_len(), 0);
+        assert_eq!(
+            precompiler.get_function_declarations(),
+            vec![llvm_ast::FunctionDeclaration {
+                name: llvm_ast::Identifier::new("function.0".to_string(), 0),
+                args: vec![llvm_ast::Identifier::new("a".to_string(), 0)],
+                captured: vec![],
+                body: vec![llvm_ast::Expression::ReturnStatement(
+                    llvm_ast::ReturnStatement {
+                        ret: llvm_ast::VariableExpression::VariableValue(
+                            llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                "a".to_string(),
+                                0
+                            ))
+                        ),
+                        deallocations: vec![vec![]],
+                    }
+                )],
+            }]
+        );
+    }
+}
@@ -1,12 +1,21 @@
 use super::VariableExpression;
 use crate::{llvm_ast, Precompiler, PrecompilerError};
+use lexer::Span;
 use rpn::input::Priority;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct BinaryExpression {
     pub left: VariableExpression,
     pub right: VariableExpression,
     pub exp_type: BinaryExpType,
+    /// span of the operator, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for BinaryExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right && self.exp_type == other.exp_type
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,6 +25,8 @@ pub enum BinaryExpType {
     Or,
     Eq,
     Ne,
+    SEq,
+    SNe,
     Gt,
     Ge,
     Lt,
@@ -25,6 +36,15 @@ pub enum BinaryExpType {
     Sub,
     Div,
     Mul,
+    Mod,
+    Exp,
+    // Bitwise
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    UShr,
 }
 
 #[allow(clippy::from_over_into)]
@@ -36,6 +56,8 @@ impl Into<llvm_ast::BinaryExpType> for BinaryExpType {
             Self::Or => llvm_ast::BinaryExpType::Or,
             Self::Eq => llvm_ast::BinaryExpType::Eq,
             Self::Ne => llvm_ast::BinaryExpType::Ne,
+            Self::SEq => llvm_ast::BinaryExpType::SEq,
+            Self::SNe => llvm_ast::BinaryExpType::SNe,
             Self::Gt => llvm_ast::BinaryExpType::Gt,
             Self::Ge => llvm_ast::BinaryExpType::Ge,
             Self::Lt => llvm_ast::BinaryExpType::Lt,
@@ -45,6 +67,15 @@ impl Into<llvm_ast::BinaryExpType> for BinaryExpType {
             Self::Sub => llvm_ast::BinaryExpType::Sub,
             Self::Div => llvm_ast::BinaryExpType::Div,
             Self::Mul => llvm_ast::BinaryExpType::Mul,
+            Self::Mod => llvm_ast::BinaryExpType::Mod,
+            Self::Exp => llvm_ast::BinaryExpType::Exp,
+            // Bitwise
+            Self::BitAnd => llvm_ast::BinaryExpType::BitAnd,
+            Self::BitOr => llvm_ast::BinaryExpType::BitOr,
+            Self::BitXor => llvm_ast::BinaryExpType::BitXor,
+            Self::Shl => llvm_ast::BinaryExpType::Shl,
+            Self::Shr => llvm_ast::BinaryExpType::Shr,
+            Self::UShr => llvm_ast::BinaryExpType::UShr,
         }
     }
 }
@@ -53,15 +84,26 @@ impl Priority for BinaryExpType {
     fn priority(&self) -> u8 {
         match self {
             // Logical
-            BinaryExpType::Eq | BinaryExpType::Ne => 8,
+            BinaryExpType::Eq | BinaryExpType::Ne | BinaryExpType::SEq | BinaryExpType::SNe => 8,
             BinaryExpType::Gt | BinaryExpType::Ge | BinaryExpType::Lt | BinaryExpType::Le => 9,
             BinaryExpType::And => 4,
             BinaryExpType::Or => 3,
             // Arithmetic
             BinaryExpType::Add | BinaryExpType::Sub => 11,
-            BinaryExpType::Div | BinaryExpType::Mul => 12,
+            BinaryExpType::Div | BinaryExpType::Mul | BinaryExpType::Mod => 12,
+            BinaryExpType::Exp => 13,
+            // Bitwise
+            BinaryExpType::BitAnd => 7,
+            BinaryExpType::BitXor => 6,
+            BinaryExpType::BitOr => 5,
+            BinaryExpType::Shl | BinaryExpType::Shr | BinaryExpType::UShr => 10,
         }
     }
+
+    fn is_right_associative(&self) -> bool {
+        // "a ** b ** c" is "a ** (b ** c)"
+        matches!(self, BinaryExpType::Exp)
+    }
 }
 
 impl BinaryExpression {
@@ -73,6 +115,7 @@ impl BinaryExpression {
             left: self.left.precompile(precompiler)?,
             right: self.right.precompile(precompiler)?,
             exp_type: self.exp_type.into(),
+            span: self.span,
         })
     }
 }
@@ -1,4 +1,4 @@
-use super::Expression;
+use super::{DeclarationType, Expression, Identifier, VariableDeclaration};
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
 use lexer::{Separator, Token, TokenReader};
 use std::io::Read;
@@ -16,20 +16,30 @@ impl BlockStatement {
         match cur_token {
             Token::Separator(Separator::OpenCurlyBrace) => {
                 let mut body = Vec::new();
-                let mut cur_token = reader.next_token()?;
+                let mut cur_token = Expression::next_token_or_recover(reader)?;
                 loop {
-                    let expr = match cur_token {
+                    cur_token = match cur_token {
                         Token::Separator(Separator::CloseCurlyBrace) => break,
-                        cur_token => Expression::parse(cur_token, reader)?,
+                        // unclosed block
+                        Token::Eof => {
+                            return Err(LexerError::UnexpectedToken(cur_token, reader.span()))
+                        }
+                        // empty statement
+                        Token::Separator(Separator::SemiColon) => {
+                            Expression::next_token_or_recover(reader)?
+                        }
+                        cur_token => {
+                            let (expr, next_token) =
+                                Expression::parse_or_recover(cur_token, reader)?;
+                            body.extend(expr);
+                            next_token
+                        }
                     };
-
-                    cur_token = reader.next_token()?;
-                    body.push(expr);
                 }
 
                 Ok(Self { body })
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -39,8 +49,75 @@ impl BlockStatement {
         self,
         precompiler: &mut Precompiler,
     ) -> Result<Vec<llvm_ast::Expression>, PrecompilerError> {
-        let mut res = Vec::with_capacity(self.body.len());
+        self.precompile_impl(precompiler, Vec::new())
+    }
+
+    /// Precompiles the function body, all "var" declarations of the body are hoisted to its beginning.
+    /// Variables with the same names as the function arguments are not redeclared
+    pub fn precompile_function_body(
+        self,
+        args: &[Identifier],
+        precompiler: &mut Precompiler,
+    ) -> Result<Vec<llvm_ast::Expression>, PrecompilerError> {
+        let mut hoisted = Vec::new();
+        self.collect_var_declarations(&mut hoisted);
+        hoisted.retain(|name| !args.contains(name));
+        self.precompile_impl(precompiler, hoisted)
+    }
+
+    /// Declares the "let" and "const" variables at the beginning of the block, so the functions declared before them could capture them.
+    /// They are visible in the whole block, but could be used only after the declaration
+    pub(super) fn declare_block_variables<'a>(
+        body: impl Iterator<Item = &'a Expression>,
+        precompiler: &mut Precompiler,
+        res: &mut Vec<llvm_ast::Expression>,
+    ) {
+        for expr in body {
+            if let Expression::VariableDeclaration(VariableDeclaration {
+                declaration_type: declaration_type @ (DeclarationType::Let | DeclarationType::Const),
+                name,
+                ..
+            }) = expr
+            {
+                let index = precompiler.declare_uninitialized_variable(
+                    name.clone(),
+                    *declaration_type == DeclarationType::Const,
+                );
+                let name = name.clone();
+                res.push(llvm_ast::Expression::VariableDeclaration(
+                    llvm_ast::VariableDeclaration {
+                        name: llvm_ast::Identifier::with_span(name.name, index, name.span),
+                        value: None,
+                    },
+                ));
+            }
+        }
+    }
+
+    pub(super) fn collect_var_declarations(&self, names: &mut Vec<Identifier>) {
+        for expr in &self.body {
+            expr.collect_var_declarations(names);
+        }
+    }
+
+    fn precompile_impl(
+        self,
+        precompiler: &mut Precompiler,
+        hoisted: Vec<Identifier>,
+    ) -> Result<Vec<llvm_ast::Expression>, PrecompilerError> {
+        let mut res = Vec::with_capacity(self.body.len() + hoisted.len());
         let variables_len = precompiler.variables_len();
+        for name in hoisted {
+            let index = precompiler.insert_variable(name.clone());
+            res.push(llvm_ast::Expression::VariableDeclaration(
+                llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::with_span(name.name, index, name.span),
+                    value: None,
+                },
+            ));
+        }
+        Self::declare_block_variables(self.body.iter(), precompiler, &mut res);
+
         for expr in self.body {
             expr.precompile(precompiler)?
                 .into_iter()
@@ -51,7 +128,7 @@ impl BlockStatement {
         for (var, index) in vars {
             res.push(llvm_ast::Expression::DeallocateExpression(
                 llvm_ast::DeallocateExpression {
-                    name: llvm_ast::Identifier::new(var.name, index),
+                    name: llvm_ast::Identifier::with_span(var.name, index, var.span),
                 },
             ));
         }
@@ -63,9 +140,9 @@ impl BlockStatement {
 mod tests {
     use super::*;
     use crate::js_ast::{
-        FunctionDeclaration, VariableAssigment, VariableDeclaration, VariableExpression,
-        VariableValue,
+        FunctionDeclaration, VariableAssigment, VariableExpression, VariableValue,
     };
+    use lexer::{Position, Span};
 
     #[test]
     fn parse_block_statement_test() {
@@ -85,7 +162,8 @@ mod tests {
                     )),
                     right: VariableExpression::VariableValue(VariableValue::Identifier(
                         "name2".to_string().into()
-                    ))
+                    )),
+                    span: Span::default(),
                 })]
             })
         );
@@ -103,7 +181,8 @@ mod tests {
                         )),
                         right: VariableExpression::VariableValue(VariableValue::Identifier(
                             "name2".to_string().into()
-                        ))
+                        )),
+                        span: Span::default(),
                     }),
                     Expression::BlockStatement(BlockStatement {
                         body: vec![
@@ -113,7 +192,8 @@ mod tests {
                                 )),
                                 right: VariableExpression::VariableValue(
                                     VariableValue::Identifier("name2".to_string().into())
-                                )
+                                ),
+                                span: Span::default(),
                             }),
                             Expression::VariableAssigment(VariableAssigment {
                                 left: VariableExpression::VariableValue(VariableValue::Identifier(
@@ -121,7 +201,8 @@ mod tests {
                                 )),
                                 right: VariableExpression::VariableValue(
                                     VariableValue::Identifier("name2".to_string().into())
-                                )
+                                ),
+                                span: Span::default(),
                             }),
                         ]
                     })
@@ -130,12 +211,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_block_statement_recovery_test() {
+        // the erroneous statement is skipped, the parsing continues from the next one
+        let mut reader = TokenReader::new("{ let = 1; name1 = name2; }".as_bytes());
+        assert_eq!(
+            BlockStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(BlockStatement {
+                body: vec![Expression::VariableAssigment(VariableAssigment {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "name1".to_string().into()
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "name2".to_string().into()
+                    )),
+                    span: Span::default(),
+                })]
+            })
+        );
+        assert_eq!(
+            reader.take_errors(),
+            vec![LexerError::UnexpectedToken(
+                Token::Assign,
+                Span::new(Position::new(1, 7), Position::new(1, 7))
+            )]
+        );
+
+        // unclosed block
+        let mut reader = TokenReader::new("{ name1 = name2;".as_bytes());
+        assert_eq!(
+            BlockStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Eof,
+                Span::new(Position::new(1, 16), Position::new(1, 16))
+            ))
+        );
+    }
+
     #[test]
     fn precompile_block_statement_test_1() {
         let mut precompiler = Precompiler::new(std::iter::empty());
         assert_eq!(precompiler.variables_len(), 0);
         let block_statement = BlockStatement {
             body: vec![Expression::VariableDeclaration(VariableDeclaration {
+                declaration_type: DeclarationType::Let,
                 name: "name_1".to_string().into(),
                 value: Some(VariableExpression::VariableValue(VariableValue::Number(
                     64_f64,
@@ -148,9 +267,19 @@ mod tests {
             Ok(vec![
                 llvm_ast::Expression::VariableDeclaration(llvm_ast::VariableDeclaration {
                     name: llvm_ast::Identifier::new("name_1".to_string(), 0),
-                    value: Some(llvm_ast::VariableExpression::VariableValue(
+                    value: None,
+                }),
+                llvm_ast::Expression::VariableAssigment(llvm_ast::VariableAssigment {
+                    left: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_1".to_string(),
+                            0
+                        ))
+                    ),
+                    right: llvm_ast::VariableExpression::VariableValue(
                         llvm_ast::VariableValue::FloatNumber(64_f64)
-                    )),
+                    ),
+                    span: Span::default(),
                 }),
                 llvm_ast::Expression::DeallocateExpression(llvm_ast::DeallocateExpression {
                     name: llvm_ast::Identifier::new("name_1".to_string(), 0)
@@ -190,4 +319,110 @@ mod tests {
         assert_eq!(precompiler.variables_len(), 0);
         assert_eq!(precompiler.get_function_declarations().len(), 1);
     }
+
+    #[test]
+    fn precompile_block_statement_test_3() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+
+        // "var" declared in the nested block is hoisted
+        let block_statement = BlockStatement {
+            body: vec![
+                Expression::BlockStatement(BlockStatement {
+                    body: vec![Expression::VariableDeclaration(VariableDeclaration {
+                        declaration_type: DeclarationType::Var,
+                        name: "name_1".to_string().into(),
+                        value: Some(VariableExpression::VariableValue(VariableValue::Number(
+                            64_f64,
+                        ))),
+                    })],
+                }),
+                Expression::VariableDeclaration(VariableDeclaration {
+                    declaration_type: DeclarationType::Let,
+                    name: "name_2".to_string().into(),
+                    value: Some(VariableExpression::VariableValue(
+                        VariableValue::Identifier("name_1".to_string().into()),
+                    )),
+                }),
+            ],
+        };
+
+        assert_eq!(
+            block_statement.precompile_function_body(&[], &mut precompiler),
+            Ok(vec![
+                llvm_ast::Expression::VariableDeclaration(llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    value: None,
+                }),
+                llvm_ast::Expression::VariableDeclaration(llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_2".to_string(), 0),
+                    value: None,
+                }),
+                llvm_ast::Expression::VariableAssigment(llvm_ast::VariableAssigment {
+                    left: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_1".to_string(),
+                            0
+                        ))
+                    ),
+                    right: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(64_f64)
+                    ),
+                    span: Span::default(),
+                }),
+                llvm_ast::Expression::VariableAssigment(llvm_ast::VariableAssigment {
+                    left: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_2".to_string(),
+                            0
+                        ))
+                    ),
+                    right: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_1".to_string(),
+                            0
+                        ))
+                    ),
+                    span: Span::default(),
+                }),
+                llvm_ast::Expression::DeallocateExpression(llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::new("name_2".to_string(), 0)
+                }),
+                llvm_ast::Expression::DeallocateExpression(llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0)
+                })
+            ])
+        );
+        assert_eq!(precompiler.variables_len(), 0);
+    }
+
+    #[test]
+    fn precompile_block_statement_error_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("name_1".to_string().into());
+
+        // "let" variable hides the outer one before the declaration
+        let block_statement = BlockStatement {
+            body: vec![
+                Expression::VariableAssigment(VariableAssigment {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "name_1".to_string().into(),
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Number(64_f64)),
+                    span: Span::default(),
+                }),
+                Expression::VariableDeclaration(VariableDeclaration {
+                    declaration_type: DeclarationType::Let,
+                    name: "name_1".to_string().into(),
+                    value: None,
+                }),
+            ],
+        };
+
+        assert_eq!(
+            block_statement.precompile(&mut precompiler),
+            Err(precompiler::Error::UninitializedVariable(
+                "name_1".to_string().into()
+            ))
+        );
+    }
 }
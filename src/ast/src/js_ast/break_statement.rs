@@ -0,0 +1,166 @@
+use super::Identifier;
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Span, Token, TokenReader};
+use std::io::Read;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreakStatement {
+    pub label: Option<Identifier>,
+    /// span of the "break" keyword
+    pub span: Span,
+}
+
+impl BreakStatement {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Break) => {
+                let span = reader.span();
+                let label = match reader.peek()? {
+                    Token::Ident(_) => Some(Identifier::parse(reader.next_token()?, reader)?),
+                    _ => None,
+                };
+                Ok(Self { label, span })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl BreakStatement {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::BreakStatement, PrecompilerError> {
+        let depth = precompiler.get_loop_depth(self.label, self.span)?;
+        // all variables of the exited blocks should be deallocated,
+        // the ones declared outside of the crossed try blocks after their finally blocks
+        let deallocations = precompiler
+            .get_loop_scope_variables(depth)
+            .into_iter()
+            .map(|variables| {
+                variables
+                    .into_iter()
+                    .map(|(var, index)| llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(llvm_ast::BreakStatement {
+            depth,
+            deallocations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lexer::Position;
+
+    #[test]
+    fn parse_break_statement_test() {
+        let mut reader = TokenReader::new("break;".as_bytes());
+        assert_eq!(
+            BreakStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(BreakStatement {
+                label: None,
+                span: Span::new(Position::new(1, 1), Position::new(1, 5)),
+            })
+        );
+
+        let mut reader = TokenReader::new("break label;".as_bytes());
+        assert_eq!(
+            BreakStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(BreakStatement {
+                label: Some("label".to_string().into()),
+                span: Span::new(Position::new(1, 1), Position::new(1, 5)),
+            })
+        );
+    }
+
+    #[test]
+    fn precompile_break_statement_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        assert_eq!(
+            BreakStatement {
+                label: None,
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Err(PrecompilerError::OutsideOfLoop(Span::default()))
+        );
+
+        precompiler.enter_loop_scope(Some("label".to_string().into()));
+        precompiler.insert_variable("a".to_string().into());
+        precompiler.enter_loop_scope(None);
+        precompiler.insert_variable("b".to_string().into());
+
+        assert_eq!(
+            BreakStatement {
+                label: None,
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Ok(llvm_ast::BreakStatement {
+                depth: 0,
+                deallocations: vec![vec![llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::new("b".to_string(), 0),
+                }]],
+            })
+        );
+        assert_eq!(
+            BreakStatement {
+                label: Some("label".to_string().into()),
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Ok(llvm_ast::BreakStatement {
+                depth: 1,
+                deallocations: vec![vec![
+                    llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("b".to_string(), 0),
+                    },
+                    llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("a".to_string(), 0),
+                    },
+                ]],
+            })
+        );
+        assert_eq!(
+            BreakStatement {
+                label: Some("unknown".to_string().into()),
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Err(PrecompilerError::UndefinedLabel(
+                "unknown".to_string().into()
+            ))
+        );
+
+        // variables declared outside of the try block are deallocated after the finally block
+        precompiler.enter_try_scope(true);
+        precompiler.insert_variable("c".to_string().into());
+        assert_eq!(
+            BreakStatement {
+                label: None,
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Ok(llvm_ast::BreakStatement {
+                depth: 0,
+                deallocations: vec![
+                    vec![llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("c".to_string(), 0),
+                    }],
+                    vec![llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("b".to_string(), 0),
+                    }],
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use super::VariableExpression;
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Arithmetic, Logical, Span, Token, TokenReader};
+use std::io::Read;
+
+/// Compound assignment, like "a += 1" or "a ||= b"
+#[derive(Clone, Debug)]
+pub struct CompoundAssigment {
+    pub left: VariableExpression,
+    pub right: VariableExpression,
+    pub exp_type: CompoundAssigmentType,
+    /// span of the operator, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for CompoundAssigment {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right && self.exp_type == other.exp_type
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompoundAssigmentType {
+    // Arithmetic
+    Add,
+    Sub,
+    Mul,
+    Div,
+    // Logical
+    And,
+    Or,
+    Nullish,
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<llvm_ast::CompoundAssigmentType> for CompoundAssigmentType {
+    fn into(self) -> llvm_ast::CompoundAssigmentType {
+        match self {
+            // Arithmetic
+            Self::Add => llvm_ast::CompoundAssigmentType::Add,
+            Self::Sub => llvm_ast::CompoundAssigmentType::Sub,
+            Self::Mul => llvm_ast::CompoundAssigmentType::Mul,
+            Self::Div => llvm_ast::CompoundAssigmentType::Div,
+            // Logical
+            Self::And => llvm_ast::CompoundAssigmentType::And,
+            Self::Or => llvm_ast::CompoundAssigmentType::Or,
+            Self::Nullish => llvm_ast::CompoundAssigmentType::Nullish,
+        }
+    }
+}
+
+impl CompoundAssigmentType {
+    /// Returns the type of the compound assignment operator token
+    pub fn parse(token: &Token) -> Option<Self> {
+        match token {
+            // Arithmetic
+            Token::Arithmetic(Arithmetic::AddAssign) => Some(Self::Add),
+            Token::Arithmetic(Arithmetic::SubAssign) => Some(Self::Sub),
+            Token::Arithmetic(Arithmetic::MulAssign) => Some(Self::Mul),
+            Token::Arithmetic(Arithmetic::DivAssign) => Some(Self::Div),
+            // Logical
+            Token::Logical(Logical::AndAssign) => Some(Self::And),
+            Token::Logical(Logical::OrAssign) => Some(Self::Or),
+            Token::Logical(Logical::NullishAssign) => Some(Self::Nullish),
+            _ => None,
+        }
+    }
+}
+
+impl CompoundAssigment {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        let left = VariableExpression::parse(cur_token, reader)?;
+        Self::parse_rest(left, reader)
+    }
+
+    /// Parses the "+= b" part after the already parsed `left`
+    pub fn parse_rest<R: Read>(
+        left: VariableExpression,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        let token = reader.next_token()?;
+        let Some(exp_type) = CompoundAssigmentType::parse(&token) else {
+            return Err(LexerError::UnexpectedToken(token, reader.span()));
+        };
+        let span = reader.span();
+        let right = VariableExpression::parse(reader.next_token()?, reader)?;
+        Ok(Self {
+            left,
+            right,
+            exp_type,
+            span,
+        })
+    }
+}
+
+impl CompoundAssigment {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::CompoundAssigment, PrecompilerError> {
+        let left = self
+            .left
+            .precompile_assigment_target(precompiler, self.span.clone())?;
+        let right = self.right.precompile(precompiler)?;
+        Ok(llvm_ast::CompoundAssigment {
+            left,
+            right,
+            exp_type: self.exp_type.into(),
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{
+        member_expression::PropertyType, BinaryExpType, BinaryExpression, MemberExpression,
+        Property, VariableValue,
+    };
+    use lexer::Position;
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn parse_compound_assigment_test() {
+        let mut reader = TokenReader::new("a += b".as_bytes());
+        let compound_assigment =
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader).unwrap();
+        assert_eq!(
+            compound_assigment.span,
+            Span::new(Position::new(1, 3), Position::new(1, 4))
+        );
+
+        let mut reader = TokenReader::new("a += 1;".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                exp_type: CompoundAssigmentType::Add,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a -= b * 2".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                right: VariableExpression::BinaryExpression(
+                    BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                        exp_type: BinaryExpType::Mul,
+                        span: Span::default(),
+                    }
+                    .into()
+                ),
+                exp_type: CompoundAssigmentType::Sub,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a[i] *= 2".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::MemberExpression(
+                    MemberExpression {
+                        object: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        property: Property {
+                            object: PropertyType::VariableExpression(
+                                VariableExpression::VariableValue(VariableValue::Identifier(
+                                    "i".to_string().into()
+                                ))
+                            ),
+                            property: None,
+                        },
+                        span: Span::default(),
+                    }
+                    .into()
+                ),
+                right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                exp_type: CompoundAssigmentType::Mul,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a /= 2".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                exp_type: CompoundAssigmentType::Div,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a &&= b".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                right: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "b".to_string().into()
+                )),
+                exp_type: CompoundAssigmentType::And,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a ||= b".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                right: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "b".to_string().into()
+                )),
+                exp_type: CompoundAssigmentType::Or,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a ??= b".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(CompoundAssigment {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                right: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "b".to_string().into()
+                )),
+                exp_type: CompoundAssigmentType::Nullish,
+                span: Span::default(),
+            })
+        );
+
+        let mut reader = TokenReader::new("a = b".as_bytes());
+        assert_eq!(
+            CompoundAssigment::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Assign,
+                Span::new(Position::new(1, 3), Position::new(1, 3))
+            ))
+        );
+    }
+
+    #[test]
+    fn precompile_compound_assigment_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+
+        let compound_assigment = CompoundAssigment {
+            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                "a".to_string().into(),
+            )),
+            right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+            exp_type: CompoundAssigmentType::Add,
+            span: Span::default(),
+        };
+        assert_eq!(
+            compound_assigment.precompile(&mut precompiler),
+            Ok(llvm_ast::CompoundAssigment {
+                left: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "a".to_string(),
+                        0
+                    ))
+                ),
+                right: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::FloatNumber(1_f64)
+                ),
+                exp_type: llvm_ast::CompoundAssigmentType::Add,
+                span: Span::default(),
+            })
+        );
+
+        let compound_assigment = CompoundAssigment {
+            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                "b".to_string().into(),
+            )),
+            right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+            exp_type: CompoundAssigmentType::Or,
+            span: Span::default(),
+        };
+        // undefined variable is collected, so the precompilation continues
+        assert_eq!(
+            compound_assigment.precompile(&mut precompiler),
+            Ok(llvm_ast::CompoundAssigment {
+                left: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "b".to_string(),
+                        0
+                    ))
+                ),
+                right: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::FloatNumber(1_f64)
+                ),
+                exp_type: llvm_ast::CompoundAssigmentType::Or,
+                span: Span::default(),
+            })
+        );
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![precompiler::Error::UndefinedVariable(
+                "b".to_string().into()
+            )]
+        );
+    }
+}
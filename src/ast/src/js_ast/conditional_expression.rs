@@ -0,0 +1,214 @@
+use super::VariableExpression;
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Separator, Span, Token, TokenReader};
+use std::io::Read;
+
+/// Conditional expression, like "a ? b : c"
+#[derive(Clone, Debug)]
+pub struct ConditionalExpression {
+    pub test: VariableExpression,
+    pub consequent: VariableExpression,
+    pub alternate: VariableExpression,
+    /// span of the "?" token, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for ConditionalExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.test == other.test
+            && self.consequent == other.consequent
+            && self.alternate == other.alternate
+    }
+}
+
+impl ConditionalExpression {
+    /// Parses the "? b : c" part after the already parsed `test`, returns `test` as is if there is no "?".
+    /// Both branches are parsed as the whole expressions, so the operator has the lowest priority and
+    /// "a ? b : c ? d : e" is parsed as "a ? b : (c ? d : e)"
+    pub fn parse_rest<R: Read>(
+        test: VariableExpression,
+        reader: &mut TokenReader<R>,
+    ) -> Result<VariableExpression, LexerError> {
+        match reader.peek()? {
+            Token::Separator(Separator::QuestionMark) => {
+                reader.next_token()?;
+                let span = reader.span();
+                let consequent = VariableExpression::parse(reader.next_token()?, reader)?;
+                match reader.next_token()? {
+                    Token::Separator(Separator::Colon) => {}
+                    token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+                }
+                let alternate = VariableExpression::parse(reader.next_token()?, reader)?;
+                Ok(VariableExpression::ConditionalExpression(
+                    Self {
+                        test,
+                        consequent,
+                        alternate,
+                        span,
+                    }
+                    .into(),
+                ))
+            }
+            _ => Ok(test),
+        }
+    }
+}
+
+impl ConditionalExpression {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::ConditionalExpression, PrecompilerError> {
+        Ok(llvm_ast::ConditionalExpression {
+            test: self.test.precompile(precompiler)?,
+            consequent: self.consequent.precompile(precompiler)?,
+            alternate: self.alternate.precompile(precompiler)?,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{BinaryExpType, BinaryExpression, VariableValue};
+    use lexer::Position;
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn parse_conditional_expression_test() {
+        let mut reader = TokenReader::new("a || b ? c : d + 1".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::ConditionalExpression(
+                ConditionalExpression {
+                    test: VariableExpression::BinaryExpression(
+                        BinaryExpression {
+                            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "a".to_string().into()
+                            )),
+                            right: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "b".to_string().into()
+                            )),
+                            exp_type: BinaryExpType::Or,
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    consequent: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "c".to_string().into()
+                    )),
+                    alternate: VariableExpression::BinaryExpression(
+                        BinaryExpression {
+                            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "d".to_string().into()
+                            )),
+                            right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                            exp_type: BinaryExpType::Add,
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    span: Span::default(),
+                }
+                .into()
+            ))
+        );
+
+        let mut reader = TokenReader::new("a ? b : c".as_bytes());
+        let VariableExpression::ConditionalExpression(expr) =
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader).unwrap()
+        else {
+            panic!("the conditional expression is expected");
+        };
+        assert_eq!(
+            expr.span,
+            Span::new(Position::new(1, 3), Position::new(1, 3))
+        );
+
+        // right associativity
+        let mut reader = TokenReader::new("a ? b ? c : d : e ? f : g".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::ConditionalExpression(
+                ConditionalExpression {
+                    test: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    consequent: VariableExpression::ConditionalExpression(
+                        ConditionalExpression {
+                            test: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "b".to_string().into()
+                            )),
+                            consequent: VariableExpression::VariableValue(
+                                VariableValue::Identifier("c".to_string().into())
+                            ),
+                            alternate: VariableExpression::VariableValue(
+                                VariableValue::Identifier("d".to_string().into())
+                            ),
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    alternate: VariableExpression::ConditionalExpression(
+                        ConditionalExpression {
+                            test: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "e".to_string().into()
+                            )),
+                            consequent: VariableExpression::VariableValue(
+                                VariableValue::Identifier("f".to_string().into())
+                            ),
+                            alternate: VariableExpression::VariableValue(
+                                VariableValue::Identifier("g".to_string().into())
+                            ),
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    span: Span::default(),
+                }
+                .into()
+            ))
+        );
+
+        // conditional expression inside the braces
+        let mut reader = TokenReader::new("(a ? b : c) + d".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(
+                BinaryExpression {
+                    left: VariableExpression::ConditionalExpression(
+                        ConditionalExpression {
+                            test: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "a".to_string().into()
+                            )),
+                            consequent: VariableExpression::VariableValue(
+                                VariableValue::Identifier("b".to_string().into())
+                            ),
+                            alternate: VariableExpression::VariableValue(
+                                VariableValue::Identifier("c".to_string().into())
+                            ),
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    right: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "d".to_string().into()
+                    )),
+                    exp_type: BinaryExpType::Add,
+                    span: Span::default(),
+                }
+                .into()
+            ))
+        );
+
+        let mut reader = TokenReader::new("a ? b ; c".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Separator(Separator::SemiColon),
+                Span::new(Position::new(1, 7), Position::new(1, 7))
+            ))
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use super::Identifier;
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Span, Token, TokenReader};
+use std::io::Read;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinueStatement {
+    pub label: Option<Identifier>,
+    /// span of the "continue" keyword
+    pub span: Span,
+}
+
+impl ContinueStatement {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Continue) => {
+                let span = reader.span();
+                let label = match reader.peek()? {
+                    Token::Ident(_) => Some(Identifier::parse(reader.next_token()?, reader)?),
+                    _ => None,
+                };
+                Ok(Self { label, span })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl ContinueStatement {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::ContinueStatement, PrecompilerError> {
+        let depth = precompiler.get_continue_depth(self.label, self.span)?;
+        // all variables of the exited blocks should be deallocated,
+        // the ones declared outside of the crossed try blocks after their finally blocks
+        let deallocations = precompiler
+            .get_loop_scope_variables(depth)
+            .into_iter()
+            .map(|variables| {
+                variables
+                    .into_iter()
+                    .map(|(var, index)| llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(llvm_ast::ContinueStatement {
+            depth,
+            deallocations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lexer::Position;
+
+    #[test]
+    fn parse_continue_statement_test() {
+        let mut reader = TokenReader::new("continue;".as_bytes());
+        assert_eq!(
+            ContinueStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ContinueStatement {
+                label: None,
+                span: Span::new(Position::new(1, 1), Position::new(1, 8)),
+            })
+        );
+
+        let mut reader = TokenReader::new("continue label;".as_bytes());
+        assert_eq!(
+            ContinueStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ContinueStatement {
+                label: Some("label".to_string().into()),
+                span: Span::new(Position::new(1, 1), Position::new(1, 8)),
+            })
+        );
+    }
+
+    #[test]
+    fn precompile_continue_statement_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        assert_eq!(
+            ContinueStatement {
+                label: None,
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Err(PrecompilerError::OutsideOfLoop(Span::default()))
+        );
+
+        precompiler.enter_loop_scope(Some("label".to_string().into()));
+        precompiler.insert_variable("a".to_string().into());
+        precompiler.enter_loop_scope(None);
+        precompiler.insert_variable("b".to_string().into());
+
+        assert_eq!(
+            ContinueStatement {
+                label: None,
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Ok(llvm_ast::ContinueStatement {
+                depth: 0,
+                deallocations: vec![vec![llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::new("b".to_string(), 0),
+                }]],
+            })
+        );
+        assert_eq!(
+            ContinueStatement {
+                label: Some("label".to_string().into()),
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Ok(llvm_ast::ContinueStatement {
+                depth: 1,
+                deallocations: vec![vec![
+                    llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("b".to_string(), 0),
+                    },
+                    llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("a".to_string(), 0),
+                    },
+                ]],
+            })
+        );
+        assert_eq!(
+            ContinueStatement {
+                label: Some("unknown".to_string().into()),
+                span: Span::default(),
+            }
+            .precompile(&mut precompiler),
+            Err(PrecompilerError::UndefinedLabel(
+                "unknown".to_string().into()
+            ))
+        );
+    }
+}
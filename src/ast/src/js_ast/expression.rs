@@ -1,6 +1,9 @@
 use super::{
-    return_statement::ReturnStatement, BlockStatement, DoWhileLoop, FunctionDeclaration,
-    IfElseStatement, VariableAssigment, VariableDeclaration, VariableExpression, WhileLoop,
+    return_statement::ReturnStatement, BlockStatement, BreakStatement, CompoundAssigment,
+    CompoundAssigmentType, ContinueStatement, DeclarationType, DoWhileLoop, ForLoop,
+    FunctionDeclaration, Identifier, IfElseStatement, LabeledStatement, SwitchStatement,
+    ThrowStatement, TryStatement, VariableAssigment, VariableDeclaration, VariableExpression,
+    WhileLoop,
 };
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
 use lexer::{Keyword, Separator, Token, TokenReader};
@@ -11,43 +14,53 @@ pub enum Expression {
     FunctionDeclaration(FunctionDeclaration),
     VariableDeclaration(VariableDeclaration),
     VariableAssigment(VariableAssigment),
+    CompoundAssigment(CompoundAssigment),
     VariableExpression(VariableExpression),
     BlockStatement(BlockStatement),
     IfElseStatement(IfElseStatement),
     WhileLoop(WhileLoop),
     DoWhileLoop(DoWhileLoop),
+    ForLoop(ForLoop),
+    LabeledStatement(LabeledStatement),
+    BreakStatement(BreakStatement),
+    ContinueStatement(ContinueStatement),
     ReturnStatement(ReturnStatement),
+    ThrowStatement(ThrowStatement),
+    TryStatement(TryStatement),
+    SwitchStatement(SwitchStatement),
 }
 
 impl Expression {
     pub fn parse<R: Read>(
         cur_token: Token,
         reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        let res = Self::parse_impl(cur_token, reader)?;
+        // skip optional ";" after the statement
+        if let Token::Separator(Separator::SemiColon) = reader.peek()? {
+            reader.next_token()?;
+        }
+        Ok(res)
+    }
+
+    fn parse_impl<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
         match cur_token {
             Token::Keyword(Keyword::Function) => Ok(Self::FunctionDeclaration(
                 FunctionDeclaration::parse(cur_token, reader)?,
             )),
-            Token::Keyword(Keyword::Var | Keyword::Let) => Ok(Self::VariableDeclaration(
-                VariableDeclaration::parse(cur_token, reader)?,
-            )),
+            Token::Keyword(Keyword::Var | Keyword::Let | Keyword::Const) => Ok(
+                Self::VariableDeclaration(VariableDeclaration::parse(cur_token, reader)?),
+            ),
             Token::Separator(Separator::OpenCurlyBrace) => {
-                reader.start_saving();
-                if let Ok(res) = BlockStatement::parse(cur_token.clone(), reader) {
-                    reader.reset_saving();
-                    Ok(Self::BlockStatement(res))
+                if Self::is_object_start(reader)? {
+                    Self::parse_assigment_or_expression(cur_token, reader)
                 } else {
-                    reader.stop_saving();
-                    reader.start_saving();
-                    if let Ok(res) = VariableAssigment::parse(cur_token.clone(), reader) {
-                        reader.reset_saving();
-                        Ok(Self::VariableAssigment(res))
-                    } else {
-                        reader.stop_saving();
-                        Ok(Self::VariableExpression(VariableExpression::parse(
-                            cur_token, reader,
-                        )?))
-                    }
+                    Ok(Self::BlockStatement(BlockStatement::parse(
+                        cur_token, reader,
+                    )?))
                 }
             }
             Token::Keyword(Keyword::If) => Ok(Self::IfElseStatement(IfElseStatement::parse(
@@ -59,23 +72,165 @@ impl Expression {
             Token::Keyword(Keyword::Do) => {
                 Ok(Self::DoWhileLoop(DoWhileLoop::parse(cur_token, reader)?))
             }
+            Token::Keyword(Keyword::For) => Ok(Self::ForLoop(ForLoop::parse(cur_token, reader)?)),
+            Token::Keyword(Keyword::Break) => Ok(Self::BreakStatement(BreakStatement::parse(
+                cur_token, reader,
+            )?)),
+            Token::Keyword(Keyword::Continue) => Ok(Self::ContinueStatement(
+                ContinueStatement::parse(cur_token, reader)?,
+            )),
             Token::Keyword(Keyword::Return) => Ok(Self::ReturnStatement(ReturnStatement::parse(
                 cur_token, reader,
             )?)),
-            cur_token => {
-                reader.start_saving();
-                if let Ok(res) = VariableAssigment::parse(cur_token.clone(), reader) {
-                    reader.reset_saving();
-                    Ok(Self::VariableAssigment(res))
-                } else {
-                    reader.stop_saving();
-                    Ok(Self::VariableExpression(VariableExpression::parse(
+            Token::Keyword(Keyword::Throw) => Ok(Self::ThrowStatement(ThrowStatement::parse(
+                cur_token, reader,
+            )?)),
+            Token::Keyword(Keyword::Try) => {
+                Ok(Self::TryStatement(TryStatement::parse(cur_token, reader)?))
+            }
+            Token::Keyword(Keyword::Switch) => Ok(Self::SwitchStatement(SwitchStatement::parse(
+                cur_token, reader,
+            )?)),
+            Token::Ident(_) => {
+                // "label:" could be only before the loop
+                if let Token::Separator(Separator::Colon) = reader.peek()? {
+                    Ok(Self::LabeledStatement(LabeledStatement::parse(
                         cur_token, reader,
                     )?))
+                } else {
+                    Self::parse_assigment_or_expression(cur_token, reader)
+                }
+            }
+            cur_token => Self::parse_assigment_or_expression(cur_token, reader),
+        }
+    }
+
+    /// Parses the statement inside the statements list, like the module or block body,
+    /// and returns it together with the next token.
+    /// Syntax error is recorded by the reader and the statement is skipped, so the parsing continues
+    /// and all errors are reported at once. Only the reader errors are returned
+    pub fn parse_or_recover<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<(Option<Self>, Token), LexerError> {
+        match Self::parse(cur_token, reader) {
+            Ok(res) => Ok((Some(res), Self::next_token_or_recover(reader)?)),
+            Err(e @ LexerError::ReaderError(_)) => Err(e),
+            Err(e) => {
+                let token = match &e {
+                    LexerError::UnexpectedToken(token, _) => Some(token.clone()),
+                    _ => None,
+                };
+                reader.add_error(e);
+                Ok((None, Self::synchronize(token, reader)?))
+            }
+        }
+    }
+
+    /// Returns the next token, invalid tokens are recorded by the reader and skipped
+    pub fn next_token_or_recover<R: Read>(
+        reader: &mut TokenReader<R>,
+    ) -> Result<Token, LexerError> {
+        loop {
+            match reader.next_token() {
+                Err(e @ LexerError::ReaderError(_)) => return Err(e),
+                Err(e) => reader.add_error(e),
+                res => return res,
+            }
+        }
+    }
+
+    /// Skips the rest of the erroneous statement, starting from the `unexpected` token, if it is provided.
+    /// Returns the token after the ";", the "}" of the enclosing block, the end of file
+    /// or the keyword starting the next statement, the parsing continues from it
+    fn synchronize<R: Read>(
+        mut unexpected: Option<Token>,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Token, LexerError> {
+        // curly braces opened inside the skipped statement
+        let mut depth = 0_u32;
+        loop {
+            let is_unexpected = unexpected.is_some();
+            let token = match unexpected.take() {
+                Some(token) => token,
+                None => match reader.next_token() {
+                    Ok(token) => token,
+                    Err(e @ LexerError::ReaderError(_)) => return Err(e),
+                    // errors of the skipped tokens are not reported
+                    Err(_) => continue,
+                },
+            };
+            if token == Token::Eof {
+                return Ok(token);
+            }
+            match token {
+                Token::Separator(Separator::SemiColon) if depth == 0 => {
+                    return Self::next_token_or_recover(reader)
+                }
+                Token::Separator(Separator::CloseCurlyBrace)
+                | Token::Keyword(
+                    Keyword::Function
+                    | Keyword::Var
+                    | Keyword::Let
+                    | Keyword::Const
+                    | Keyword::If
+                    | Keyword::While
+                    | Keyword::Do
+                    | Keyword::For
+                    | Keyword::Break
+                    | Keyword::Continue
+                    | Keyword::Return
+                    | Keyword::Throw
+                    | Keyword::Try
+                    | Keyword::Switch,
+                ) if depth == 0 => return Ok(token),
+                // the next switch case, unless it is the misplaced one
+                Token::Keyword(Keyword::Case | Keyword::Default)
+                    if depth == 0 && !is_unexpected =>
+                {
+                    return Ok(token)
                 }
+                Token::Separator(Separator::OpenCurlyBrace) => depth += 1,
+                Token::Separator(Separator::CloseCurlyBrace) => depth -= 1,
+                _ => {}
             }
         }
     }
+
+    /// Parses the assignment, compound assignment or expression statement
+    pub(super) fn parse_assigment_or_expression<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        let left = VariableExpression::parse(cur_token, reader)?;
+        let token = reader.peek()?;
+        if let Token::Assign = token {
+            Ok(Self::VariableAssigment(VariableAssigment::parse_rest(
+                left, reader,
+            )?))
+        } else if CompoundAssigmentType::parse(token).is_some() {
+            Ok(Self::CompoundAssigment(CompoundAssigment::parse_rest(
+                left, reader,
+            )?))
+        } else {
+            Ok(Self::VariableExpression(left))
+        }
+    }
+
+    /// Checks if "{" at the beginning of the statement starts the object, like "{ a: 1 } = b",
+    /// rather than the block. It is the object, if the first key is followed by ":",
+    /// which is not the label of the loop
+    fn is_object_start<R: Read>(reader: &mut TokenReader<R>) -> Result<bool, LexerError> {
+        if !matches!(reader.peek_n(0)?, Token::Ident(_))
+            || !matches!(reader.peek_n(1)?, Token::Separator(Separator::Colon))
+        {
+            return Ok(false);
+        }
+        Ok(!matches!(
+            reader.peek_n(2)?,
+            Token::Keyword(Keyword::While | Keyword::Do | Keyword::For)
+        ))
+    }
 }
 
 impl Expression {
@@ -93,15 +248,18 @@ impl Expression {
                 )])
             }
             Self::VariableDeclaration(variable_declaration) => {
-                Ok(vec![llvm_ast::Expression::VariableDeclaration(
-                    variable_declaration.precompile(precompiler)?,
-                )])
+                Ok(variable_declaration.precompile(precompiler)?)
             }
             Self::VariableAssigment(variable_assigment) => {
                 Ok(vec![llvm_ast::Expression::VariableAssigment(
                     variable_assigment.precompile(precompiler)?,
                 )])
             }
+            Self::CompoundAssigment(compound_assigment) => {
+                Ok(vec![llvm_ast::Expression::CompoundAssigment(
+                    compound_assigment.precompile(precompiler)?,
+                )])
+            }
             Self::VariableExpression(variable_expression) => {
                 Ok(vec![llvm_ast::Expression::VariableExpression(
                     variable_expression.precompile(precompiler)?,
@@ -119,11 +277,99 @@ impl Expression {
                 )])
             }
             Self::WhileLoop(while_loop) => Ok(vec![llvm_ast::Expression::WhileLoop(
-                while_loop.precompile(precompiler)?,
+                while_loop.precompile(precompiler, None)?,
             )]),
             Self::DoWhileLoop(do_while_loop) => Ok(vec![llvm_ast::Expression::DoWhileLoop(
-                do_while_loop.precompile(precompiler)?,
+                do_while_loop.precompile(precompiler, None)?,
+            )]),
+            Self::ForLoop(for_loop) => Ok(for_loop.precompile(precompiler, None)?),
+            Self::LabeledStatement(labeled_statement) => {
+                Ok(labeled_statement.precompile(precompiler)?)
+            }
+            Self::BreakStatement(break_statement) => {
+                Ok(vec![llvm_ast::Expression::BreakStatement(
+                    break_statement.precompile(precompiler)?,
+                )])
+            }
+            Self::ContinueStatement(continue_statement) => {
+                Ok(vec![llvm_ast::Expression::ContinueStatement(
+                    continue_statement.precompile(precompiler)?,
+                )])
+            }
+            Self::ThrowStatement(throw_statement) => {
+                Ok(vec![llvm_ast::Expression::ThrowStatement(
+                    throw_statement.precompile(precompiler)?,
+                )])
+            }
+            Self::TryStatement(try_statement) => Ok(vec![llvm_ast::Expression::TryStatement(
+                try_statement.precompile(precompiler)?,
             )]),
+            Self::SwitchStatement(switch_statement) => {
+                Ok(vec![llvm_ast::Expression::SwitchStatement(
+                    switch_statement.precompile(precompiler)?,
+                )])
+            }
+        }
+    }
+
+    /// Collects the names of the "var" declarations, including the ones from the nested blocks.
+    /// Nested functions have their own scope, so they are skipped
+    pub(super) fn collect_var_declarations(&self, names: &mut Vec<Identifier>) {
+        match self {
+            Self::VariableDeclaration(VariableDeclaration {
+                declaration_type: DeclarationType::Var,
+                name,
+                ..
+            }) => {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            Self::BlockStatement(block_statement) => {
+                block_statement.collect_var_declarations(names);
+            }
+            Self::IfElseStatement(if_else_statement) => {
+                if_else_statement.if_clause.collect_var_declarations(names);
+                if_else_statement
+                    .else_clause
+                    .collect_var_declarations(names);
+            }
+            Self::WhileLoop(while_loop) => while_loop.body.collect_var_declarations(names),
+            Self::DoWhileLoop(do_while_loop) => do_while_loop.body.collect_var_declarations(names),
+            Self::ForLoop(for_loop) => {
+                if let Some(init) = &for_loop.init {
+                    init.collect_var_declarations(names);
+                }
+                for_loop.body.collect_var_declarations(names);
+            }
+            Self::LabeledStatement(labeled_statement) => {
+                labeled_statement.body.collect_var_declarations(names);
+            }
+            Self::TryStatement(try_statement) => {
+                try_statement.try_block.collect_var_declarations(names);
+                if let Some(catch) = &try_statement.catch {
+                    catch.body.collect_var_declarations(names);
+                }
+                if let Some(finally) = &try_statement.finally {
+                    finally.collect_var_declarations(names);
+                }
+            }
+            Self::SwitchStatement(switch_statement) => {
+                for case in &switch_statement.cases {
+                    for expr in &case.body {
+                        expr.collect_var_declarations(names);
+                    }
+                }
+            }
+            Self::FunctionDeclaration(_)
+            | Self::VariableDeclaration(_)
+            | Self::VariableAssigment(_)
+            | Self::CompoundAssigment(_)
+            | Self::VariableExpression(_)
+            | Self::BreakStatement(_)
+            | Self::ContinueStatement(_)
+            | Self::ReturnStatement(_)
+            | Self::ThrowStatement(_) => {}
         }
     }
 }
@@ -135,6 +381,7 @@ mod tests {
         member_expression::PropertyType, BinaryExpType, BinaryExpression, FunctionCall,
         MemberExpression, ObjectExpression, Property, VariableExpression, VariableValue,
     };
+    use lexer::Span;
 
     #[test]
     fn parse_expression_test1() {
@@ -142,6 +389,7 @@ mod tests {
         assert_eq!(
             Expression::parse(reader.next_token().unwrap(), &mut reader).unwrap(),
             Expression::VariableDeclaration(VariableDeclaration {
+                declaration_type: DeclarationType::Var,
                 name: "name".to_string().into(),
                 value: Some(VariableExpression::VariableValue(VariableValue::Number(
                     12_f64
@@ -156,6 +404,7 @@ mod tests {
         assert_eq!(
             Expression::parse(reader.next_token().unwrap(), &mut reader).unwrap(),
             Expression::VariableDeclaration(VariableDeclaration {
+                declaration_type: DeclarationType::Let,
                 name: "name".to_string().into(),
                 value: Some(VariableExpression::VariableValue(VariableValue::Number(
                     12_f64
@@ -173,7 +422,8 @@ mod tests {
                 left: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name".to_string().into()
                 )),
-                right: VariableExpression::VariableValue(VariableValue::Number(12_f64))
+                right: VariableExpression::VariableValue(VariableValue::Number(12_f64)),
+                span: Span::default(),
             })
         );
     }
@@ -196,7 +446,8 @@ mod tests {
                     )),
                     right: VariableExpression::VariableValue(VariableValue::Identifier(
                         "name2".to_string().into()
-                    ))
+                    )),
+                    span: Span::default(),
                 })]
             })
         );
@@ -214,7 +465,8 @@ mod tests {
                         )),
                         right: VariableExpression::VariableValue(VariableValue::Identifier(
                             "name2".to_string().into()
-                        ))
+                        )),
+                        span: Span::default(),
                     }),
                     Expression::BlockStatement(BlockStatement {
                         body: vec![
@@ -224,7 +476,8 @@ mod tests {
                                 )),
                                 right: VariableExpression::VariableValue(
                                     VariableValue::Identifier("name2".to_string().into())
-                                )
+                                ),
+                                span: Span::default(),
                             }),
                             Expression::VariableAssigment(VariableAssigment {
                                 left: VariableExpression::VariableValue(VariableValue::Identifier(
@@ -232,7 +485,8 @@ mod tests {
                                 )),
                                 right: VariableExpression::VariableValue(
                                     VariableValue::Identifier("name2".to_string().into())
-                                )
+                                ),
+                                span: Span::default(),
                             }),
                         ]
                     })
@@ -257,6 +511,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
                     right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
                     exp_type: BinaryExpType::Add,
+                    span: Span::default(),
                 }
                 .into()
             ))
@@ -272,7 +527,8 @@ mod tests {
                     VariableExpression::VariableValue(VariableValue::Identifier(
                         "b".to_string().into()
                     ))
-                ]
+                ],
+                span: Span::default(),
             }))
         );
         assert_eq!(
@@ -281,12 +537,14 @@ mod tests {
                 left: VariableExpression::VariableValue(VariableValue::Identifier(
                     "a".to_string().into()
                 )),
-                right: VariableExpression::VariableValue(VariableValue::Number(6_f64))
+                right: VariableExpression::VariableValue(VariableValue::Number(6_f64)),
+                span: Span::default(),
             }))
         );
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn parse_expression_test6() {
         let mut reader = TokenReader::new("name.name()".as_bytes());
         assert_eq!(
@@ -299,10 +557,12 @@ mod tests {
                     property: Property {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "name".to_string().into(),
-                            args: vec![]
+                            args: vec![],
+                            span: Span::default(),
                         }),
                         property: None
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ))
@@ -317,12 +577,14 @@ mod tests {
                         left: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
                         right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
                         exp_type: BinaryExpType::Add,
+                        span: Span::default(),
                     }
                     .into()
                 ),
                 right: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name2".to_string().into()
-                ))
+                )),
+                span: Span::default(),
             })
         );
 
@@ -336,6 +598,7 @@ mod tests {
                             left: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
                             right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
                             exp_type: BinaryExpType::Add,
+                            span: Span::default(),
                         }
                         .into(),
                     ),
@@ -343,9 +606,11 @@ mod tests {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "foo".to_string().into(),
                             args: vec![],
+                            span: Span::default(),
                         }),
                         property: None,
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into(),
             ))
@@ -369,7 +634,24 @@ mod tests {
                 )),
                 right: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name2".to_string().into()
-                ))
+                )),
+                span: Span::default(),
+            })
+        );
+
+        // the label of the loop does not make the block the object
+        let mut reader = TokenReader::new("{ name: while (true) {} }".as_bytes());
+        assert_eq!(
+            Expression::parse(reader.next_token().unwrap(), &mut reader).unwrap(),
+            Expression::BlockStatement(BlockStatement {
+                body: vec![Expression::LabeledStatement(LabeledStatement {
+                    label: "name".to_string().into(),
+                    body: Expression::WhileLoop(WhileLoop {
+                        condition: VariableExpression::VariableValue(VariableValue::Boolean(true)),
+                        body: BlockStatement { body: Vec::new() },
+                    })
+                    .into(),
+                })],
             })
         );
     }
@@ -0,0 +1,262 @@
+use super::{BlockStatement, Expression, Identifier, VariableDeclaration, VariableExpression};
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Separator, Token, TokenReader};
+use std::io::Read;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForLoop {
+    pub init: Option<Box<Expression>>,
+    pub condition: Option<VariableExpression>,
+    pub update: Option<Box<Expression>>,
+    pub body: BlockStatement,
+}
+
+impl ForLoop {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::For) => match reader.next_token()? {
+                Token::Separator(Separator::OpenBrace) => {
+                    let init = match reader.next_token()? {
+                        Token::Separator(Separator::SemiColon) => None,
+                        cur_token => {
+                            let init = Self::parse_init(cur_token, reader)?;
+                            match reader.next_token()? {
+                                Token::Separator(Separator::SemiColon) => Some(init.into()),
+                                token => {
+                                    return Err(LexerError::UnexpectedToken(token, reader.span()))
+                                }
+                            }
+                        }
+                    };
+                    let condition = match reader.next_token()? {
+                        Token::Separator(Separator::SemiColon) => None,
+                        cur_token => {
+                            let condition = VariableExpression::parse(cur_token, reader)?;
+                            match reader.next_token()? {
+                                Token::Separator(Separator::SemiColon) => Some(condition),
+                                token => {
+                                    return Err(LexerError::UnexpectedToken(token, reader.span()))
+                                }
+                            }
+                        }
+                    };
+                    let update = match reader.next_token()? {
+                        Token::Separator(Separator::CloseBrace) => None,
+                        cur_token => {
+                            let update = Self::parse_expression(cur_token, reader)?;
+                            match reader.next_token()? {
+                                Token::Separator(Separator::CloseBrace) => Some(update.into()),
+                                token => {
+                                    return Err(LexerError::UnexpectedToken(token, reader.span()))
+                                }
+                            }
+                        }
+                    };
+                    let body = BlockStatement::parse(reader.next_token()?, reader)?;
+
+                    Ok(Self {
+                        init,
+                        condition,
+                        update,
+                        body,
+                    })
+                }
+                token => Err(LexerError::UnexpectedToken(token, reader.span())),
+            },
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+
+    fn parse_init<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Expression, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Var | Keyword::Let | Keyword::Const) => Ok(
+                Expression::VariableDeclaration(VariableDeclaration::parse(cur_token, reader)?),
+            ),
+            cur_token => Self::parse_expression(cur_token, reader),
+        }
+    }
+
+    fn parse_expression<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Expression, LexerError> {
+        Expression::parse_assigment_or_expression(cur_token, reader)
+    }
+}
+
+impl ForLoop {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+        label: Option<Identifier>,
+    ) -> Result<Vec<llvm_ast::Expression>, PrecompilerError> {
+        // variables declared in the init part are visible only inside the loop
+        let variables_len = precompiler.variables_len();
+        let mut init = Vec::new();
+        if let Some(expr) = self.init {
+            BlockStatement::declare_block_variables(
+                std::iter::once(expr.as_ref()),
+                precompiler,
+                &mut init,
+            );
+            init.extend(expr.precompile(precompiler)?);
+        }
+        let condition = match self.condition {
+            Some(condition) => Some(condition.precompile(precompiler)?),
+            None => None,
+        };
+        let update = match self.update {
+            Some(update) => update.precompile(precompiler)?,
+            None => Vec::new(),
+        };
+        precompiler.enter_loop_scope(label);
+        let body = self.body.precompile(precompiler);
+        precompiler.exit_loop_scope();
+        let body = body?;
+
+        let mut res = vec![llvm_ast::Expression::ForLoop(llvm_ast::ForLoop {
+            init,
+            condition,
+            update,
+            body,
+        })];
+        let vars =
+            precompiler.remove_last_added_variables(precompiler.variables_len() - variables_len);
+        for (var, index) in vars {
+            res.push(llvm_ast::Expression::DeallocateExpression(
+                llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+                },
+            ));
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{
+        BinaryExpType, BinaryExpression, DeclarationType, VariableAssigment, VariableValue,
+    };
+    use lexer::Span;
+
+    #[test]
+    fn parse_for_loop_test() {
+        let mut reader = TokenReader::new("for (;;) {}".as_bytes());
+        assert_eq!(
+            ForLoop::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ForLoop {
+                init: None,
+                condition: None,
+                update: None,
+                body: BlockStatement { body: Vec::new() },
+            })
+        );
+
+        let mut reader = TokenReader::new("for (var i = 0; i < 5; i = i + 1) {}".as_bytes());
+        assert_eq!(
+            ForLoop::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ForLoop {
+                init: Some(
+                    Expression::VariableDeclaration(VariableDeclaration {
+                        declaration_type: DeclarationType::Var,
+                        name: "i".to_string().into(),
+                        value: Some(VariableExpression::VariableValue(VariableValue::Number(
+                            0_f64
+                        ))),
+                    })
+                    .into()
+                ),
+                condition: Some(VariableExpression::BinaryExpression(
+                    BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "i".to_string().into()
+                        )),
+                        right: VariableExpression::VariableValue(VariableValue::Number(5_f64)),
+                        exp_type: BinaryExpType::Lt,
+                        span: Span::default(),
+                    }
+                    .into()
+                )),
+                update: Some(
+                    Expression::VariableAssigment(VariableAssigment {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "i".to_string().into()
+                        )),
+                        right: VariableExpression::BinaryExpression(
+                            BinaryExpression {
+                                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                    "i".to_string().into()
+                                )),
+                                right: VariableExpression::VariableValue(VariableValue::Number(
+                                    1_f64
+                                )),
+                                exp_type: BinaryExpType::Add,
+                                span: Span::default(),
+                            }
+                            .into()
+                        ),
+                        span: Span::default(),
+                    })
+                    .into()
+                ),
+                body: BlockStatement { body: Vec::new() },
+            })
+        );
+    }
+
+    #[test]
+    fn precompile_for_loop_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+
+        let for_loop = ForLoop {
+            init: Some(
+                Expression::VariableDeclaration(VariableDeclaration {
+                    declaration_type: DeclarationType::Let,
+                    name: "i".to_string().into(),
+                    value: None,
+                })
+                .into(),
+            ),
+            condition: Some(VariableExpression::VariableValue(
+                VariableValue::Identifier("i".to_string().into()),
+            )),
+            update: None,
+            body: BlockStatement { body: Vec::new() },
+        };
+
+        assert_eq!(
+            for_loop.precompile(&mut precompiler, None),
+            Ok(vec![
+                llvm_ast::Expression::ForLoop(llvm_ast::ForLoop {
+                    init: vec![llvm_ast::Expression::VariableDeclaration(
+                        llvm_ast::VariableDeclaration {
+                            name: llvm_ast::Identifier::new("i".to_string(), 0),
+                            value: None,
+                        }
+                    )],
+                    condition: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "i".to_string(),
+                            0
+                        ))
+                    )),
+                    update: Vec::new(),
+                    body: Vec::new(),
+                }),
+                llvm_ast::Expression::DeallocateExpression(llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::new("i".to_string(), 0),
+                }),
+            ])
+        );
+        // loop variable is not visible after the loop
+        assert_eq!(precompiler.variables_len(), 0);
+    }
+}
@@ -1,12 +1,20 @@
 use super::{Identifier, VariableExpression};
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
-use lexer::{Separator, Token, TokenReader};
+use lexer::{Separator, Span, Token, TokenReader};
 use std::io::Read;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct FunctionCall {
     pub name: Identifier,
     pub args: Vec<VariableExpression>,
+    /// span of the whole call, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for FunctionCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.args == other.args
+    }
 }
 
 impl FunctionCall {
@@ -31,12 +39,13 @@ impl FunctionCall {
                     cur_token = match reader.next_token()? {
                         Token::Separator(Separator::CloseBrace) => break,
                         Token::Separator(Separator::Comma) => reader.next_token()?,
-                        token => return Err(LexerError::UnexpectedToken(token)),
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
                     };
                 }
-                Ok(Self { name, args })
+                let span = Span::new(name.span.start.clone(), reader.span().end);
+                Ok(Self { name, args, span })
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -46,7 +55,9 @@ impl FunctionCall {
         self,
         precompiler: &mut Precompiler,
     ) -> Result<llvm_ast::FunctionCall, PrecompilerError> {
-        let index = precompiler.get_variable(self.name.clone())?;
+        let index = precompiler
+            .get_variable(self.name.clone())
+            .or_else(|e| precompiler.collect_undefined_variable(e))?;
         // check if arguments exist
         let mut args = Vec::new();
         for arg in self.args {
@@ -54,12 +65,27 @@ impl FunctionCall {
         }
 
         Ok(llvm_ast::FunctionCall {
-            name: llvm_ast::Identifier::new(self.name.name, index),
+            name: llvm_ast::Identifier::with_span(self.name.name, index, self.name.span),
             args,
+            deallocations: exception_deallocations(precompiler),
+            span: self.span,
         })
     }
 }
 
+/// Returns the variables, which should be deallocated if the called function throws an exception
+pub(super) fn exception_deallocations(
+    precompiler: &Precompiler,
+) -> Vec<llvm_ast::DeallocateExpression> {
+    precompiler
+        .get_exception_scope_variables()
+        .into_iter()
+        .map(|(var, index)| llvm_ast::DeallocateExpression {
+            name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,7 +107,8 @@ mod tests {
                     )),
                     VariableExpression::VariableValue(VariableValue::String("val".to_string())),
                     VariableExpression::VariableValue(VariableValue::Number(5_f64)),
-                ]
+                ],
+                span: Span::default(),
             })
         );
     }
@@ -106,6 +133,7 @@ mod tests {
                 VariableExpression::VariableValue(VariableValue::String("val".to_string())),
                 VariableExpression::VariableValue(VariableValue::Number(5_f64)),
             ],
+            span: Span::default(),
         };
 
         assert_eq!(
@@ -132,6 +160,8 @@ mod tests {
                         llvm_ast::VariableValue::FloatNumber(5_f64)
                     ),
                 ],
+                deallocations: Vec::new(),
+                span: Span::default(),
             })
         );
     }
@@ -144,13 +174,23 @@ mod tests {
             name: "name_1".to_string().into(),
 
             args: vec![],
+            span: Span::default(),
         };
 
         assert_eq!(
             function_call.precompile(&mut precompiler),
-            Err(precompiler::Error::UndefinedVariable(
+            Ok(llvm_ast::FunctionCall {
+                name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                args: vec![],
+                deallocations: Vec::new(),
+                span: Span::default(),
+            })
+        );
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![precompiler::Error::UndefinedVariable(
                 "name_1".to_string().into()
-            ))
+            )]
         );
     }
 }
@@ -12,7 +12,7 @@ pub struct FunctionDeclaration {
 
 impl FunctionDeclaration {
     pub fn parse<R: Read>(
-        mut cur_token: Token,
+        cur_token: Token,
         reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
         match cur_token {
@@ -21,34 +21,42 @@ impl FunctionDeclaration {
                 let name = Identifier::parse(reader.next_token()?, reader)?;
 
                 // parse function args
-                let args = match reader.next_token()? {
-                    Token::Separator(Separator::OpenBrace) => {
-                        let mut args = Vec::new();
-                        cur_token = reader.next_token()?;
-                        loop {
-                            let arg = match cur_token {
-                                Token::Separator(Separator::CloseBrace) => break,
-                                cur_token => Identifier::parse(cur_token, reader)?,
-                            };
-                            args.push(arg);
-
-                            cur_token = match reader.next_token()? {
-                                Token::Separator(Separator::CloseBrace) => break,
-                                Token::Separator(Separator::Comma) => reader.next_token()?,
-                                token => return Err(LexerError::UnexpectedToken(token)),
-                            };
-                        }
-                        Ok(args)
-                    }
-                    token => Err(LexerError::UnexpectedToken(token)),
-                }?;
+                let args = Self::parse_args(reader.next_token()?, reader)?;
 
                 // parse function body
                 let body = BlockStatement::parse(reader.next_token()?, reader)?;
 
                 Ok(Self { name, args, body })
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+
+    /// Parses function arguments, like "(a, b)"
+    pub fn parse_args<R: Read>(
+        mut cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Vec<Identifier>, LexerError> {
+        match cur_token {
+            Token::Separator(Separator::OpenBrace) => {
+                let mut args = Vec::new();
+                cur_token = reader.next_token()?;
+                loop {
+                    let arg = match cur_token {
+                        Token::Separator(Separator::CloseBrace) => break,
+                        cur_token => Identifier::parse(cur_token, reader)?,
+                    };
+                    args.push(arg);
+
+                    cur_token = match reader.next_token()? {
+                        Token::Separator(Separator::CloseBrace) => break,
+                        Token::Separator(Separator::Comma) => reader.next_token()?,
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+                    };
+                }
+                Ok(args)
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -66,36 +74,53 @@ impl FunctionDeclaration {
     > {
         let index = precompiler.insert_variable(self.name.clone());
 
-        let variables_len = precompiler.variables_len();
-        let name = llvm_ast::Identifier::new(self.name.name, index);
+        let name = llvm_ast::Identifier::with_span(self.name.name, index, self.name.span);
         let args_num = self.args.len().try_into().expect("");
 
-        let function_declaration = llvm_ast::FunctionDeclaration {
-            name: name.clone(),
-            args: self
-                .args
-                .into_iter()
-                .map(|name| {
-                    // argument initialization hides the previous variable declaration with the same name
-                    let index = precompiler.insert_variable(name.clone());
-                    llvm_ast::Identifier::new(name.name, index)
-                })
-                .collect(),
-            body: self.body.precompile(precompiler)?,
-        };
-        precompiler.remove_last_added_variables(precompiler.variables_len() - variables_len);
+        let function_declaration =
+            Self::precompile_function(name.clone(), self.args, self.body, precompiler)?;
 
         Ok((
             function_declaration,
             llvm_ast::VariableFunctionDeclaration { name, args_num },
         ))
     }
+
+    /// Precompiles the function arguments and body, which are shared by all kinds of functions
+    pub fn precompile_function(
+        name: llvm_ast::Identifier,
+        args: Vec<Identifier>,
+        body: BlockStatement,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::FunctionDeclaration, PrecompilerError> {
+        // argument initialization hides the previous variable declaration with the same name
+        let indexes = precompiler.enter_function_scope(args.clone());
+        let body = body.precompile_function_body(&args, precompiler);
+        let args = args
+            .into_iter()
+            .zip(indexes)
+            .map(|(name, index)| llvm_ast::Identifier::with_span(name.name, index, name.span))
+            .collect();
+        let captured = precompiler
+            .exit_function_scope()
+            .into_iter()
+            .map(|(name, index)| llvm_ast::Identifier::with_span(name.name, index, name.span))
+            .collect();
+
+        Ok(llvm_ast::FunctionDeclaration {
+            name,
+            args,
+            captured,
+            body: body?,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::js_ast::{Expression, VariableAssigment, VariableExpression, VariableValue};
+    use lexer::Span;
 
     #[test]
     fn parse_function_declaration_test() {
@@ -113,6 +138,7 @@ mod tests {
                         right: VariableExpression::VariableValue(VariableValue::Identifier(
                             "b".to_string().into()
                         )),
+                        span: Span::default(),
                     })]
                 }
             })
@@ -134,6 +160,7 @@ mod tests {
                     right: VariableExpression::VariableValue(VariableValue::Identifier(
                         "b".to_string().into(),
                     )),
+                    span: Span::default(),
                 })],
             },
         };
@@ -147,6 +174,7 @@ mod tests {
                         llvm_ast::Identifier::new("a".to_string(), 0),
                         llvm_ast::Identifier::new("b".to_string(), 0)
                     ],
+                    captured: vec![],
                     body: vec![llvm_ast::Expression::VariableAssigment(
                         llvm_ast::VariableAssigment {
                             left: llvm_ast::VariableExpression::VariableValue(
@@ -160,7 +188,8 @@ mod tests {
                                     "b".to_string(),
                                     0
                                 ))
-                            )
+                            ),
+                            span: Span::default(),
                         }
                     )]
                 },
@@ -191,6 +220,7 @@ mod tests {
                     right: VariableExpression::VariableValue(VariableValue::Identifier(
                         "b".to_string().into(),
                     )),
+                    span: Span::default(),
                 })],
             },
         };
@@ -204,6 +234,7 @@ mod tests {
                         llvm_ast::Identifier::new("a".to_string(), 1),
                         llvm_ast::Identifier::new("b".to_string(), 1)
                     ],
+                    captured: vec![],
                     body: vec![llvm_ast::Expression::VariableAssigment(
                         llvm_ast::VariableAssigment {
                             left: llvm_ast::VariableExpression::VariableValue(
@@ -217,7 +248,8 @@ mod tests {
                                     "b".to_string(),
                                     1
                                 ))
-                            )
+                            ),
+                            span: Span::default(),
                         }
                     )]
                 },
@@ -249,6 +281,7 @@ mod tests {
                 llvm_ast::FunctionDeclaration {
                     name: llvm_ast::Identifier::new("name_1".to_string(), 1),
                     args: vec![],
+                    captured: vec![],
                     body: vec![],
                 },
                 llvm_ast::VariableFunctionDeclaration {
@@ -258,4 +291,59 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn precompile_function_declaration_test_4() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+
+        let function_declaration = FunctionDeclaration {
+            name: "name_1".to_string().into(),
+            args: vec!["b".to_string().into()],
+            body: BlockStatement {
+                body: vec![Expression::VariableAssigment(VariableAssigment {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into(),
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "b".to_string().into(),
+                    )),
+                    span: Span::default(),
+                })],
+            },
+        };
+
+        assert_eq!(
+            function_declaration.precompile(&mut precompiler),
+            Ok((
+                llvm_ast::FunctionDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    args: vec![llvm_ast::Identifier::new("b".to_string(), 0)],
+                    captured: vec![llvm_ast::Identifier::new("a".to_string(), 0)],
+                    body: vec![llvm_ast::Expression::VariableAssigment(
+                        llvm_ast::VariableAssigment {
+                            left: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                    "a".to_string(),
+                                    0
+                                ))
+                            ),
+                            right: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                    "b".to_string(),
+                                    0
+                                ))
+                            ),
+                            span: Span::default(),
+                        }
+                    )]
+                },
+                llvm_ast::VariableFunctionDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    args_num: 1
+                }
+            ))
+        );
+        assert_eq!(precompiler.variables_len(), 2);
+    }
 }
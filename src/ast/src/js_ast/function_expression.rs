@@ -0,0 +1,167 @@
+use super::{BlockStatement, FunctionDeclaration, Identifier};
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Token, TokenReader};
+use std::io::Read;
+
+/// Anonymous function, like "function (a, b) { ... }"
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionExpression {
+    pub args: Vec<Identifier>,
+    pub body: BlockStatement,
+}
+
+impl FunctionExpression {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Function) => {
+                let args = FunctionDeclaration::parse_args(reader.next_token()?, reader)?;
+                let body = BlockStatement::parse(reader.next_token()?, reader)?;
+                Ok(Self { args, body })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl FunctionExpression {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::FunctionExpression, PrecompilerError> {
+        precompile_anonymous_function(self.args, self.body, precompiler)
+    }
+}
+
+/// Declares the function with the generated unique name
+pub(super) fn precompile_anonymous_function(
+    args: Vec<Identifier>,
+    body: BlockStatement,
+    precompiler: &mut Precompiler,
+) -> Result<llvm_ast::FunctionExpression, PrecompilerError> {
+    // "." could not be the part of the JS identifier, so the name does not clash with the declared functions
+    let name = llvm_ast::Identifier::new(
+        format!("function.{}", precompiler.get_anonymous_function_index()),
+        0,
+    );
+    let function_declaration =
+        FunctionDeclaration::precompile_function(name.clone(), args, body, precompiler)?;
+    precompiler.insert_function_declaration(function_declaration);
+    Ok(llvm_ast::FunctionExpression { name })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{Expression, VariableAssigment, VariableExpression, VariableValue};
+    use lexer::{Position, Span};
+
+    #[test]
+    fn parse_function_expression_test() {
+        let mut reader = TokenReader::new("function (a, b) { a = b; }".as_bytes());
+        assert_eq!(
+            FunctionExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(FunctionExpression {
+                args: vec!["a".to_string().into(), "b".to_string().into()],
+                body: BlockStatement {
+                    body: vec![Expression::VariableAssigment(VariableAssigment {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        right: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        span: Span::default(),
+                    })]
+                }
+            })
+        );
+
+        let mut reader = TokenReader::new("function name() {}".as_bytes());
+        assert_eq!(
+            FunctionExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Ident("name".to_string()),
+                Span::new(Position::new(1, 10), Position::new(1, 13))
+            ))
+        );
+    }
+
+    #[test]
+    fn precompile_function_expression_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+
+        let function_expression = FunctionExpression {
+            args: vec![],
+            body: BlockStatement {
+                body: vec![Expression::VariableAssigment(VariableAssigment {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into(),
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                    span: Span::default(),
+                })],
+            },
+        };
+
+        assert_eq!(
+            function_expression.clone().precompile(&mut precompiler),
+            Ok(llvm_ast::FunctionExpression {
+                name: llvm_ast::Identifier::new("function.0".to_string(), 0),
+            })
+        );
+        assert_eq!(
+            function_expression.precompile(&mut precompiler),
+            Ok(llvm_ast::FunctionExpression {
+                name: llvm_ast::Identifier::new("function.1".to_string(), 0),
+            })
+        );
+        assert_eq!(precompiler.variables_len(), 1);
+        assert_eq!(
+            precompiler.get_function_declarations(),
+            vec![
+                llvm_ast::FunctionDeclaration {
+                    name: llvm_ast::Identifier::new("function.0".to_string(), 0),
+                    args: vec![],
+                    captured: vec![llvm_ast::Identifier::new("a".to_string(), 0)],
+                    body: vec![llvm_ast::Expression::VariableAssigment(
+                        llvm_ast::VariableAssigment {
+                            left: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                    "a".to_string(),
+                                    0
+                                ))
+                            ),
+                            right: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::FloatNumber(1_f64)
+                            ),
+                            span: Span::default(),
+                        }
+                    )]
+                },
+                llvm_ast::FunctionDeclaration {
+                    name: llvm_ast::Identifier::new("function.1".to_string(), 0),
+                    args: vec![],
+                    captured: vec![llvm_ast::Identifier::new("a".to_string(), 0)],
+                    body: vec![llvm_ast::Expression::VariableAssigment(
+                        llvm_ast::VariableAssigment {
+                            left: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                    "a".to_string(),
+                                    0
+                                ))
+                            ),
+                            right: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::FloatNumber(1_f64)
+                            ),
+                            span: Span::default(),
+                        }
+                    )]
+                }
+            ]
+        );
+    }
+}
@@ -1,30 +1,59 @@
 use crate::LexerError;
-use lexer::{Token, TokenReader};
-use std::{fmt::Display, io::Read};
+use lexer::{Span, Token, TokenReader};
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    io::Read,
+};
 
 /// Identifier - Expression type for any identifier, like "name"
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Identifier {
     pub name: String,
+    /// location in the source, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Identifier {}
+
+impl Hash for Identifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
 }
 
 impl Display for Identifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Identifier: {0}", self.name)
+        write!(f, "Identifier: {0}, position: {1}", self.name, self.span)
     }
 }
 
 impl From<String> for Identifier {
     fn from(name: String) -> Self {
-        Self { name }
+        Self {
+            name,
+            span: Span::default(),
+        }
     }
 }
 
 impl Identifier {
-    pub fn parse<R: Read>(cur_token: Token, _: &mut TokenReader<R>) -> Result<Self, LexerError> {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
         match cur_token {
-            Token::Ident(name) => Ok(Self { name }),
-            token => Err(LexerError::UnexpectedToken(token)),
+            Token::Ident(name) => Ok(Self {
+                name,
+                span: reader.span(),
+            }),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -32,6 +61,7 @@ impl Identifier {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lexer::Position;
 
     #[test]
     fn parse_indetifier_test() {
@@ -40,5 +70,21 @@ mod tests {
             Identifier::parse(reader.next_token().unwrap(), &mut reader),
             Ok("name".to_string().into())
         );
+
+        let mut reader = TokenReader::new("\n  name".as_bytes());
+        let identifier = Identifier::parse(reader.next_token().unwrap(), &mut reader).unwrap();
+        assert_eq!(
+            identifier.span,
+            Span::new(Position::new(2, 3), Position::new(2, 6))
+        );
+
+        let mut reader = TokenReader::new("12".as_bytes());
+        assert_eq!(
+            Identifier::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Literal(lexer::Literal::Number(12_f64)),
+                Span::new(Position::new(1, 1), Position::new(1, 2))
+            ))
+        );
     }
 }
@@ -23,14 +23,11 @@ impl IfElseStatement {
                         Token::Separator(Separator::CloseBrace) => {
                             let if_clause = BlockStatement::parse(reader.next_token()?, reader)?;
 
-                            reader.start_saving();
-
                             let else_clause =
-                                if let Token::Keyword(Keyword::Else) = reader.next_token()? {
-                                    reader.reset_saving();
+                                if let Token::Keyword(Keyword::Else) = reader.peek()? {
+                                    reader.next_token()?;
                                     BlockStatement::parse(reader.next_token()?, reader)?
                                 } else {
-                                    reader.stop_saving();
                                     BlockStatement { body: Vec::new() }
                                 };
 
@@ -40,12 +37,12 @@ impl IfElseStatement {
                                 else_clause,
                             })
                         }
-                        token => Err(LexerError::UnexpectedToken(token)),
+                        token => Err(LexerError::UnexpectedToken(token, reader.span())),
                     }
                 }
-                token => Err(LexerError::UnexpectedToken(token)),
+                token => Err(LexerError::UnexpectedToken(token, reader.span())),
             },
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -0,0 +1,91 @@
+use super::{DoWhileLoop, Expression, ForLoop, Identifier, WhileLoop};
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Separator, Token, TokenReader};
+use std::io::Read;
+
+/// Labeled loop, like "label: while (true) {}"
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabeledStatement {
+    pub label: Identifier,
+    pub body: Box<Expression>,
+}
+
+impl LabeledStatement {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        let label = Identifier::parse(cur_token, reader)?;
+        match reader.next_token()? {
+            Token::Separator(Separator::Colon) => {
+                let body = match reader.next_token()? {
+                    cur_token @ Token::Keyword(Keyword::While) => {
+                        Expression::WhileLoop(WhileLoop::parse(cur_token, reader)?)
+                    }
+                    cur_token @ Token::Keyword(Keyword::Do) => {
+                        Expression::DoWhileLoop(DoWhileLoop::parse(cur_token, reader)?)
+                    }
+                    cur_token @ Token::Keyword(Keyword::For) => {
+                        Expression::ForLoop(ForLoop::parse(cur_token, reader)?)
+                    }
+                    token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+                };
+                Ok(Self {
+                    label,
+                    body: body.into(),
+                })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl LabeledStatement {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<Vec<llvm_ast::Expression>, PrecompilerError> {
+        match *self.body {
+            Expression::WhileLoop(while_loop) => Ok(vec![llvm_ast::Expression::WhileLoop(
+                while_loop.precompile(precompiler, Some(self.label))?,
+            )]),
+            Expression::DoWhileLoop(do_while_loop) => Ok(vec![llvm_ast::Expression::DoWhileLoop(
+                do_while_loop.precompile(precompiler, Some(self.label))?,
+            )]),
+            Expression::ForLoop(for_loop) => for_loop.precompile(precompiler, Some(self.label)),
+            body => body.precompile(precompiler),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{BlockStatement, VariableExpression, VariableValue};
+    use lexer::{Position, Span};
+
+    #[test]
+    fn parse_labeled_statement_test() {
+        let mut reader = TokenReader::new("label: while (true) {}".as_bytes());
+        assert_eq!(
+            LabeledStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(LabeledStatement {
+                label: "label".to_string().into(),
+                body: Expression::WhileLoop(WhileLoop {
+                    condition: VariableExpression::VariableValue(VariableValue::Boolean(true)),
+                    body: BlockStatement { body: Vec::new() },
+                })
+                .into(),
+            })
+        );
+
+        let mut reader = TokenReader::new("label: 5".as_bytes());
+        assert_eq!(
+            LabeledStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Literal(lexer::Literal::Number(5_f64)),
+                Span::new(Position::new(1, 8), Position::new(1, 8))
+            ))
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use super::{FunctionCall, Identifier, VariableExpression};
+use super::{function_call, FunctionCall, Identifier, VariableExpression};
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
-use lexer::{Separator, Token, TokenReader};
+use lexer::{Separator, Span, Token, TokenReader};
 use std::io::Read;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -23,28 +23,16 @@ impl Property {
     ) -> Result<Option<Self>, LexerError> {
         match cur_token {
             Token::Separator(Separator::Dot) => {
-                reader.start_saving();
-                let object = if let Ok(res) = FunctionCall::parse(reader.next_token()?, reader) {
-                    reader.reset_saving();
-                    PropertyType::FunctionCall(res)
+                // ".name(" starts the method call
+                let object = if let Token::Separator(Separator::OpenBrace) = reader.peek_n(1)? {
+                    PropertyType::FunctionCall(FunctionCall::parse(reader.next_token()?, reader)?)
                 } else {
-                    reader.stop_saving();
                     PropertyType::Identifier(Identifier::parse(reader.next_token()?, reader)?)
                 };
-                reader.start_saving();
-                if let Some(property) = Self::parse(&reader.next_token()?, reader)? {
-                    reader.reset_saving();
-                    Ok(Some(Self {
-                        object,
-                        property: Some(property.into()),
-                    }))
-                } else {
-                    reader.stop_saving();
-                    Ok(Some(Self {
-                        object,
-                        property: None,
-                    }))
-                }
+                Ok(Some(Self {
+                    object,
+                    property: Self::parse_next(reader)?.map(Box::new),
+                }))
             }
             Token::Separator(Separator::OpenSquareBracket) => {
                 let object = PropertyType::VariableExpression(VariableExpression::parse(
@@ -52,29 +40,28 @@ impl Property {
                     reader,
                 )?);
                 match reader.next_token()? {
-                    Token::Separator(Separator::CloseSquareBracket) => {
-                        reader.start_saving();
-                        if let Some(property) = Self::parse(&reader.next_token()?, reader)? {
-                            reader.reset_saving();
-                            Ok(Some(Self {
-                                object,
-                                property: Some(property.into()),
-                            }))
-                        } else {
-                            reader.stop_saving();
-                            Ok(Some(Self {
-                                object,
-                                property: None,
-                            }))
-                        }
-                    }
-                    token => Err(LexerError::UnexpectedToken(token)),
+                    Token::Separator(Separator::CloseSquareBracket) => Ok(Some(Self {
+                        object,
+                        property: Self::parse_next(reader)?.map(Box::new),
+                    })),
+                    token => Err(LexerError::UnexpectedToken(token, reader.span())),
                 }
             }
             _ => Ok(None),
         }
     }
 
+    /// Parses the property, if the next token is "." or "[", otherwise nothing is consumed
+    pub fn parse_next<R: Read>(reader: &mut TokenReader<R>) -> Result<Option<Self>, LexerError> {
+        match reader.peek()? {
+            Token::Separator(Separator::Dot | Separator::OpenSquareBracket) => {
+                let cur_token = reader.next_token()?;
+                Self::parse(&cur_token, reader)
+            }
+            _ => Ok(None),
+        }
+    }
+
     pub fn precompile(
         self,
         precompiler: &mut Precompiler,
@@ -93,6 +80,8 @@ impl Property {
                 llvm_ast::PropertyType::FunctionCall(llvm_ast::FunctionCall {
                     name: llvm_ast::Identifier::new(function_call.name.name, 0),
                     args,
+                    deallocations: function_call::exception_deallocations(precompiler),
+                    span: function_call.span,
                 })
             }
             PropertyType::VariableExpression(variable_expression) => {
@@ -110,10 +99,18 @@ impl Property {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct MemberExpression {
     pub object: VariableExpression,
     pub property: Property,
+    /// span of the whole expression, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for MemberExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object && self.property == other.property
+    }
 }
 
 impl MemberExpression {
@@ -123,7 +120,12 @@ impl MemberExpression {
     ) -> Result<llvm_ast::MemberExpression, PrecompilerError> {
         let object = self.object.precompile(precompiler)?.into();
         let property = self.property.precompile(precompiler)?;
-        Ok(llvm_ast::MemberExpression { object, property })
+        Ok(llvm_ast::MemberExpression {
+            object,
+            property,
+            deallocations: function_call::exception_deallocations(precompiler),
+            span: self.span,
+        })
     }
 }
 
@@ -145,7 +147,8 @@ mod tests {
                     property: Property {
                         object: PropertyType::Identifier("name".to_string().into()),
                         property: None
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -168,7 +171,8 @@ mod tests {
                             }
                             .into()
                         )
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -197,7 +201,8 @@ mod tests {
                             }
                             .into()
                         )
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -217,10 +222,12 @@ mod tests {
                     property: Property {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "name".to_string().into(),
-                            args: vec![]
+                            args: vec![],
+                            span: Span::default(),
                         }),
                         property: None
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -237,19 +244,22 @@ mod tests {
                     property: Property {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "name".to_string().into(),
-                            args: vec![]
+                            args: vec![],
+                            span: Span::default(),
                         }),
                         property: Some(
                             Property {
                                 object: PropertyType::FunctionCall(FunctionCall {
                                     name: "name".to_string().into(),
-                                    args: vec![]
+                                    args: vec![],
+                                    span: Span::default(),
                                 }),
                                 property: None
                             }
                             .into()
                         )
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -266,19 +276,22 @@ mod tests {
                     property: Property {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "name".to_string().into(),
-                            args: vec![]
+                            args: vec![],
+                            span: Span::default(),
                         }),
                         property: Some(
                             Property {
                                 object: PropertyType::FunctionCall(FunctionCall {
                                     name: "name".to_string().into(),
-                                    args: vec![]
+                                    args: vec![],
+                                    span: Span::default(),
                                 }),
                                 property: Some(
                                     Property {
                                         object: PropertyType::FunctionCall(FunctionCall {
                                             name: "name".to_string().into(),
-                                            args: vec![]
+                                            args: vec![],
+                                            span: Span::default(),
                                         }),
                                         property: None
                                     }
@@ -287,7 +300,8 @@ mod tests {
                             }
                             .into()
                         )
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -316,10 +330,12 @@ mod tests {
                     property: Property {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "name".to_string().into(),
-                            args: vec![]
+                            args: vec![],
+                            span: Span::default(),
                         }),
                         property: None
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -332,15 +348,18 @@ mod tests {
                 MemberExpression {
                     object: VariableExpression::FunctionCall(FunctionCall {
                         name: "name".to_string().into(),
-                        args: vec![]
+                        args: vec![],
+                        span: Span::default(),
                     }),
                     property: Property {
                         object: PropertyType::FunctionCall(FunctionCall {
                             name: "name".to_string().into(),
-                            args: vec![]
+                            args: vec![],
+                            span: Span::default(),
                         }),
                         property: None
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -365,7 +384,8 @@ mod tests {
                             ))
                         ),
                         property: None
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -396,7 +416,8 @@ mod tests {
                             }
                             .into()
                         )
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -438,7 +459,8 @@ mod tests {
                                                             "name".to_string().into()
                                                         ),
                                                         property: None
-                                                    }
+                                                    },
+                                                    span: Span::default(),
                                                 }
                                                 .into()
                                             )
@@ -450,7 +472,8 @@ mod tests {
                             }
                             .into()
                         )
-                    }
+                    },
+                    span: Span::default(),
                 }
                 .into()
             ),
@@ -476,7 +499,7 @@ mod tests {
                                                 property: Property {
                                                         object: PropertyType::Identifier("name".to_string().into()),
                                                         property: None
-                                                    }
+                                                    }, span: Span::default(), 
                                             }.into()
                                         )),
                                         property: Some(
@@ -493,7 +516,7 @@ mod tests {
                                                                     }
                                                                     .into()
                                                                 )
-                                                            }
+                                                            }, span: Span::default(), 
                                                     }.into(),
                                                 )),
                                                 property: None
@@ -506,7 +529,7 @@ mod tests {
                             }
                             .into()
                         )
-                    }
+                    }, span: Span::default(), 
             }.into()),
         );
     }
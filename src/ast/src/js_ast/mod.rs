@@ -1,36 +1,60 @@
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
 pub use array_expression::ArrayExpression;
+pub use arrow_function::{ArrowFunction, ArrowFunctionBody};
 pub use binary_expression::{BinaryExpType, BinaryExpression};
 pub use block_statement::BlockStatement;
+pub use break_statement::BreakStatement;
+pub use compound_assigment::{CompoundAssigment, CompoundAssigmentType};
+pub use conditional_expression::ConditionalExpression;
+pub use continue_statement::ContinueStatement;
 pub use do_while_loop::DoWhileLoop;
 pub use expression::Expression;
+pub use for_loop::ForLoop;
 pub use function_call::FunctionCall;
 pub use function_declaration::FunctionDeclaration;
+pub use function_expression::FunctionExpression;
 pub use identifier::Identifier;
 pub use if_else_statement::IfElseStatement;
-use lexer::{Token, TokenReader};
+pub use labeled_statement::LabeledStatement;
+use lexer::{Separator, Token, TokenReader};
 pub use member_expression::{MemberExpression, Property};
 pub use object_expression::ObjectExpression;
 use std::io::Read;
+pub use switch_statement::{SwitchCase, SwitchStatement};
+pub use template_literal::TemplateLiteral;
+pub use throw_statement::ThrowStatement;
+pub use try_statement::{CatchClause, TryStatement};
 pub use unary_expression::{UnaryExpType, UnaryExpression};
 pub use variable_assigment::VariableAssigment;
-pub use variable_declaration::VariableDeclaration;
+pub use variable_declaration::{DeclarationType, VariableDeclaration};
 pub use variable_expression::VariableExpression;
 pub use variable_value::VariableValue;
 pub use while_loop::WhileLoop;
 
 mod array_expression;
+mod arrow_function;
 mod binary_expression;
 mod block_statement;
+mod break_statement;
+mod compound_assigment;
+mod conditional_expression;
+mod continue_statement;
 mod do_while_loop;
 mod expression;
+mod for_loop;
 mod function_call;
 mod function_declaration;
+mod function_expression;
 mod identifier;
 mod if_else_statement;
+mod labeled_statement;
 mod member_expression;
 mod object_expression;
 mod return_statement;
+mod switch_statement;
+mod template_literal;
+mod throw_statement;
+mod try_statement;
 mod unary_expression;
 mod variable_assigment;
 mod variable_declaration;
@@ -39,61 +63,151 @@ mod variable_value;
 mod while_loop;
 
 /// Module
+#[derive(Debug)]
 pub struct Module {
     name: String,
     body: Vec<Expression>,
 }
 
 impl Module {
-    pub fn new<R: Read>(name: String, input: R) -> Result<Self, LexerError> {
+    /// Parses the module, all syntax errors are returned at once
+    pub fn new<R: Read>(name: String, input: R) -> Result<Self, Vec<LexerError>> {
         let mut reader = TokenReader::new(input);
+        let body = Self::parse_body(&mut reader);
 
+        let mut errors = reader.take_errors();
+        let body = match body {
+            Ok(body) => Some(body),
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        };
+        // the same unexpected "}" could be reported by the statement and by the module
+        errors.dedup();
+        match body {
+            Some(body) if errors.is_empty() => Ok(Self { name, body }),
+            _ => Err(errors),
+        }
+    }
+
+    fn parse_body<R: Read>(reader: &mut TokenReader<R>) -> Result<Vec<Expression>, LexerError> {
         let mut body = Vec::new();
-        let mut cur_token = reader.next_token()?;
+        let mut cur_token = Expression::next_token_or_recover(reader)?;
 
         loop {
-            let expr = match cur_token {
+            cur_token = match cur_token {
                 Token::Eof => break,
-                cur_token => Expression::parse(cur_token, &mut reader)?,
+                // empty statement
+                Token::Separator(Separator::SemiColon) => {
+                    Expression::next_token_or_recover(reader)?
+                }
+                // "}" without the block
+                Token::Separator(Separator::CloseCurlyBrace) => {
+                    reader.add_error(LexerError::UnexpectedToken(cur_token, reader.span()));
+                    Expression::next_token_or_recover(reader)?
+                }
+                cur_token => {
+                    let (expr, next_token) = Expression::parse_or_recover(cur_token, reader)?;
+                    body.extend(expr);
+                    next_token
+                }
             };
-
-            cur_token = reader.next_token()?;
-            body.push(expr);
         }
-        Ok(Self { name, body })
+        Ok(body)
     }
 
+    /// Precompiles the module, all undefined variables are returned at once
     pub fn precompile<Iter>(
         self,
         predefined_functions: Iter,
-    ) -> Result<llvm_ast::Module, PrecompilerError>
+    ) -> Result<llvm_ast::Module, Vec<PrecompilerError>>
     where
         Iter: Iterator<Item = Identifier>,
     {
         let mut precompiler = Precompiler::new(predefined_functions);
-        let vars_count = precompiler.variables_len();
+        // the module body is precompiled like the function body, predefined functions are never deallocated
+        let body =
+            BlockStatement { body: self.body }.precompile_function_body(&[], &mut precompiler);
 
-        let mut body = Vec::new();
-        for expr in self.body {
-            expr.precompile(&mut precompiler)?
-                .into_iter()
-                .for_each(|expr| body.push(expr));
-        }
-        // Do not deallocate predefined function variables
-        let vars =
-            precompiler.remove_last_added_variables(precompiler.variables_len() - vars_count);
-        for (var, index) in vars {
-            body.push(llvm_ast::Expression::DeallocateExpression(
-                llvm_ast::DeallocateExpression {
-                    name: llvm_ast::Identifier::new(var.name, index),
-                },
-            ));
+        let mut errors = precompiler.take_errors();
+        match body {
+            Ok(body) if errors.is_empty() => Ok(llvm_ast::Module::new(
+                self.name,
+                precompiler.get_function_declarations(),
+                body,
+            )),
+            Ok(_) => Err(errors),
+            Err(e) => {
+                errors.push(e);
+                Err(errors)
+            }
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lexer::{Position, Span};
+
+    #[test]
+    fn parse_module_errors_test() {
+        let module = Module::new(
+            "test".to_string(),
+            "let = 1;\nfoo(;\nlet a = 2;\n{ let b = ; }\n}".as_bytes(),
+        );
+        assert_eq!(
+            module.err(),
+            Some(vec![
+                LexerError::UnexpectedToken(
+                    Token::Assign,
+                    Span::new(Position::new(1, 5), Position::new(1, 5))
+                ),
+                LexerError::UnexpectedToken(
+                    Token::Separator(Separator::SemiColon),
+                    Span::new(Position::new(2, 5), Position::new(2, 5))
+                ),
+                LexerError::UnexpectedToken(
+                    Token::Separator(Separator::SemiColon),
+                    Span::new(Position::new(4, 11), Position::new(4, 11))
+                ),
+                LexerError::UnexpectedToken(
+                    Token::Separator(Separator::CloseCurlyBrace),
+                    Span::new(Position::new(5, 1), Position::new(5, 1))
+                ),
+            ])
+        );
+
+        let module = Module::new(
+            "test".to_string(),
+            "if (a { a = 2; }\nfunction f() { return 1 + ; }\nlet b = 3;".as_bytes(),
+        );
+        assert_eq!(
+            module.err(),
+            Some(vec![
+                LexerError::UnexpectedToken(
+                    Token::Separator(Separator::OpenCurlyBrace),
+                    Span::new(Position::new(1, 7), Position::new(1, 7))
+                ),
+                LexerError::UnexpectedToken(
+                    Token::Separator(Separator::SemiColon),
+                    Span::new(Position::new(2, 27), Position::new(2, 27))
+                ),
+            ])
+        );
 
-        Ok(llvm_ast::Module::new(
-            self.name,
-            precompiler.get_function_declarations(),
-            body,
-        ))
+        // the lexer error is reported as is, not as the failure of the following token
+        let module = Module::new("test".to_string(), "a = 1_;\nb = 2 +;".as_bytes());
+        assert_eq!(
+            module.err(),
+            Some(vec![
+                LexerError::InvalidNumber("1_".to_string(), Position::new(1, 5)),
+                LexerError::UnexpectedToken(
+                    Token::Separator(Separator::SemiColon),
+                    Span::new(Position::new(2, 8), Position::new(2, 8))
+                ),
+            ])
+        );
     }
 }
@@ -27,7 +27,7 @@ impl ObjectExpression {
                                 Token::Separator(Separator::Colon) => {
                                     VariableExpression::parse(reader.next_token()?, reader)
                                 }
-                                token => Err(LexerError::UnexpectedToken(token)),
+                                token => Err(LexerError::UnexpectedToken(token, reader.span())),
                             }?;
                             properties.insert(key, value);
                         }
@@ -35,13 +35,13 @@ impl ObjectExpression {
                     cur_token = match reader.next_token()? {
                         Token::Separator(Separator::CloseCurlyBrace) => break,
                         Token::Separator(Separator::Comma) => reader.next_token()?,
-                        token => return Err(LexerError::UnexpectedToken(token)),
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
                     };
                 }
 
                 Ok(Self { properties })
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
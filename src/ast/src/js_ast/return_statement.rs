@@ -1,11 +1,13 @@
 use super::VariableExpression;
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
-use lexer::{Keyword, Token, TokenReader};
+use lexer::{Keyword, Span, Token, TokenReader};
 use std::io::Read;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ReturnStatement {
-    ret: VariableExpression,
+    pub ret: VariableExpression,
+    /// span of the "return" keyword
+    pub span: Span,
 }
 
 impl ReturnStatement {
@@ -14,10 +16,12 @@ impl ReturnStatement {
         reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
         match cur_token {
-            Token::Keyword(Keyword::Return) => Ok(Self {
-                ret: VariableExpression::parse(reader.next_token()?, reader)?,
-            }),
-            cur_token => Err(LexerError::UnexpectedToken(cur_token)),
+            Token::Keyword(Keyword::Return) => {
+                let span = reader.span();
+                let ret = VariableExpression::parse(reader.next_token()?, reader)?;
+                Ok(Self { ret, span })
+            }
+            cur_token => Err(LexerError::UnexpectedToken(cur_token, reader.span())),
         }
     }
 }
@@ -27,9 +31,22 @@ impl ReturnStatement {
         self,
         precompiler: &mut Precompiler,
     ) -> Result<llvm_ast::ReturnStatement, PrecompilerError> {
-        Ok(llvm_ast::ReturnStatement {
-            ret: self.ret.precompile(precompiler)?,
-        })
+        let ret = self.ret.precompile(precompiler)?;
+        // all variables of the function should be deallocated before leaving it,
+        // the ones declared outside of the crossed try blocks after their finally blocks
+        let deallocations = precompiler
+            .get_return_scope_variables()
+            .into_iter()
+            .map(|variables| {
+                variables
+                    .into_iter()
+                    .map(|(var, index)| llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(llvm_ast::ReturnStatement { ret, deallocations })
     }
 }
 
@@ -37,6 +54,7 @@ impl ReturnStatement {
 mod tests {
     use super::*;
     use crate::js_ast::{VariableExpression, VariableValue};
+    use lexer::Position;
 
     #[test]
     fn parse_return_statement_test() {
@@ -44,7 +62,44 @@ mod tests {
         assert_eq!(
             ReturnStatement::parse(reader.next_token().unwrap(), &mut reader),
             Ok(ReturnStatement {
-                ret: VariableExpression::VariableValue(VariableValue::Null)
+                ret: VariableExpression::VariableValue(VariableValue::Null),
+                span: Span::new(Position::new(1, 1), Position::new(1, 6)),
+            })
+        );
+    }
+
+    #[test]
+    fn precompile_return_statement_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+        precompiler.enter_function_scope(Vec::new());
+        precompiler.insert_variable("b".to_string().into());
+        precompiler.insert_variable("a".to_string().into());
+
+        let return_statement = ReturnStatement {
+            ret: VariableExpression::VariableValue(VariableValue::Identifier(
+                "a".to_string().into(),
+            )),
+            span: Span::default(),
+        };
+
+        assert_eq!(
+            return_statement.precompile(&mut precompiler),
+            Ok(llvm_ast::ReturnStatement {
+                ret: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "a".to_string(),
+                        1
+                    ))
+                ),
+                deallocations: vec![vec![
+                    llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("a".to_string(), 1)
+                    },
+                    llvm_ast::DeallocateExpression {
+                        name: llvm_ast::Identifier::new("b".to_string(), 0)
+                    },
+                ]],
             })
         );
     }
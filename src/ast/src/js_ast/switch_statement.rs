@@ -0,0 +1,263 @@
+use super::{BlockStatement, Expression, VariableExpression};
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Separator, Token, TokenReader};
+use std::io::Read;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchCase {
+    /// `None` for the "default" case
+    pub test: Option<VariableExpression>,
+    pub body: Vec<Expression>,
+}
+
+impl SwitchCase {
+    /// Parses the case body until the next "case", "default" or "}", returns the body and the token after it
+    fn parse_body<R: Read>(
+        reader: &mut TokenReader<R>,
+    ) -> Result<(Vec<Expression>, Token), LexerError> {
+        let mut body = Vec::new();
+        let mut cur_token = Expression::next_token_or_recover(reader)?;
+        loop {
+            cur_token = match cur_token {
+                Token::Keyword(Keyword::Case | Keyword::Default)
+                | Token::Separator(Separator::CloseCurlyBrace) => break,
+                // unclosed switch statement
+                Token::Eof => return Err(LexerError::UnexpectedToken(cur_token, reader.span())),
+                // empty statement
+                Token::Separator(Separator::SemiColon) => {
+                    Expression::next_token_or_recover(reader)?
+                }
+                cur_token => {
+                    let (expr, next_token) = Expression::parse_or_recover(cur_token, reader)?;
+                    body.extend(expr);
+                    next_token
+                }
+            };
+        }
+        Ok((body, cur_token))
+    }
+}
+
+impl SwitchCase {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::SwitchCase, PrecompilerError> {
+        let test = match self.test {
+            Some(test) => Some(test.precompile(precompiler)?),
+            None => None,
+        };
+        // each case body is a separate block scope
+        let body = BlockStatement { body: self.body }.precompile(precompiler)?;
+        Ok(llvm_ast::SwitchCase { test, body })
+    }
+}
+
+/// Switch statement, like "switch (a) { case 1: break; default: }"
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchStatement {
+    pub discriminant: VariableExpression,
+    pub cases: Vec<SwitchCase>,
+}
+
+impl SwitchStatement {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Switch) => match reader.next_token()? {
+                Token::Separator(Separator::OpenBrace) => {
+                    let discriminant = VariableExpression::parse(reader.next_token()?, reader)?;
+                    match reader.next_token()? {
+                        Token::Separator(Separator::CloseBrace) => {}
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+                    }
+                    match reader.next_token()? {
+                        Token::Separator(Separator::OpenCurlyBrace) => {}
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+                    }
+                    Ok(Self {
+                        discriminant,
+                        cases: Self::parse_cases(reader)?,
+                    })
+                }
+                token => Err(LexerError::UnexpectedToken(token, reader.span())),
+            },
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+
+    fn parse_cases<R: Read>(reader: &mut TokenReader<R>) -> Result<Vec<SwitchCase>, LexerError> {
+        let mut cases: Vec<SwitchCase> = Vec::new();
+        let mut cur_token = reader.next_token()?;
+        loop {
+            let has_default = cases.iter().any(|case| case.test.is_none());
+            let test = match cur_token {
+                Token::Separator(Separator::CloseCurlyBrace) => break,
+                Token::Keyword(Keyword::Case) => {
+                    Some(VariableExpression::parse(reader.next_token()?, reader)?)
+                }
+                // only one "default" case is allowed
+                Token::Keyword(Keyword::Default) if !has_default => None,
+                token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+            };
+            match reader.next_token()? {
+                Token::Separator(Separator::Colon) => {}
+                token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+            }
+
+            let (body, next_token) = SwitchCase::parse_body(reader)?;
+            cur_token = next_token;
+            cases.push(SwitchCase { test, body });
+        }
+        Ok(cases)
+    }
+}
+
+impl SwitchStatement {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::SwitchStatement, PrecompilerError> {
+        let discriminant = self.discriminant.precompile(precompiler)?;
+
+        precompiler.enter_switch_scope();
+        let cases = self
+            .cases
+            .into_iter()
+            .map(|case| case.precompile(precompiler))
+            .collect::<Result<Vec<_>, _>>();
+        precompiler.exit_switch_scope();
+
+        Ok(llvm_ast::SwitchStatement {
+            discriminant,
+            cases: cases?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{BreakStatement, DeclarationType, VariableDeclaration, VariableValue};
+    use lexer::{Position, Span};
+
+    #[test]
+    fn parse_switch_statement_test() {
+        let mut reader = TokenReader::new(
+            "switch (a) { case 1: case 2: b; break; default: ; case 3: }".as_bytes(),
+        );
+        assert_eq!(
+            SwitchStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(SwitchStatement {
+                discriminant: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                cases: vec![
+                    SwitchCase {
+                        test: Some(VariableExpression::VariableValue(VariableValue::Number(
+                            1_f64
+                        ))),
+                        body: Vec::new(),
+                    },
+                    SwitchCase {
+                        test: Some(VariableExpression::VariableValue(VariableValue::Number(
+                            2_f64
+                        ))),
+                        body: vec![
+                            Expression::VariableExpression(VariableExpression::VariableValue(
+                                VariableValue::Identifier("b".to_string().into())
+                            )),
+                            Expression::BreakStatement(BreakStatement {
+                                label: None,
+                                span: Span::new(Position::new(1, 33), Position::new(1, 37)),
+                            }),
+                        ],
+                    },
+                    SwitchCase {
+                        test: None,
+                        body: Vec::new(),
+                    },
+                    SwitchCase {
+                        test: Some(VariableExpression::VariableValue(VariableValue::Number(
+                            3_f64
+                        ))),
+                        body: Vec::new(),
+                    },
+                ],
+            })
+        );
+
+        let mut reader = TokenReader::new("switch (a) {}".as_bytes());
+        assert_eq!(
+            SwitchStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(SwitchStatement {
+                discriminant: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "a".to_string().into()
+                )),
+                cases: Vec::new(),
+            })
+        );
+
+        let mut reader = TokenReader::new("switch (a) { default: default: }".as_bytes());
+        assert_eq!(
+            SwitchStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Keyword(Keyword::Default),
+                Span::new(Position::new(1, 23), Position::new(1, 29))
+            ))
+        );
+    }
+
+    #[test]
+    fn precompile_switch_statement_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+
+        let switch_statement = SwitchStatement {
+            discriminant: VariableExpression::VariableValue(VariableValue::Identifier(
+                "a".to_string().into(),
+            )),
+            cases: vec![SwitchCase {
+                test: Some(VariableExpression::VariableValue(VariableValue::Number(
+                    1_f64,
+                ))),
+                body: vec![Expression::VariableDeclaration(VariableDeclaration {
+                    declaration_type: DeclarationType::Let,
+                    name: "b".to_string().into(),
+                    value: None,
+                })],
+            }],
+        };
+        assert_eq!(
+            switch_statement.precompile(&mut precompiler),
+            Ok(llvm_ast::SwitchStatement {
+                discriminant: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "a".to_string(),
+                        0
+                    ))
+                ),
+                cases: vec![llvm_ast::SwitchCase {
+                    test: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(1_f64)
+                    )),
+                    body: vec![
+                        llvm_ast::Expression::VariableDeclaration(llvm_ast::VariableDeclaration {
+                            name: llvm_ast::Identifier::new("b".to_string(), 0),
+                            value: None,
+                        }),
+                        llvm_ast::Expression::DeallocateExpression(
+                            llvm_ast::DeallocateExpression {
+                                name: llvm_ast::Identifier::new("b".to_string(), 0),
+                            }
+                        ),
+                    ],
+                }],
+            })
+        );
+        // case variables are not visible after the switch statement
+        assert_eq!(precompiler.variables_len(), 1);
+    }
+}
@@ -0,0 +1,189 @@
+use super::VariableExpression;
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Span, Template, Token, TokenReader};
+use std::io::Read;
+
+/// Template literal, like "`a ${b} c`"
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateLiteral {
+    /// string parts, there is always one more part than the substitutions
+    pub quasis: Vec<String>,
+    pub expressions: Vec<VariableExpression>,
+}
+
+impl TemplateLiteral {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Template(Template::NoSubstitution(string)) => Ok(Self {
+                quasis: vec![string],
+                expressions: Vec::new(),
+            }),
+            Token::Template(Template::Head(string)) => {
+                let mut quasis = vec![string];
+                let mut expressions = Vec::new();
+                loop {
+                    expressions.push(VariableExpression::parse(reader.next_token()?, reader)?);
+                    match reader.next_token()? {
+                        Token::Template(Template::Middle(string)) => quasis.push(string),
+                        Token::Template(Template::Tail(string)) => {
+                            quasis.push(string);
+                            break;
+                        }
+                        token => return Err(LexerError::UnexpectedToken(token, reader.span())),
+                    }
+                }
+                Ok(Self {
+                    quasis,
+                    expressions,
+                })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl TemplateLiteral {
+    /// Lowers the template literal to the chain of additions, which starts with the string,
+    /// so each substitution is converted to the string and concatenated
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::VariableExpression, PrecompilerError> {
+        let concat = |left, right| {
+            llvm_ast::VariableExpression::BinaryExpression(
+                llvm_ast::BinaryExpression {
+                    left,
+                    right,
+                    exp_type: llvm_ast::BinaryExpType::Add,
+                    span: Span::default(),
+                }
+                .into(),
+            )
+        };
+
+        let mut quasis = self.quasis.into_iter();
+        let mut res = llvm_ast::VariableExpression::VariableValue(llvm_ast::VariableValue::String(
+            quasis.next().unwrap_or_default(),
+        ));
+        for (expr, quasi) in self.expressions.into_iter().zip(quasis) {
+            res = concat(res, expr.precompile(precompiler)?);
+            if !quasi.is_empty() {
+                res = concat(
+                    res,
+                    llvm_ast::VariableExpression::VariableValue(llvm_ast::VariableValue::String(
+                        quasi,
+                    )),
+                );
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{BinaryExpType, BinaryExpression, VariableValue};
+    use lexer::{Position, Span};
+
+    #[test]
+    fn parse_template_literal_test() {
+        let mut reader = TokenReader::new("`hello`".as_bytes());
+        assert_eq!(
+            TemplateLiteral::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(TemplateLiteral {
+                quasis: vec!["hello".to_string()],
+                expressions: vec![],
+            })
+        );
+
+        let mut reader = TokenReader::new("`a ${b + 1} c ${d}`".as_bytes());
+        assert_eq!(
+            TemplateLiteral::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(TemplateLiteral {
+                quasis: vec!["a ".to_string(), " c ".to_string(), String::new()],
+                expressions: vec![
+                    VariableExpression::BinaryExpression(
+                        BinaryExpression {
+                            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "b".to_string().into()
+                            )),
+                            right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                            exp_type: BinaryExpType::Add,
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    VariableExpression::VariableValue(VariableValue::Identifier(
+                        "d".to_string().into()
+                    )),
+                ],
+            })
+        );
+
+        let mut reader = TokenReader::new("`a ${}`".as_bytes());
+        assert_eq!(
+            TemplateLiteral::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Template(Template::Tail(String::new())),
+                Span::new(Position::new(1, 6), Position::new(1, 7))
+            ))
+        );
+    }
+
+    #[test]
+    fn precompile_template_literal_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("b".to_string().into());
+
+        let template_literal = TemplateLiteral {
+            quasis: vec!["a".to_string(), "c".to_string()],
+            expressions: vec![VariableExpression::VariableValue(
+                VariableValue::Identifier("b".to_string().into()),
+            )],
+        };
+        assert_eq!(
+            template_literal.precompile(&mut precompiler),
+            Ok(llvm_ast::VariableExpression::BinaryExpression(
+                llvm_ast::BinaryExpression {
+                    left: llvm_ast::VariableExpression::BinaryExpression(
+                        llvm_ast::BinaryExpression {
+                            left: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::String("a".to_string())
+                            ),
+                            right: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                    "b".to_string(),
+                                    0
+                                ))
+                            ),
+                            exp_type: llvm_ast::BinaryExpType::Add,
+                            span: Span::default(),
+                        }
+                        .into()
+                    ),
+                    right: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::String("c".to_string())
+                    ),
+                    exp_type: llvm_ast::BinaryExpType::Add,
+                    span: Span::default(),
+                }
+                .into()
+            ))
+        );
+
+        let template_literal = TemplateLiteral {
+            quasis: vec!["a".to_string()],
+            expressions: vec![],
+        };
+        assert_eq!(
+            template_literal.precompile(&mut precompiler),
+            Ok(llvm_ast::VariableExpression::VariableValue(
+                llvm_ast::VariableValue::String("a".to_string())
+            ))
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use super::VariableExpression;
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Span, Token, TokenReader};
+use std::io::Read;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrowStatement {
+    pub value: VariableExpression,
+    /// span of the "throw" keyword
+    pub span: Span,
+}
+
+impl ThrowStatement {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Throw) => {
+                let span = reader.span();
+                let value = VariableExpression::parse(reader.next_token()?, reader)?;
+                Ok(Self { value, span })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl ThrowStatement {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::ThrowStatement, PrecompilerError> {
+        let value = self.value.precompile(precompiler)?;
+        let deallocations = precompiler
+            .get_exception_scope_variables()
+            .into_iter()
+            .map(|(var, index)| llvm_ast::DeallocateExpression {
+                name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+            })
+            .collect();
+        Ok(llvm_ast::ThrowStatement {
+            value,
+            deallocations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::VariableValue;
+    use lexer::Position;
+
+    #[test]
+    fn parse_throw_statement_test() {
+        let mut reader = TokenReader::new("throw 5;".as_bytes());
+        assert_eq!(
+            ThrowStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(ThrowStatement {
+                value: VariableExpression::VariableValue(VariableValue::Number(5_f64)),
+                span: Span::new(Position::new(1, 1), Position::new(1, 5)),
+            })
+        );
+    }
+
+    #[test]
+    fn precompile_throw_statement_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+        precompiler.enter_try_scope(false);
+        precompiler.insert_variable("b".to_string().into());
+
+        let throw_statement = ThrowStatement {
+            value: VariableExpression::VariableValue(VariableValue::Identifier(
+                "a".to_string().into(),
+            )),
+            span: Span::default(),
+        };
+        assert_eq!(
+            throw_statement.precompile(&mut precompiler),
+            Ok(llvm_ast::ThrowStatement {
+                value: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "a".to_string(),
+                        0
+                    ))
+                ),
+                deallocations: vec![llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::new("b".to_string(), 0),
+                }],
+            })
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use super::{BlockStatement, Identifier};
+use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
+use lexer::{Keyword, Separator, Token, TokenReader};
+use std::io::Read;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatchClause {
+    pub param: Option<Identifier>,
+    pub body: BlockStatement,
+}
+
+impl CatchClause {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Catch) => match reader.next_token()? {
+                Token::Separator(Separator::OpenBrace) => {
+                    let param = Identifier::parse(reader.next_token()?, reader)?;
+                    match reader.next_token()? {
+                        Token::Separator(Separator::CloseBrace) => Ok(Self {
+                            param: Some(param),
+                            body: BlockStatement::parse(reader.next_token()?, reader)?,
+                        }),
+                        token => Err(LexerError::UnexpectedToken(token, reader.span())),
+                    }
+                }
+                // "catch" without the exception variable
+                cur_token => Ok(Self {
+                    param: None,
+                    body: BlockStatement::parse(cur_token, reader)?,
+                }),
+            },
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl CatchClause {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+        has_finally: bool,
+    ) -> Result<llvm_ast::CatchClause, PrecompilerError> {
+        // exceptions thrown inside the catch block are handled by the finally block
+        if has_finally {
+            precompiler.enter_try_scope(true);
+        }
+        let variables_len = precompiler.variables_len();
+        let param = self.param.map(|param| {
+            let index = precompiler.insert_variable(param.clone());
+            llvm_ast::Identifier::with_span(param.name, index, param.span)
+        });
+        let body = self.body.precompile(precompiler);
+        let vars =
+            precompiler.remove_last_added_variables(precompiler.variables_len() - variables_len);
+        if has_finally {
+            precompiler.exit_try_scope();
+        }
+        let mut body = body?;
+
+        for (var, index) in vars {
+            body.push(llvm_ast::Expression::DeallocateExpression(
+                llvm_ast::DeallocateExpression {
+                    name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+                },
+            ));
+        }
+        Ok(llvm_ast::CatchClause { param, body })
+    }
+}
+
+/// Try statement, like "try {} catch (e) {} finally {}"
+#[derive(Clone, Debug, PartialEq)]
+pub struct TryStatement {
+    pub try_block: BlockStatement,
+    pub catch: Option<CatchClause>,
+    pub finally: Option<BlockStatement>,
+}
+
+impl TryStatement {
+    pub fn parse<R: Read>(
+        cur_token: Token,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
+        match cur_token {
+            Token::Keyword(Keyword::Try) => {
+                let try_block = BlockStatement::parse(reader.next_token()?, reader)?;
+
+                let catch = match reader.peek()? {
+                    Token::Keyword(Keyword::Catch) => {
+                        Some(CatchClause::parse(reader.next_token()?, reader)?)
+                    }
+                    _ => None,
+                };
+
+                let finally = match reader.peek()? {
+                    Token::Keyword(Keyword::Finally) => {
+                        reader.next_token()?;
+                        Some(BlockStatement::parse(reader.next_token()?, reader)?)
+                    }
+                    _ if catch.is_none() => {
+                        return Err(LexerError::UnexpectedToken(
+                            reader.next_token()?,
+                            reader.span(),
+                        ));
+                    }
+                    _ => None,
+                };
+
+                Ok(Self {
+                    try_block,
+                    catch,
+                    finally,
+                })
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
+        }
+    }
+}
+
+impl TryStatement {
+    pub fn precompile(
+        self,
+        precompiler: &mut Precompiler,
+    ) -> Result<llvm_ast::TryStatement, PrecompilerError> {
+        // exception, which is not caught, is rethrown after the finally block
+        let deallocations = precompiler
+            .get_exception_scope_variables()
+            .into_iter()
+            .map(|(var, index)| llvm_ast::DeallocateExpression {
+                name: llvm_ast::Identifier::with_span(var.name, index, var.span),
+            })
+            .collect();
+
+        precompiler.enter_try_scope(self.finally.is_some());
+        let try_block = self.try_block.precompile(precompiler);
+        precompiler.exit_try_scope();
+        let try_block = try_block?;
+
+        let catch = match self.catch {
+            Some(catch) => Some(catch.precompile(precompiler, self.finally.is_some())?),
+            None => None,
+        };
+        let finally = match self.finally {
+            Some(finally) => Some(finally.precompile(precompiler)?),
+            None => None,
+        };
+
+        Ok(llvm_ast::TryStatement {
+            try_block,
+            catch,
+            finally,
+            deallocations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_ast::{Expression, ThrowStatement, VariableExpression, VariableValue};
+    use lexer::{Position, Span};
+
+    #[test]
+    fn parse_try_statement_test() {
+        let mut reader = TokenReader::new("try { throw 5; } catch (e) {}".as_bytes());
+        assert_eq!(
+            TryStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(TryStatement {
+                try_block: BlockStatement {
+                    body: vec![Expression::ThrowStatement(ThrowStatement {
+                        value: VariableExpression::VariableValue(VariableValue::Number(5_f64)),
+                        span: Span::new(Position::new(1, 7), Position::new(1, 11)),
+                    })]
+                },
+                catch: Some(CatchClause {
+                    param: Some("e".to_string().into()),
+                    body: BlockStatement { body: Vec::new() },
+                }),
+                finally: None,
+            })
+        );
+
+        let mut reader = TokenReader::new("try {} catch {} finally {}".as_bytes());
+        assert_eq!(
+            TryStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(TryStatement {
+                try_block: BlockStatement { body: Vec::new() },
+                catch: Some(CatchClause {
+                    param: None,
+                    body: BlockStatement { body: Vec::new() },
+                }),
+                finally: Some(BlockStatement { body: Vec::new() }),
+            })
+        );
+
+        let mut reader = TokenReader::new("try {} finally {}".as_bytes());
+        assert_eq!(
+            TryStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(TryStatement {
+                try_block: BlockStatement { body: Vec::new() },
+                catch: None,
+                finally: Some(BlockStatement { body: Vec::new() }),
+            })
+        );
+
+        let mut reader = TokenReader::new("try {} 5".as_bytes());
+        assert_eq!(
+            TryStatement::parse(reader.next_token().unwrap(), &mut reader),
+            Err(LexerError::UnexpectedToken(
+                Token::Literal(lexer::Literal::Number(5_f64)),
+                Span::new(Position::new(1, 8), Position::new(1, 8))
+            ))
+        );
+    }
+
+    #[test]
+    fn precompile_try_statement_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+
+        let try_statement = TryStatement {
+            try_block: BlockStatement { body: Vec::new() },
+            catch: Some(CatchClause {
+                param: Some("e".to_string().into()),
+                body: BlockStatement {
+                    body: vec![Expression::ThrowStatement(ThrowStatement {
+                        value: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "e".to_string().into(),
+                        )),
+                        span: Span::default(),
+                    })],
+                },
+            }),
+            finally: Some(BlockStatement { body: Vec::new() }),
+        };
+
+        assert_eq!(
+            try_statement.precompile(&mut precompiler),
+            Ok(llvm_ast::TryStatement {
+                try_block: Vec::new(),
+                catch: Some(llvm_ast::CatchClause {
+                    param: Some(llvm_ast::Identifier::new("e".to_string(), 0)),
+                    body: vec![
+                        llvm_ast::Expression::ThrowStatement(llvm_ast::ThrowStatement {
+                            value: llvm_ast::VariableExpression::VariableValue(
+                                llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                                    "e".to_string(),
+                                    0
+                                ))
+                            ),
+                            deallocations: vec![llvm_ast::DeallocateExpression {
+                                name: llvm_ast::Identifier::new("e".to_string(), 0),
+                            }],
+                        }),
+                        llvm_ast::Expression::DeallocateExpression(
+                            llvm_ast::DeallocateExpression {
+                                name: llvm_ast::Identifier::new("e".to_string(), 0),
+                            }
+                        ),
+                    ],
+                }),
+                finally: Some(Vec::new()),
+                deallocations: Vec::new(),
+            })
+        );
+        // exception variable is not visible after the catch block
+        assert_eq!(precompiler.variables_len(), 0);
+    }
+}
@@ -1,15 +1,34 @@
-use super::VariableExpression;
+use super::{VariableExpression, VariableValue};
 use crate::{llvm_ast, Precompiler, PrecompilerError};
+use lexer::Span;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct UnaryExpression {
     pub exp: VariableExpression,
     pub exp_type: UnaryExpType,
+    /// span of the operator, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for UnaryExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.exp == other.exp && self.exp_type == other.exp_type
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum UnaryExpType {
     Not,
+    BitNot,
+    Neg,
+    Plus,
+    Typeof,
+    Void,
+    // Update
+    PrefixInc,
+    PrefixDec,
+    PostfixInc,
+    PostfixDec,
 }
 
 #[allow(clippy::from_over_into)]
@@ -17,6 +36,16 @@ impl Into<llvm_ast::UnaryExpType> for UnaryExpType {
     fn into(self) -> llvm_ast::UnaryExpType {
         match self {
             Self::Not => llvm_ast::UnaryExpType::Not,
+            Self::BitNot => llvm_ast::UnaryExpType::BitNot,
+            Self::Neg => llvm_ast::UnaryExpType::Neg,
+            Self::Plus => llvm_ast::UnaryExpType::Plus,
+            Self::Typeof => llvm_ast::UnaryExpType::Typeof,
+            Self::Void => llvm_ast::UnaryExpType::Void,
+            // Update
+            Self::PrefixInc => llvm_ast::UnaryExpType::PrefixInc,
+            Self::PrefixDec => llvm_ast::UnaryExpType::PrefixDec,
+            Self::PostfixInc => llvm_ast::UnaryExpType::PostfixInc,
+            Self::PostfixDec => llvm_ast::UnaryExpType::PostfixDec,
         }
     }
 }
@@ -26,9 +55,144 @@ impl UnaryExpression {
         self,
         precompiler: &mut Precompiler,
     ) -> Result<llvm_ast::UnaryExpression, PrecompilerError> {
+        // "typeof" of the undeclared variable is "undefined"
+        if let (
+            UnaryExpType::Typeof,
+            VariableExpression::VariableValue(VariableValue::Identifier(identifier)),
+        ) = (&self.exp_type, &self.exp)
+        {
+            if precompiler.get_variable(identifier.clone()).is_err() {
+                return Ok(llvm_ast::UnaryExpression {
+                    exp: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Undefined,
+                    ),
+                    exp_type: llvm_ast::UnaryExpType::Typeof,
+                    span: self.span,
+                });
+            }
+        }
+        let exp = match self.exp_type {
+            UnaryExpType::PrefixInc
+            | UnaryExpType::PrefixDec
+            | UnaryExpType::PostfixInc
+            | UnaryExpType::PostfixDec => self
+                .exp
+                .precompile_assigment_target(precompiler, self.span.clone())?,
+            _ => self.exp.precompile(precompiler)?,
+        };
         Ok(llvm_ast::UnaryExpression {
-            exp: self.exp.precompile(precompiler)?,
+            exp,
             exp_type: self.exp_type.into(),
+            span: self.span,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lexer::Position;
+
+    #[test]
+    fn precompile_unary_expression_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("a".to_string().into());
+
+        let unary_expression = UnaryExpression {
+            exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                "a".to_string().into(),
+            )),
+            exp_type: UnaryExpType::Typeof,
+            span: Span::default(),
+        };
+        assert_eq!(
+            unary_expression.precompile(&mut precompiler),
+            Ok(llvm_ast::UnaryExpression {
+                exp: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "a".to_string(),
+                        0
+                    ))
+                ),
+                exp_type: llvm_ast::UnaryExpType::Typeof,
+                span: Span::default(),
+            })
+        );
+
+        // undeclared variable is allowed only for "typeof"
+        let unary_expression = UnaryExpression {
+            exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                "b".to_string().into(),
+            )),
+            exp_type: UnaryExpType::Typeof,
+            span: Span::default(),
+        };
+        assert_eq!(
+            unary_expression.precompile(&mut precompiler),
+            Ok(llvm_ast::UnaryExpression {
+                exp: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Undefined
+                ),
+                exp_type: llvm_ast::UnaryExpType::Typeof,
+                span: Span::default(),
+            })
+        );
+
+        let unary_expression = UnaryExpression {
+            exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                "b".to_string().into(),
+            )),
+            exp_type: UnaryExpType::Neg,
+            span: Span::default(),
+        };
+        assert_eq!(
+            unary_expression.precompile(&mut precompiler),
+            Ok(llvm_ast::UnaryExpression {
+                exp: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "b".to_string(),
+                        0
+                    ))
+                ),
+                exp_type: llvm_ast::UnaryExpType::Neg,
+                span: Span::default(),
+            })
+        );
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![precompiler::Error::UndefinedVariable(
+                "b".to_string().into()
+            )]
+        );
+
+        // constant could not be updated
+        precompiler.insert_const_variable("c".to_string().into());
+        let unary_expression = UnaryExpression {
+            exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                "c".to_string().into(),
+            )),
+            exp_type: UnaryExpType::PostfixInc,
+            span: Span::default(),
+        };
+        assert_eq!(
+            unary_expression.precompile(&mut precompiler),
+            Err(precompiler::Error::ConstantAssigment(
+                "c".to_string().into(),
+            ))
+        );
+
+        // only the variable or the property could be updated
+        let unary_expression = UnaryExpression {
+            exp: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+            exp_type: UnaryExpType::PrefixInc,
+            span: Span::new(Position::new(1, 1), Position::new(1, 2)),
+        };
+        assert_eq!(
+            unary_expression.precompile(&mut precompiler),
+            Err(precompiler::Error::InvalidAssigmentTarget(Span::new(
+                Position::new(1, 1),
+                Position::new(1, 2)
+            )))
+        );
+    }
+}
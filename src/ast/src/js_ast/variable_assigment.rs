@@ -1,12 +1,20 @@
 use super::VariableExpression;
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
-use lexer::{Token, TokenReader};
+use lexer::{Span, Token, TokenReader};
 use std::io::Read;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct VariableAssigment {
     pub left: VariableExpression,
     pub right: VariableExpression,
+    /// span of the "=" token, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for VariableAssigment {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right
+    }
 }
 
 impl VariableAssigment {
@@ -15,12 +23,21 @@ impl VariableAssigment {
         reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
         let left = VariableExpression::parse(cur_token, reader)?;
+        Self::parse_rest(left, reader)
+    }
+
+    /// Parses the "= b" part after the already parsed `left`
+    pub fn parse_rest<R: Read>(
+        left: VariableExpression,
+        reader: &mut TokenReader<R>,
+    ) -> Result<Self, LexerError> {
         match reader.next_token()? {
             Token::Assign => {
+                let span = reader.span();
                 let right = VariableExpression::parse(reader.next_token()?, reader)?;
-                Ok(Self { left, right })
+                Ok(Self { left, right, span })
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -30,9 +47,15 @@ impl VariableAssigment {
         self,
         precompiler: &mut Precompiler,
     ) -> Result<llvm_ast::VariableAssigment, PrecompilerError> {
-        let left = self.left.precompile(precompiler)?;
+        let left = self
+            .left
+            .precompile_assigment_target(precompiler, self.span.clone())?;
         let right = self.right.precompile(precompiler)?;
-        Ok(llvm_ast::VariableAssigment { left, right })
+        Ok(llvm_ast::VariableAssigment {
+            left,
+            right,
+            span: self.span,
+        })
     }
 }
 
@@ -43,9 +66,18 @@ mod tests {
         BinaryExpType, BinaryExpression, FunctionCall, ObjectExpression, VariableExpression,
         VariableValue,
     };
+    use lexer::Position;
 
     #[test]
     fn parse_assigment_expression_test() {
+        let mut reader = TokenReader::new("a = b".as_bytes());
+        let variable_assigment =
+            VariableAssigment::parse(reader.next_token().unwrap(), &mut reader).unwrap();
+        assert_eq!(
+            variable_assigment.span,
+            Span::new(Position::new(1, 3), Position::new(1, 3))
+        );
+
         let mut reader = TokenReader::new("name = 12;".as_bytes());
         assert_eq!(
             VariableAssigment::parse(reader.next_token().unwrap(), &mut reader).unwrap(),
@@ -53,7 +85,8 @@ mod tests {
                 left: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name".to_string().into()
                 )),
-                right: VariableExpression::VariableValue(VariableValue::Number(12_f64))
+                right: VariableExpression::VariableValue(VariableValue::Number(12_f64)),
+                span: Span::default(),
             }
         );
 
@@ -66,7 +99,8 @@ mod tests {
                 )),
                 right: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name2".to_string().into()
-                ))
+                )),
+                span: Span::default(),
             }
         );
 
@@ -76,11 +110,13 @@ mod tests {
             VariableAssigment {
                 left: VariableExpression::FunctionCall(FunctionCall {
                     name: "foo".to_string().into(),
-                    args: vec![]
+                    args: vec![],
+                    span: Span::default(),
                 }),
                 right: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name2".to_string().into()
-                ))
+                )),
+                span: Span::default(),
             }
         );
 
@@ -93,12 +129,14 @@ mod tests {
                         left: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
                         right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
                         exp_type: BinaryExpType::Add,
+                        span: Span::default(),
                     }
                     .into()
                 ),
                 right: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name2".to_string().into()
-                ))
+                )),
+                span: Span::default(),
             }
         );
 
@@ -120,7 +158,8 @@ mod tests {
                 )),
                 right: VariableExpression::VariableValue(VariableValue::Identifier(
                     "name2".to_string().into()
-                ))
+                )),
+                span: Span::default(),
             }
         );
     }
@@ -135,6 +174,7 @@ mod tests {
                 "name_1".to_string().into(),
             )),
             right: VariableExpression::VariableValue(VariableValue::Number(64_f64)),
+            span: Span::default(),
         };
 
         assert_eq!(
@@ -149,6 +189,7 @@ mod tests {
                 right: llvm_ast::VariableExpression::VariableValue(
                     llvm_ast::VariableValue::FloatNumber(64_f64)
                 ),
+                span: Span::default(),
             })
         );
         assert_eq!(precompiler.get_variable("name_1".to_string().into()), Ok(0));
@@ -167,6 +208,7 @@ mod tests {
             right: VariableExpression::VariableValue(VariableValue::Identifier(
                 "name_2".to_string().into(),
             )),
+            span: Span::default(),
         };
 
         assert_eq!(
@@ -184,6 +226,7 @@ mod tests {
                         0
                     ))
                 ),
+                span: Span::default(),
             })
         );
         assert_eq!(precompiler.get_variable("name_1".to_string().into()), Ok(0));
@@ -198,13 +241,29 @@ mod tests {
                 "name_1".to_string().into(),
             )),
             right: VariableExpression::VariableValue(VariableValue::Number(64_f64)),
+            span: Span::default(),
         };
 
         assert_eq!(
             variable_assigment.precompile(&mut precompiler),
-            Err(precompiler::Error::UndefinedVariable(
-                "name_1".to_string().into(),
-            ))
+            Ok(llvm_ast::VariableAssigment {
+                left: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "name_1".to_string(),
+                        0
+                    ))
+                ),
+                right: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::FloatNumber(64_f64)
+                ),
+                span: Span::default(),
+            })
+        );
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![precompiler::Error::UndefinedVariable(
+                "name_1".to_string().into()
+            )]
         );
     }
 
@@ -220,13 +279,97 @@ mod tests {
             right: VariableExpression::VariableValue(VariableValue::Identifier(
                 "name_2".to_string().into(),
             )),
+            span: Span::default(),
         };
 
         assert_eq!(
             variable_assigment.precompile(&mut precompiler),
-            Err(precompiler::Error::UndefinedVariable(
-                "name_2".to_string().into(),
+            Ok(llvm_ast::VariableAssigment {
+                left: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "name_1".to_string(),
+                        0
+                    ))
+                ),
+                right: llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                        "name_2".to_string(),
+                        0
+                    ))
+                ),
+                span: Span::default(),
+            })
+        );
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![precompiler::Error::UndefinedVariable(
+                "name_2".to_string().into()
+            )]
+        );
+    }
+
+    #[test]
+    fn precompile_variable_assigment_error_test_3() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_const_variable("name_1".to_string().into());
+
+        let variable_assigment = VariableAssigment {
+            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                "name_1".to_string().into(),
+            )),
+            right: VariableExpression::VariableValue(VariableValue::Number(64_f64)),
+            span: Span::default(),
+        };
+
+        assert_eq!(
+            variable_assigment.precompile(&mut precompiler),
+            Err(precompiler::Error::ConstantAssigment(
+                "name_1".to_string().into(),
             ))
         );
     }
+
+    #[test]
+    fn precompile_variable_assigment_error_test_4() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("name_1".to_string().into());
+
+        // the function call result could not be assigned
+        let variable_assigment = VariableAssigment {
+            left: VariableExpression::FunctionCall(FunctionCall {
+                name: "name_1".to_string().into(),
+                args: vec![],
+                span: Span::default(),
+            }),
+            right: VariableExpression::VariableValue(VariableValue::Number(64_f64)),
+            span: Span::new(Position::new(1, 10), Position::new(1, 10)),
+        };
+        assert_eq!(
+            variable_assigment.precompile(&mut precompiler),
+            Err(precompiler::Error::InvalidAssigmentTarget(Span::new(
+                Position::new(1, 10),
+                Position::new(1, 10)
+            )))
+        );
+
+        let variable_assigment = VariableAssigment {
+            left: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                left: VariableExpression::VariableValue(VariableValue::Identifier(
+                    "name_1".to_string().into(),
+                )),
+                right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                exp_type: BinaryExpType::Add,
+                span: Span::default(),
+            })),
+            right: VariableExpression::VariableValue(VariableValue::Number(64_f64)),
+            span: Span::new(Position::new(1, 12), Position::new(1, 12)),
+        };
+        assert_eq!(
+            variable_assigment.precompile(&mut precompiler),
+            Err(precompiler::Error::InvalidAssigmentTarget(Span::new(
+                Position::new(1, 12),
+                Position::new(1, 12)
+            )))
+        );
+    }
 }
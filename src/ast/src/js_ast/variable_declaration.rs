@@ -1,29 +1,44 @@
-use super::{Identifier, VariableExpression};
+use super::{Identifier, VariableAssigment, VariableExpression, VariableValue};
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
 use lexer::{Keyword, Token, TokenReader};
 use std::io::Read;
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeclarationType {
+    Var,
+    Let,
+    Const,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariableDeclaration {
+    pub declaration_type: DeclarationType,
     pub name: Identifier,
     pub value: Option<VariableExpression>,
 }
 
 impl VariableDeclaration {
     fn parse_impl<R: Read>(
+        declaration_type: DeclarationType,
         cur_token: Token,
         reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
         let name = Identifier::parse(cur_token, reader)?;
 
-        reader.start_saving();
-        if let Token::Assign = reader.next_token()? {
-            reader.reset_saving();
+        if let Token::Assign = reader.peek()? {
+            reader.next_token()?;
             let value = Some(VariableExpression::parse(reader.next_token()?, reader)?);
-            Ok(Self { name, value })
+            Ok(Self {
+                declaration_type,
+                name,
+                value,
+            })
         } else {
-            reader.stop_saving();
-            Ok(Self { name, value: None })
+            Ok(Self {
+                declaration_type,
+                name,
+                value: None,
+            })
         }
     }
 
@@ -32,10 +47,16 @@ impl VariableDeclaration {
         reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
         match cur_token {
-            Token::Keyword(Keyword::Var | Keyword::Let) => {
-                Self::parse_impl(reader.next_token()?, reader)
+            Token::Keyword(Keyword::Var) => {
+                Self::parse_impl(DeclarationType::Var, reader.next_token()?, reader)
             }
-            token => Err(LexerError::UnexpectedToken(token)),
+            Token::Keyword(Keyword::Let) => {
+                Self::parse_impl(DeclarationType::Let, reader.next_token()?, reader)
+            }
+            Token::Keyword(Keyword::Const) => {
+                Self::parse_impl(DeclarationType::Const, reader.next_token()?, reader)
+            }
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -44,23 +65,67 @@ impl VariableDeclaration {
     pub fn precompile(
         self,
         precompiler: &mut Precompiler,
-    ) -> Result<llvm_ast::VariableDeclaration, PrecompilerError> {
+    ) -> Result<Vec<llvm_ast::Expression>, PrecompilerError> {
+        if self.declaration_type == DeclarationType::Var {
+            // "var" is already declared at the beginning of the function, only the initialization is left
+            return match self.value {
+                Some(value) => Ok(vec![llvm_ast::Expression::VariableAssigment(
+                    VariableAssigment {
+                        // the declaration keeps only the position of the name
+                        span: self.name.span.clone(),
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            self.name,
+                        )),
+                        right: value,
+                    }
+                    .precompile(precompiler)?,
+                )]),
+                None => Ok(Vec::new()),
+            };
+        }
+
         let value = match self.value {
             Some(expr) => Some(expr.precompile(precompiler)?),
+            None if self.declaration_type == DeclarationType::Const => {
+                return Err(precompiler::Error::MissingInitializer(self.name));
+            }
             None => None,
         };
-        let index = precompiler.insert_variable(self.name.clone());
-        Ok(llvm_ast::VariableDeclaration {
-            name: llvm_ast::Identifier::new(self.name.name, index),
-            value,
-        })
+        if let Some(index) = precompiler.initialize_variable(&self.name) {
+            // the variable is already declared at the beginning of the block, only the initialization is left
+            let span = self.name.span.clone();
+            let name = llvm_ast::Identifier::with_span(self.name.name, index, self.name.span);
+            return Ok(value
+                .map(|value| {
+                    llvm_ast::Expression::VariableAssigment(llvm_ast::VariableAssigment {
+                        left: llvm_ast::VariableExpression::VariableValue(
+                            llvm_ast::VariableValue::Identifier(name),
+                        ),
+                        right: value,
+                        span,
+                    })
+                })
+                .into_iter()
+                .collect());
+        }
+        let index = if self.declaration_type == DeclarationType::Const {
+            precompiler.insert_const_variable(self.name.clone())
+        } else {
+            precompiler.insert_variable(self.name.clone())
+        };
+        Ok(vec![llvm_ast::Expression::VariableDeclaration(
+            llvm_ast::VariableDeclaration {
+                name: llvm_ast::Identifier::with_span(self.name.name, index, self.name.span),
+                value,
+            },
+        )])
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::js_ast::{VariableExpression, VariableValue};
+    use lexer::Span;
 
     #[test]
     fn parse_variable_declaration_test1() {
@@ -68,6 +133,7 @@ mod tests {
         assert_eq!(
             VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
             Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Var,
                 name: "name".to_string().into(),
                 value: Some(VariableExpression::VariableValue(VariableValue::Number(
                     12_f64
@@ -79,6 +145,7 @@ mod tests {
         assert_eq!(
             VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
             Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Var,
                 name: "name1".to_string().into(),
                 value: Some(VariableExpression::VariableValue(
                     VariableValue::Identifier("name2".to_string().into())
@@ -90,6 +157,7 @@ mod tests {
         assert_eq!(
             VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
             Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Var,
                 name: "name1".to_string().into(),
                 value: None
             })
@@ -102,6 +170,7 @@ mod tests {
         assert_eq!(
             VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
             Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Let,
                 name: "name".to_string().into(),
                 value: Some(VariableExpression::VariableValue(VariableValue::Number(
                     12_f64
@@ -113,6 +182,7 @@ mod tests {
         assert_eq!(
             VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
             Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Let,
                 name: "name1".to_string().into(),
                 value: Some(VariableExpression::VariableValue(
                     VariableValue::Identifier("name2".to_string().into())
@@ -124,17 +194,44 @@ mod tests {
         assert_eq!(
             VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
             Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Let,
                 name: "name1".to_string().into(),
                 value: None
             })
         );
     }
 
+    #[test]
+    fn parse_variable_declaration_test3() {
+        let mut reader = TokenReader::new("const name = 12;".as_bytes());
+        assert_eq!(
+            VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Const,
+                name: "name".to_string().into(),
+                value: Some(VariableExpression::VariableValue(VariableValue::Number(
+                    12_f64
+                )))
+            })
+        );
+
+        let mut reader = TokenReader::new("const name;".as_bytes());
+        assert_eq!(
+            VariableDeclaration::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableDeclaration {
+                declaration_type: DeclarationType::Const,
+                name: "name".to_string().into(),
+                value: None
+            })
+        );
+    }
+
     #[test]
     fn precompile_variable_declaration_test_1() {
         let mut precompiler = Precompiler::new(std::iter::empty());
 
         let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Let,
             name: "name_1".to_string().into(),
             value: Some(VariableExpression::VariableValue(VariableValue::Number(
                 64_f64,
@@ -143,12 +240,14 @@ mod tests {
 
         assert_eq!(
             variable_declaration.precompile(&mut precompiler),
-            Ok(llvm_ast::VariableDeclaration {
-                name: llvm_ast::Identifier::new("name_1".to_string(), 0),
-                value: Some(llvm_ast::VariableExpression::VariableValue(
-                    llvm_ast::VariableValue::FloatNumber(64_f64)
-                )),
-            })
+            Ok(vec![llvm_ast::Expression::VariableDeclaration(
+                llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    value: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(64_f64)
+                    )),
+                }
+            )])
         );
         assert_eq!(precompiler.get_variable("name_1".to_string().into()), Ok(0));
     }
@@ -159,6 +258,7 @@ mod tests {
         precompiler.insert_variable("name_2".to_string().into());
 
         let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Let,
             name: "name_1".to_string().into(),
             value: Some(VariableExpression::VariableValue(
                 VariableValue::Identifier("name_2".to_string().into()),
@@ -167,15 +267,17 @@ mod tests {
 
         assert_eq!(
             variable_declaration.precompile(&mut precompiler),
-            Ok(llvm_ast::VariableDeclaration {
-                name: llvm_ast::Identifier::new("name_1".to_string(), 0),
-                value: Some(llvm_ast::VariableExpression::VariableValue(
-                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
-                        "name_2".to_string(),
-                        0
-                    ))
-                )),
-            })
+            Ok(vec![llvm_ast::Expression::VariableDeclaration(
+                llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    value: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_2".to_string(),
+                            0
+                        ))
+                    )),
+                }
+            )])
         );
         assert_eq!(precompiler.get_variable("name_1".to_string().into()), Ok(0));
     }
@@ -186,6 +288,7 @@ mod tests {
         precompiler.insert_variable("name_1".to_string().into());
 
         let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Let,
             name: "name_1".to_string().into(),
             value: Some(VariableExpression::VariableValue(VariableValue::Number(
                 64_f64,
@@ -194,12 +297,14 @@ mod tests {
 
         assert_eq!(
             variable_declaration.precompile(&mut precompiler),
-            Ok(llvm_ast::VariableDeclaration {
-                name: llvm_ast::Identifier::new("name_1".to_string(), 1),
-                value: Some(llvm_ast::VariableExpression::VariableValue(
-                    llvm_ast::VariableValue::FloatNumber(64_f64)
-                )),
-            })
+            Ok(vec![llvm_ast::Expression::VariableDeclaration(
+                llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 1),
+                    value: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(64_f64)
+                    )),
+                }
+            )])
         );
     }
 
@@ -208,6 +313,7 @@ mod tests {
         let mut precompiler = Precompiler::new(std::iter::empty());
 
         let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Let,
             name: "name_1".to_string().into(),
             value: Some(VariableExpression::VariableValue(
                 VariableValue::Identifier("name_2".to_string().into()),
@@ -216,8 +322,159 @@ mod tests {
 
         assert_eq!(
             variable_declaration.precompile(&mut precompiler),
-            Err(precompiler::Error::UndefinedVariable(
-                "name_2".to_string().into(),
+            Ok(vec![llvm_ast::Expression::VariableDeclaration(
+                llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    value: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_2".to_string(),
+                            0
+                        ))
+                    )),
+                }
+            )])
+        );
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![precompiler::Error::UndefinedVariable(
+                "name_2".to_string().into()
+            )]
+        );
+    }
+
+    #[test]
+    fn precompile_var_declaration_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.insert_variable("name_1".to_string().into());
+
+        let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Var,
+            name: "name_1".to_string().into(),
+            value: Some(VariableExpression::VariableValue(VariableValue::Number(
+                64_f64,
+            ))),
+        };
+        assert_eq!(
+            variable_declaration.precompile(&mut precompiler),
+            Ok(vec![llvm_ast::Expression::VariableAssigment(
+                llvm_ast::VariableAssigment {
+                    left: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_1".to_string(),
+                            0
+                        ))
+                    ),
+                    right: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(64_f64)
+                    ),
+                    span: Span::default(),
+                }
+            )])
+        );
+
+        let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Var,
+            name: "name_1".to_string().into(),
+            value: None,
+        };
+        assert_eq!(
+            variable_declaration.precompile(&mut precompiler),
+            Ok(Vec::new())
+        );
+        assert_eq!(precompiler.variables_len(), 1);
+    }
+
+    #[test]
+    fn precompile_uninitialized_variable_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+        precompiler.declare_uninitialized_variable("name_1".to_string().into(), false);
+
+        let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Let,
+            name: "name_1".to_string().into(),
+            value: Some(VariableExpression::VariableValue(
+                VariableValue::Identifier("name_1".to_string().into()),
+            )),
+        };
+        assert_eq!(
+            variable_declaration.precompile(&mut precompiler),
+            Err(precompiler::Error::UninitializedVariable(
+                "name_1".to_string().into()
+            ))
+        );
+
+        // the variable declared at the beginning of the block is only initialized
+        precompiler.declare_uninitialized_variable("name_2".to_string().into(), true);
+        let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Const,
+            name: "name_2".to_string().into(),
+            value: Some(VariableExpression::VariableValue(VariableValue::Number(
+                64_f64,
+            ))),
+        };
+        assert_eq!(
+            variable_declaration.precompile(&mut precompiler),
+            Ok(vec![llvm_ast::Expression::VariableAssigment(
+                llvm_ast::VariableAssigment {
+                    left: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::new(
+                            "name_2".to_string(),
+                            0
+                        ))
+                    ),
+                    right: llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(64_f64)
+                    ),
+                    span: Span::default(),
+                }
+            )])
+        );
+        assert_eq!(
+            precompiler.get_mutable_variable("name_2".to_string().into()),
+            Err(precompiler::Error::ConstantAssigment(
+                "name_2".to_string().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn precompile_const_declaration_test() {
+        let mut precompiler = Precompiler::new(std::iter::empty());
+
+        let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Const,
+            name: "name_1".to_string().into(),
+            value: Some(VariableExpression::VariableValue(VariableValue::Number(
+                64_f64,
+            ))),
+        };
+        assert_eq!(
+            variable_declaration.precompile(&mut precompiler),
+            Ok(vec![llvm_ast::Expression::VariableDeclaration(
+                llvm_ast::VariableDeclaration {
+                    name: llvm_ast::Identifier::new("name_1".to_string(), 0),
+                    value: Some(llvm_ast::VariableExpression::VariableValue(
+                        llvm_ast::VariableValue::FloatNumber(64_f64)
+                    )),
+                }
+            )])
+        );
+        assert_eq!(
+            precompiler.get_mutable_variable("name_1".to_string().into()),
+            Err(precompiler::Error::ConstantAssigment(
+                "name_1".to_string().into()
+            ))
+        );
+
+        let variable_declaration = VariableDeclaration {
+            declaration_type: DeclarationType::Const,
+            name: "name_2".to_string().into(),
+            value: None,
+        };
+        assert_eq!(
+            variable_declaration.precompile(&mut precompiler),
+            Err(precompiler::Error::MissingInitializer(
+                "name_2".to_string().into()
             ))
         );
     }
@@ -1,11 +1,11 @@
 use super::{
-    BinaryExpType, BinaryExpression, FunctionCall, MemberExpression, Property, UnaryExpType,
-    UnaryExpression, VariableValue,
+    ArrowFunction, BinaryExpType, BinaryExpression, ConditionalExpression, FunctionCall,
+    MemberExpression, Property, TemplateLiteral, UnaryExpType, UnaryExpression, VariableValue,
 };
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
-use lexer::{Arithmetic, Logical, Separator, Token, TokenReader};
+use lexer::{Arithmetic, Keyword, Logical, Separator, Span, Token, TokenReader};
 use rpn::{
-    input::{InputExpression, Operation, Value},
+    input::{InputExpression, Operation, Priority, Value},
     output::OutputExpression,
     RPN,
 };
@@ -18,10 +18,16 @@ pub enum VariableExpression {
     BinaryExpression(Box<BinaryExpression>),
     MemberExpression(Box<MemberExpression>),
     FunctionCall(FunctionCall),
+    ConditionalExpression(Box<ConditionalExpression>),
+    TemplateLiteral(TemplateLiteral),
 }
 
-impl From<OutputExpression<RpnValue, UnaryExpType, BinaryExpType>> for VariableExpression {
-    fn from(val: OutputExpression<RpnValue, UnaryExpType, BinaryExpType>) -> Self {
+impl From<OutputExpression<RpnValue, Operator<UnaryExpType>, Operator<BinaryExpType>>>
+    for VariableExpression
+{
+    fn from(
+        val: OutputExpression<RpnValue, Operator<UnaryExpType>, Operator<BinaryExpType>>,
+    ) -> Self {
         match val {
             OutputExpression::Value(RpnValue::VariableValue(value)) => Self::VariableValue(value),
             OutputExpression::Value(RpnValue::FunctionCall(function_call)) => {
@@ -30,10 +36,12 @@ impl From<OutputExpression<RpnValue, UnaryExpType, BinaryExpType>> for VariableE
             OutputExpression::Value(RpnValue::MemberExpression(member_expression)) => {
                 Self::MemberExpression(member_expression.into())
             }
+            OutputExpression::Value(RpnValue::VariableExpression(expr)) => expr,
             OutputExpression::UnaryExpression(expr) => Self::UnaryExpression(
                 UnaryExpression {
                     exp: expr.exp.into(),
-                    exp_type: expr.exp_type,
+                    exp_type: expr.exp_type.exp_type,
+                    span: expr.exp_type.span,
                 }
                 .into(),
             ),
@@ -41,7 +49,8 @@ impl From<OutputExpression<RpnValue, UnaryExpType, BinaryExpType>> for VariableE
                 BinaryExpression {
                     left: expr.left.into(),
                     right: expr.right.into(),
-                    exp_type: expr.exp_type,
+                    exp_type: expr.exp_type.exp_type,
+                    span: expr.exp_type.span,
                 }
                 .into(),
             ),
@@ -49,10 +58,30 @@ impl From<OutputExpression<RpnValue, UnaryExpType, BinaryExpType>> for VariableE
     }
 }
 
+/// Operator with the span of its token, the priority is resolved by the operator type
+struct Operator<T> {
+    exp_type: T,
+    span: Span,
+}
+
+impl<T: Priority> Priority for Operator<T> {
+    fn priority(&self) -> u8 {
+        self.exp_type.priority()
+    }
+
+    fn is_right_associative(&self) -> bool {
+        self.exp_type.is_right_associative()
+    }
+}
+
+type Rpn = RPN<RpnValue, Operator<UnaryExpType>, Operator<BinaryExpType>>;
+
 enum RpnValue {
     VariableValue(VariableValue),
     MemberExpression(MemberExpression),
     FunctionCall(FunctionCall),
+    /// expression inside the braces
+    VariableExpression(VariableExpression),
 }
 
 impl From<FunctionCall> for RpnValue {
@@ -75,151 +104,208 @@ impl From<MemberExpression> for RpnValue {
 
 impl VariableExpression {
     pub fn parse<R: Read>(
-        cur_token: Token,
+        mut cur_token: Token,
         reader: &mut TokenReader<R>,
     ) -> Result<Self, LexerError> {
-        let mut rpn = RPN::new();
-        Self::parse_impl(cur_token, reader, &mut rpn, false)?;
+        let start = reader.span().start;
+        let mut rpn = Rpn::new();
+        // operands are separated by the binary operators, their priorities are resolved by the RPN
+        while Self::parse_operand(cur_token, reader, &mut rpn)? {
+            let Some(exp_type) = Self::binary_exp_type(reader.peek()?) else {
+                break;
+            };
+            reader.next_token()?;
+            rpn.build(InputExpression::Value(Value::Operation(
+                Operation::BinaryOp(Operator {
+                    exp_type,
+                    span: reader.span(),
+                }),
+            )))?;
+            cur_token = reader.next_token()?;
+        }
         let object = rpn.finish()?.evaluate().into();
-        reader.start_saving();
-        if let Some(property) = Property::parse(&reader.next_token()?, reader)? {
-            reader.reset_saving();
-            Ok(Self::MemberExpression(
-                MemberExpression { object, property }.into(),
-            ))
+        let expr = if let Some(property) = Property::parse_next(reader)? {
+            Self::MemberExpression(
+                MemberExpression {
+                    object,
+                    property,
+                    span: Span::new(start, reader.span().end),
+                }
+                .into(),
+            )
         } else {
-            reader.stop_saving();
-            Ok(object)
-        }
+            object
+        };
+        ConditionalExpression::parse_rest(expr, reader)
     }
 
-    #[allow(clippy::too_many_lines)]
-    fn parse_impl<R: Read>(
+    /// Parses the value with its prefix and postfix operators.
+    /// Returns false for the arrow function, as its body takes the rest of the expression
+    fn parse_operand<R: Read>(
         cur_token: Token,
         reader: &mut TokenReader<R>,
-        rpn: &mut RPN<RpnValue, UnaryExpType, BinaryExpType>,
-        is_unary: bool,
-    ) -> Result<(), LexerError> {
-        match cur_token {
-            Token::Logical(Logical::Not) => {
-                rpn.build(InputExpression::Value(Value::Operation(
-                    Operation::PrefixOp(UnaryExpType::Not),
+        rpn: &mut Rpn,
+    ) -> Result<bool, LexerError> {
+        if matches!(
+            cur_token,
+            Token::Separator(Separator::OpenBrace) | Token::Ident(_)
+        ) {
+            if let Some(arrow_function) = ArrowFunction::try_parse(&cur_token, reader)? {
+                rpn.build(InputExpression::Value(Value::Value(
+                    VariableValue::ArrowFunction(arrow_function).into(),
                 )))?;
-                Self::parse_impl(reader.next_token()?, reader, rpn, true)?;
+                return Ok(false);
             }
+        }
+
+        if let Some(exp_type) = Self::prefix_exp_type(&cur_token) {
+            rpn.build(InputExpression::Value(Value::Operation(
+                Operation::PrefixOp(Operator {
+                    exp_type,
+                    span: reader.span(),
+                }),
+            )))?;
+            Self::parse_operand(reader.next_token()?, reader, rpn)?;
+            return Ok(true);
+        }
+
+        match cur_token {
             Token::Separator(Separator::OpenBrace) => {
-                rpn.build(InputExpression::OpenBrace)?;
-                Self::parse_impl(reader.next_token()?, reader, rpn, false)?;
+                // parsed separately, as it could contain the conditional expression
+                let expr = Self::parse(reader.next_token()?, reader)?;
                 match reader.next_token()? {
                     Token::Separator(Separator::CloseBrace) => {
-                        rpn.build(InputExpression::CloseBrace)?;
-                    }
-                    token => return Err(LexerError::UnexpectedToken(token)),
-                }
-            }
-            Token::Ident(_) => {
-                reader.start_saving();
-                if let Ok(function_call) = FunctionCall::parse(cur_token.clone(), reader) {
-                    reader.reset_saving();
-                    rpn.build(InputExpression::Value(Value::Value(function_call.into())))?;
-                } else {
-                    reader.stop_saving();
-                    let object = VariableValue::parse(cur_token, reader)?;
-                    reader.start_saving();
-                    if let Some(property) = Property::parse(&reader.next_token()?, reader)? {
-                        reader.reset_saving();
                         rpn.build(InputExpression::Value(Value::Value(
-                            MemberExpression {
-                                object: VariableExpression::VariableValue(object),
-                                property,
-                            }
-                            .into(),
+                            RpnValue::VariableExpression(expr),
                         )))?;
-                    } else {
-                        reader.stop_saving();
-                        rpn.build(InputExpression::Value(Value::Value(object.into())))?;
                     }
+                    token => return Err(LexerError::UnexpectedToken(token, reader.span())),
                 }
             }
+            Token::Template(_) => {
+                rpn.build(InputExpression::Value(Value::Value(
+                    RpnValue::VariableExpression(Self::TemplateLiteral(TemplateLiteral::parse(
+                        cur_token, reader,
+                    )?)),
+                )))?;
+            }
+            Token::Ident(_) if matches!(reader.peek()?, Token::Separator(Separator::OpenBrace)) => {
+                let function_call = FunctionCall::parse(cur_token, reader)?;
+                rpn.build(InputExpression::Value(Value::Value(function_call.into())))?;
+            }
             cur_token => {
+                let start = reader.span().start;
                 let object = VariableValue::parse(cur_token, reader)?;
-                reader.start_saving();
-                if let Some(property) = Property::parse(&reader.next_token()?, reader)? {
-                    reader.reset_saving();
+                if let Some(property) = Property::parse_next(reader)? {
                     rpn.build(InputExpression::Value(Value::Value(
                         MemberExpression {
                             object: VariableExpression::VariableValue(object),
                             property,
+                            span: Span::new(start, reader.span().end),
                         }
                         .into(),
                     )))?;
                 } else {
-                    reader.stop_saving();
                     rpn.build(InputExpression::Value(Value::Value(object.into())))?;
                 }
             }
         }
-        if !is_unary {
-            reader.start_saving();
-            let parse_binary_op = |reader: &mut TokenReader<R>,
-                                   rpn: &mut RPN<RpnValue, UnaryExpType, BinaryExpType>,
-                                   exp_type|
-             -> Result<(), LexerError> {
-                reader.reset_saving();
-                rpn.build(InputExpression::Value(Value::Operation(
-                    Operation::BinaryOp(exp_type),
-                )))?;
-                Self::parse_impl(reader.next_token()?, reader, rpn, false)?;
-                Ok(())
-            };
+        Self::parse_postfix_op(reader, rpn)?;
+        Ok(true)
+    }
 
-            match reader.next_token()? {
-                // Logical
-                Token::Logical(Logical::Or) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Or)?;
-                }
-                Token::Logical(Logical::And) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::And)?;
-                }
-                Token::Logical(Logical::Eq) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Eq)?;
-                }
-                Token::Logical(Logical::Ne) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Ne)?;
-                }
-                Token::Logical(Logical::Gt) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Gt)?;
-                }
-                Token::Logical(Logical::Ge) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Ge)?;
-                }
-                Token::Logical(Logical::Lt) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Lt)?;
-                }
-                Token::Logical(Logical::Le) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Le)?;
-                }
-                // Arithmetic
-                Token::Arithmetic(Arithmetic::Add) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Add)?;
-                }
-                Token::Arithmetic(Arithmetic::Sub) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Sub)?;
-                }
-                Token::Arithmetic(Arithmetic::Div) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Div)?;
-                }
-                Token::Arithmetic(Arithmetic::Mul) => {
-                    parse_binary_op(reader, rpn, BinaryExpType::Mul)?;
-                }
-                _ => {
-                    reader.stop_saving();
-                }
-            }
+    fn prefix_exp_type(token: &Token) -> Option<UnaryExpType> {
+        match token {
+            Token::Logical(Logical::Not) => Some(UnaryExpType::Not),
+            Token::Arithmetic(Arithmetic::BitNot) => Some(UnaryExpType::BitNot),
+            Token::Arithmetic(Arithmetic::Sub) => Some(UnaryExpType::Neg),
+            Token::Arithmetic(Arithmetic::Add) => Some(UnaryExpType::Plus),
+            Token::Keyword(Keyword::Typeof) => Some(UnaryExpType::Typeof),
+            Token::Keyword(Keyword::Void) => Some(UnaryExpType::Void),
+            Token::Arithmetic(Arithmetic::Inc) => Some(UnaryExpType::PrefixInc),
+            Token::Arithmetic(Arithmetic::Dec) => Some(UnaryExpType::PrefixDec),
+            _ => None,
         }
+    }
+
+    fn binary_exp_type(token: &Token) -> Option<BinaryExpType> {
+        match token {
+            // Logical
+            Token::Logical(Logical::Or) => Some(BinaryExpType::Or),
+            Token::Logical(Logical::And) => Some(BinaryExpType::And),
+            Token::Logical(Logical::Eq) => Some(BinaryExpType::Eq),
+            Token::Logical(Logical::Ne) => Some(BinaryExpType::Ne),
+            Token::Logical(Logical::SEq) => Some(BinaryExpType::SEq),
+            Token::Logical(Logical::SNe) => Some(BinaryExpType::SNe),
+            Token::Logical(Logical::Gt) => Some(BinaryExpType::Gt),
+            Token::Logical(Logical::Ge) => Some(BinaryExpType::Ge),
+            Token::Logical(Logical::Lt) => Some(BinaryExpType::Lt),
+            Token::Logical(Logical::Le) => Some(BinaryExpType::Le),
+            // Arithmetic
+            Token::Arithmetic(Arithmetic::Add) => Some(BinaryExpType::Add),
+            Token::Arithmetic(Arithmetic::Sub) => Some(BinaryExpType::Sub),
+            Token::Arithmetic(Arithmetic::Div) => Some(BinaryExpType::Div),
+            Token::Arithmetic(Arithmetic::Mul) => Some(BinaryExpType::Mul),
+            Token::Arithmetic(Arithmetic::Mod) => Some(BinaryExpType::Mod),
+            Token::Arithmetic(Arithmetic::Exp) => Some(BinaryExpType::Exp),
+            // Bitwise
+            Token::Arithmetic(Arithmetic::BitAnd) => Some(BinaryExpType::BitAnd),
+            Token::Arithmetic(Arithmetic::BitOr) => Some(BinaryExpType::BitOr),
+            Token::Arithmetic(Arithmetic::BitXor) => Some(BinaryExpType::BitXor),
+            Token::Arithmetic(Arithmetic::Shl) => Some(BinaryExpType::Shl),
+            Token::Arithmetic(Arithmetic::Shr) => Some(BinaryExpType::Shr),
+            Token::Arithmetic(Arithmetic::UShr) => Some(BinaryExpType::UShr),
+            _ => None,
+        }
+    }
+
+    /// Parses the optional postfix "++" or "--" after the value
+    fn parse_postfix_op<R: Read>(
+        reader: &mut TokenReader<R>,
+        rpn: &mut Rpn,
+    ) -> Result<(), LexerError> {
+        let exp_type = match reader.peek()? {
+            Token::Arithmetic(Arithmetic::Inc) => UnaryExpType::PostfixInc,
+            Token::Arithmetic(Arithmetic::Dec) => UnaryExpType::PostfixDec,
+            _ => return Ok(()),
+        };
+        reader.next_token()?;
+        rpn.build(InputExpression::Value(Value::Operation(
+            Operation::PostfixOp(Operator {
+                exp_type,
+                span: reader.span(),
+            }),
+        )))?;
         Ok(())
     }
 
+    /// Precompiles the target of the assignment, only the variable or the object property could be assigned
+    /// and the constant variable could not be reassigned
+    pub fn precompile_assigment_target(
+        self,
+        precompiler: &mut Precompiler,
+        span: Span,
+    ) -> Result<llvm_ast::VariableExpression, PrecompilerError> {
+        match self {
+            Self::VariableValue(VariableValue::Identifier(identifier)) => {
+                let index = precompiler
+                    .get_mutable_variable(identifier.clone())
+                    .or_else(|e| precompiler.collect_undefined_variable(e))?;
+                Ok(llvm_ast::VariableExpression::VariableValue(
+                    llvm_ast::VariableValue::Identifier(llvm_ast::Identifier::with_span(
+                        identifier.name,
+                        index,
+                        identifier.span,
+                    )),
+                ))
+            }
+            Self::MemberExpression(expr) => Ok(llvm_ast::VariableExpression::MemberExpression(
+                expr.precompile(precompiler)?.into(),
+            )),
+            _ => Err(PrecompilerError::InvalidAssigmentTarget(span)),
+        }
+    }
+
     pub fn precompile(
         self,
         precompiler: &mut Precompiler,
@@ -240,6 +326,12 @@ impl VariableExpression {
             Self::FunctionCall(function_call) => Ok(llvm_ast::VariableExpression::FunctionCall(
                 function_call.precompile(precompiler)?,
             )),
+            Self::ConditionalExpression(expr) => {
+                Ok(llvm_ast::VariableExpression::ConditionalExpression(
+                    expr.precompile(precompiler)?.into(),
+                ))
+            }
+            Self::TemplateLiteral(template_literal) => template_literal.precompile(precompiler),
         }
     }
 }
@@ -248,6 +340,7 @@ impl VariableExpression {
 mod tests {
     use super::*;
     use crate::js_ast::member_expression::PropertyType;
+    use lexer::Position;
 
     #[test]
     fn parse_not_logical_expression_test() {
@@ -258,6 +351,7 @@ mod tests {
                 UnaryExpression {
                     exp: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     exp_type: UnaryExpType::Not,
+                    span: Span::default(),
                 },
             ))),
         );
@@ -269,6 +363,7 @@ mod tests {
                 UnaryExpression {
                     exp: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: UnaryExpType::Not,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -282,6 +377,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: UnaryExpType::Not,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -297,6 +393,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::And,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -311,6 +408,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::And,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -327,6 +425,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::And,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -345,6 +444,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -357,10 +457,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::And,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -376,6 +478,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Or,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -390,6 +493,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Or,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -406,6 +510,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Or,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -424,6 +529,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -436,10 +542,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Or,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -455,6 +563,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Eq,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -469,6 +578,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Eq,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -485,6 +595,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Eq,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -503,6 +614,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -515,10 +627,52 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Eq,
+                    span: Span::default(),
+                }
+            ))),
+        );
+    }
+
+    #[test]
+    fn parse_strict_eq_logical_expression_test() {
+        let mut reader = TokenReader::new("a === 1 + 1".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                        right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                        exp_type: BinaryExpType::Add,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::SEq,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        let mut reader = TokenReader::new("a !== b".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "b".to_string().into()
+                    )),
+                    exp_type: BinaryExpType::SNe,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -534,6 +688,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Ne,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -548,6 +703,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Ne,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -564,6 +720,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Ne,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -582,6 +739,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -594,10 +752,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Ne,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -613,6 +773,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Gt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -627,6 +788,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Gt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -643,6 +805,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Gt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -661,6 +824,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -673,10 +837,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Gt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -692,6 +858,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Ge,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -706,6 +873,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Ge,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -722,6 +890,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Ge,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -740,6 +909,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -752,10 +922,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Ge,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -771,6 +943,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Lt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -785,6 +958,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Lt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -801,6 +975,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Lt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -819,6 +994,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -831,10 +1007,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Lt,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -850,6 +1028,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Le,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -864,6 +1043,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Le,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -880,6 +1060,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Le,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -898,6 +1079,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -910,10 +1092,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Le,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -929,6 +1113,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Add,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -943,6 +1128,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Add,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -959,6 +1145,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Add,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -977,6 +1164,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -989,10 +1177,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Add,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1008,6 +1198,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Sub,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1022,6 +1213,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Sub,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1038,6 +1230,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Sub,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1056,6 +1249,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -1068,10 +1262,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Sub,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1087,6 +1283,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Div,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1101,6 +1298,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Div,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1117,6 +1315,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Div,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1135,6 +1334,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -1147,10 +1347,12 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Div,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1166,6 +1368,7 @@ mod tests {
                     left: VariableExpression::VariableValue(VariableValue::Boolean(true)),
                     right: VariableExpression::VariableValue(VariableValue::Boolean(false)),
                     exp_type: BinaryExpType::Mul,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1180,6 +1383,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: BinaryExpType::Mul,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1196,6 +1400,7 @@ mod tests {
                         "b".to_string().into()
                     )),
                     exp_type: BinaryExpType::Mul,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1214,6 +1419,7 @@ mod tests {
                                 object: PropertyType::Identifier("a".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
@@ -1226,10 +1432,155 @@ mod tests {
                                 object: PropertyType::Identifier("b".to_string().into()),
                                 property: None
                             },
+                            span: Span::default(),
                         }
                         .into()
                     ),
                     exp_type: BinaryExpType::Mul,
+                    span: Span::default(),
+                }
+            ))),
+        );
+    }
+
+    #[test]
+    fn parse_mod_exp_arithmetic_expression_test() {
+        let mut reader = TokenReader::new("a % 2".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                    exp_type: BinaryExpType::Mod,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // right associative
+        let mut reader = TokenReader::new("a ** b ** 2".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                        exp_type: BinaryExpType::Exp,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::Exp,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        let mut reader = TokenReader::new("a * b ** 2".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                        exp_type: BinaryExpType::Exp,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::Mul,
+                    span: Span::default(),
+                }
+            ))),
+        );
+    }
+
+    #[test]
+    fn parse_bitwise_expression_test() {
+        let mut reader = TokenReader::new("~a".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    exp_type: UnaryExpType::BitNot,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // "a | (b ^ (c & d))"
+        let mut reader = TokenReader::new("a | b ^ c & d".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "c".to_string().into()
+                            )),
+                            right: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "d".to_string().into()
+                            )),
+                            exp_type: BinaryExpType::BitAnd,
+                            span: Span::default(),
+                        })),
+                        exp_type: BinaryExpType::BitXor,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::BitOr,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // "((a << 1) >> 2) >>> (3 + 4)"
+        let mut reader = TokenReader::new("a << 1 >> 2 >>> 3 + 4".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                            left: VariableExpression::VariableValue(VariableValue::Identifier(
+                                "a".to_string().into()
+                            )),
+                            right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                            exp_type: BinaryExpType::Shl,
+                            span: Span::default(),
+                        })),
+                        right: VariableExpression::VariableValue(VariableValue::Number(2_f64)),
+                        exp_type: BinaryExpType::Shr,
+                        span: Span::default(),
+                    })),
+                    right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Number(3_f64)),
+                        right: VariableExpression::VariableValue(VariableValue::Number(4_f64)),
+                        exp_type: BinaryExpType::Add,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::UShr,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1247,6 +1598,7 @@ mod tests {
                             "a".to_string().into()
                         )),
                         exp_type: UnaryExpType::Not,
+                        span: Span::default(),
                     })),
                     right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
                         left: VariableExpression::VariableValue(VariableValue::Identifier(
@@ -1257,10 +1609,13 @@ mod tests {
                                 "c".to_string().into()
                             )),
                             exp_type: UnaryExpType::Not,
+                            span: Span::default(),
                         })),
                         exp_type: BinaryExpType::And,
+                        span: Span::default(),
                     })),
                     exp_type: BinaryExpType::Or,
+                    span: Span::default(),
                 }
             ))),
         );
@@ -1276,18 +1631,194 @@ mod tests {
                                 exp: VariableExpression::VariableValue(VariableValue::Identifier(
                                     "a".to_string().into()
                                 )),
-                                exp_type: UnaryExpType::Not
+                                exp_type: UnaryExpType::Not,
+                                span: Span::default(),
                             })),
                             exp_type: UnaryExpType::Not,
+                            span: Span::default(),
                         })),
                         exp_type: UnaryExpType::Not,
+                        span: Span::default(),
                     })),
                     exp_type: UnaryExpType::Not,
+                    span: Span::default(),
                 }
             )))
         );
     }
 
+    #[test]
+    fn parse_unary_expression_test() {
+        let mut reader = TokenReader::new("-a".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    exp_type: UnaryExpType::Neg,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        let mut reader = TokenReader::new("-(a + 1)".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
+                        left: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                        exp_type: BinaryExpType::Add,
+                        span: Span::default(),
+                    })),
+                    exp_type: UnaryExpType::Neg,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // "(+a) - (-b)"
+        let mut reader = TokenReader::new("+a - -b".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::UnaryExpression(Box::new(UnaryExpression {
+                        exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        exp_type: UnaryExpType::Plus,
+                        span: Span::default(),
+                    })),
+                    right: VariableExpression::UnaryExpression(Box::new(UnaryExpression {
+                        exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        exp_type: UnaryExpType::Neg,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::Sub,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // "(typeof a) == b"
+        let mut reader = TokenReader::new("typeof a == b".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::UnaryExpression(Box::new(UnaryExpression {
+                        exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        exp_type: UnaryExpType::Typeof,
+                        span: Span::default(),
+                    })),
+                    right: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "b".to_string().into()
+                    )),
+                    exp_type: BinaryExpType::Eq,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        let mut reader = TokenReader::new("void 0".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::VariableValue(VariableValue::Number(0_f64)),
+                    exp_type: UnaryExpType::Void,
+                    span: Span::default(),
+                }
+            ))),
+        );
+    }
+
+    #[test]
+    fn parse_update_expression_test() {
+        let mut reader = TokenReader::new("++a".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    exp_type: UnaryExpType::PrefixInc,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        let mut reader = TokenReader::new("a--".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    exp_type: UnaryExpType::PostfixDec,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // "(a++) + (--b)"
+        let mut reader = TokenReader::new("a++ + --b".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(Box::new(
+                BinaryExpression {
+                    left: VariableExpression::UnaryExpression(Box::new(UnaryExpression {
+                        exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        exp_type: UnaryExpType::PostfixInc,
+                        span: Span::default(),
+                    })),
+                    right: VariableExpression::UnaryExpression(Box::new(UnaryExpression {
+                        exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "b".to_string().into()
+                        )),
+                        exp_type: UnaryExpType::PrefixDec,
+                        span: Span::default(),
+                    })),
+                    exp_type: BinaryExpType::Add,
+                    span: Span::default(),
+                }
+            ))),
+        );
+
+        // "!(a++)"
+        let mut reader = TokenReader::new("!a++".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::UnaryExpression(Box::new(
+                UnaryExpression {
+                    exp: VariableExpression::UnaryExpression(Box::new(UnaryExpression {
+                        exp: VariableExpression::VariableValue(VariableValue::Identifier(
+                            "a".to_string().into()
+                        )),
+                        exp_type: UnaryExpType::PostfixInc,
+                        span: Span::default(),
+                    })),
+                    exp_type: UnaryExpType::Not,
+                    span: Span::default(),
+                }
+            ))),
+        );
+    }
+
     #[test]
     fn parse_grouping_test() {
         let mut reader = TokenReader::new("(!a || (b && !c) && d && g)".as_bytes());
@@ -1299,6 +1830,7 @@ mod tests {
                         "a".to_string().into()
                     )),
                     exp_type: UnaryExpType::Not,
+                    span: Span::default(),
                 })),
                 right: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
                     left: VariableExpression::BinaryExpression(Box::new(BinaryExpression {
@@ -1311,20 +1843,25 @@ mod tests {
                                     "c".to_string().into()
                                 )),
                                 exp_type: UnaryExpType::Not,
+                                span: Span::default(),
                             })),
                             exp_type: BinaryExpType::And,
+                            span: Span::default(),
                         })),
                         right: VariableExpression::VariableValue(VariableValue::Identifier(
                             "d".to_string().into()
                         )),
                         exp_type: BinaryExpType::And,
+                        span: Span::default(),
                     })),
                     right: VariableExpression::VariableValue(VariableValue::Identifier(
                         "g".to_string().into()
                     )),
                     exp_type: BinaryExpType::And,
+                    span: Span::default(),
                 })),
                 exp_type: BinaryExpType::Or,
+                span: Span::default(),
             }))
         );
 
@@ -1335,7 +1872,8 @@ mod tests {
                 exp: VariableExpression::VariableValue(VariableValue::Identifier(
                     "a".to_string().into()
                 )),
-                exp_type: UnaryExpType::Not
+                exp_type: UnaryExpType::Not,
+                span: Span::default(),
             }))
         );
 
@@ -1352,10 +1890,12 @@ mod tests {
                             object: PropertyType::Identifier("a".to_string().into()),
                             property: None
                         },
+                        span: Span::default(),
                     }
                     .into()
                 ),
-                exp_type: UnaryExpType::Not
+                exp_type: UnaryExpType::Not,
+                span: Span::default(),
             }))
         );
     }
@@ -1376,8 +1916,123 @@ mod tests {
                     )),
                     VariableExpression::VariableValue(VariableValue::String("val".to_string())),
                     VariableExpression::VariableValue(VariableValue::Number(5_f64)),
-                ]
+                ],
+                span: Span::default(),
             })),
         );
     }
+
+    #[test]
+    fn parse_arrow_function_test() {
+        let mut reader = TokenReader::new("foo(x => x * 2, (a) => {})".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::FunctionCall(FunctionCall {
+                name: "foo".to_string().into(),
+                args: vec![
+                    VariableExpression::VariableValue(VariableValue::ArrowFunction(
+                        ArrowFunction {
+                            args: vec!["x".to_string().into()],
+                            body: crate::js_ast::ArrowFunctionBody::VariableExpression(
+                                VariableExpression::BinaryExpression(
+                                    BinaryExpression {
+                                        left: VariableExpression::VariableValue(
+                                            VariableValue::Identifier("x".to_string().into())
+                                        ),
+                                        right: VariableExpression::VariableValue(
+                                            VariableValue::Number(2_f64)
+                                        ),
+                                        exp_type: BinaryExpType::Mul,
+                                        span: Span::default(),
+                                    }
+                                    .into()
+                                )
+                                .into()
+                            ),
+                        }
+                    )),
+                    VariableExpression::VariableValue(VariableValue::ArrowFunction(
+                        ArrowFunction {
+                            args: vec!["a".to_string().into()],
+                            body: crate::js_ast::ArrowFunctionBody::BlockStatement(
+                                crate::js_ast::BlockStatement { body: vec![] }
+                            ),
+                        }
+                    )),
+                ],
+                span: Span::default(),
+            })),
+        );
+
+        // parenthesized expression is not the arrow function
+        let mut reader = TokenReader::new("(a) + 1".as_bytes());
+        assert_eq!(
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader),
+            Ok(VariableExpression::BinaryExpression(
+                BinaryExpression {
+                    left: VariableExpression::VariableValue(VariableValue::Identifier(
+                        "a".to_string().into()
+                    )),
+                    right: VariableExpression::VariableValue(VariableValue::Number(1_f64)),
+                    exp_type: BinaryExpType::Add,
+                    span: Span::default(),
+                }
+                .into()
+            )),
+        );
+    }
+
+    #[test]
+    fn parse_expression_span_test() {
+        let mut reader = TokenReader::new("-a + f(b) * c.d[0]++".as_bytes());
+        let VariableExpression::BinaryExpression(add) =
+            VariableExpression::parse(reader.next_token().unwrap(), &mut reader).unwrap()
+        else {
+            panic!("the binary expression is expected");
+        };
+        assert_eq!(
+            add.span,
+            Span::new(Position::new(1, 4), Position::new(1, 4))
+        );
+
+        let VariableExpression::UnaryExpression(neg) = &add.left else {
+            panic!("the unary expression is expected");
+        };
+        assert_eq!(
+            neg.span,
+            Span::new(Position::new(1, 1), Position::new(1, 1))
+        );
+
+        let VariableExpression::BinaryExpression(mul) = &add.right else {
+            panic!("the binary expression is expected");
+        };
+        assert_eq!(
+            mul.span,
+            Span::new(Position::new(1, 11), Position::new(1, 11))
+        );
+
+        let VariableExpression::FunctionCall(function_call) = &mul.left else {
+            panic!("the function call is expected");
+        };
+        assert_eq!(
+            function_call.span,
+            Span::new(Position::new(1, 6), Position::new(1, 9))
+        );
+
+        let VariableExpression::UnaryExpression(inc) = &mul.right else {
+            panic!("the unary expression is expected");
+        };
+        assert_eq!(
+            inc.span,
+            Span::new(Position::new(1, 19), Position::new(1, 20))
+        );
+
+        let VariableExpression::MemberExpression(member_expression) = &inc.exp else {
+            panic!("the member expression is expected");
+        };
+        assert_eq!(
+            member_expression.span,
+            Span::new(Position::new(1, 13), Position::new(1, 18))
+        );
+    }
 }
@@ -1,6 +1,6 @@
-use super::{ArrayExpression, Identifier, ObjectExpression};
+use super::{ArrayExpression, ArrowFunction, FunctionExpression, Identifier, ObjectExpression};
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
-use lexer::{Arithmetic, Literal, Separator, Token, TokenReader};
+use lexer::{Keyword, Literal, Separator, Token, TokenReader};
 use std::io::Read;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -9,13 +9,14 @@ pub enum VariableValue {
     Null,
     NaN,
     Infinity,
-    NegInfinity,
     Boolean(bool),
     Number(f64),
     String(String),
     Identifier(Identifier),
     ObjectExpression(ObjectExpression),
     ArrayExpression(ArrayExpression),
+    FunctionExpression(FunctionExpression),
+    ArrowFunction(ArrowFunction),
 }
 
 impl VariableValue {
@@ -32,19 +33,16 @@ impl VariableValue {
             Token::Literal(Literal::Number(val)) => Ok(Self::Number(val)),
             Token::Literal(Literal::String(val)) => Ok(Self::String(val)),
             Token::Ident(_) => Ok(Self::Identifier(Identifier::parse(cur_token, reader)?)),
-            // negative
-            Token::Arithmetic(Arithmetic::Sub) => match reader.next_token()? {
-                Token::Literal(Literal::Infinity) => Ok(Self::NegInfinity),
-                Token::Literal(Literal::Number(val)) => Ok(Self::Number(-val)),
-                token => Err(LexerError::UnexpectedToken(token)),
-            },
             Token::Separator(Separator::OpenCurlyBrace) => Ok(Self::ObjectExpression(
                 ObjectExpression::parse(cur_token, reader)?,
             )),
             Token::Separator(Separator::OpenSquareBracket) => Ok(Self::ArrayExpression(
                 ArrayExpression::parse(cur_token, reader)?,
             )),
-            cur_token => Err(LexerError::UnexpectedToken(cur_token)),
+            Token::Keyword(Keyword::Function) => Ok(Self::FunctionExpression(
+                FunctionExpression::parse(cur_token, reader)?,
+            )),
+            cur_token => Err(LexerError::UnexpectedToken(cur_token, reader.span())),
         }
     }
 }
@@ -59,12 +57,13 @@ impl VariableValue {
             Self::Null => Ok(llvm_ast::VariableValue::Null),
             Self::NaN => Ok(llvm_ast::VariableValue::NaN),
             Self::Infinity => Ok(llvm_ast::VariableValue::Infinity),
-            Self::NegInfinity => Ok(llvm_ast::VariableValue::NegInfinity),
             Self::Boolean(boolean) => Ok(llvm_ast::VariableValue::Boolean(boolean)),
             Self::Identifier(identifier) => {
-                let index = precompiler.get_variable(identifier.clone())?;
+                let index = precompiler
+                    .get_variable(identifier.clone())
+                    .or_else(|e| precompiler.collect_undefined_variable(e))?;
                 Ok(llvm_ast::VariableValue::Identifier(
-                    llvm_ast::Identifier::new(identifier.name, index),
+                    llvm_ast::Identifier::with_span(identifier.name, index, identifier.span),
                 ))
             }
             Self::Number(number) => Ok(llvm_ast::VariableValue::FloatNumber(number)),
@@ -77,6 +76,14 @@ impl VariableValue {
             Self::ArrayExpression(array_expression) => Ok(
                 llvm_ast::VariableValue::ArrayExpression(array_expression.precompile(precompiler)?),
             ),
+            Self::FunctionExpression(function_expression) => {
+                Ok(llvm_ast::VariableValue::FunctionExpression(
+                    function_expression.precompile(precompiler)?,
+                ))
+            }
+            Self::ArrowFunction(arrow_function) => Ok(llvm_ast::VariableValue::FunctionExpression(
+                arrow_function.precompile(precompiler)?,
+            )),
         }
     }
 }
@@ -84,6 +91,8 @@ impl VariableValue {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lexer::Arithmetic;
+    use lexer::{Position, Span};
 
     #[test]
     fn parse_variable_value_test() {
@@ -111,12 +120,6 @@ mod tests {
             Ok(VariableValue::Infinity),
         );
 
-        let mut reader = TokenReader::new("-Infinity".as_bytes());
-        assert_eq!(
-            VariableValue::parse(reader.next_token().unwrap(), &mut reader),
-            Ok(VariableValue::NegInfinity),
-        );
-
         let mut reader = TokenReader::new("true".as_bytes());
         assert_eq!(
             VariableValue::parse(reader.next_token().unwrap(), &mut reader),
@@ -135,10 +138,14 @@ mod tests {
             Ok(VariableValue::Number(12_f64)),
         );
 
+        // negation is the unary expression
         let mut reader = TokenReader::new("-12".as_bytes());
         assert_eq!(
             VariableValue::parse(reader.next_token().unwrap(), &mut reader),
-            Ok(VariableValue::Number(-12_f64)),
+            Err(LexerError::UnexpectedToken(
+                Token::Arithmetic(Arithmetic::Sub),
+                Span::new(Position::new(1, 1), Position::new(1, 1))
+            )),
         );
 
         let mut reader = TokenReader::new(r#""name""#.as_bytes());
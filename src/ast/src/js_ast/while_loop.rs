@@ -1,4 +1,4 @@
-use super::{BlockStatement, VariableExpression};
+use super::{BlockStatement, Identifier, VariableExpression};
 use crate::{llvm_ast, LexerError, Precompiler, PrecompilerError};
 use lexer::{Keyword, Separator, Token, TokenReader};
 use std::io::Read;
@@ -24,12 +24,12 @@ impl WhileLoop {
 
                             Ok(Self { condition, body })
                         }
-                        token => Err(LexerError::UnexpectedToken(token)),
+                        token => Err(LexerError::UnexpectedToken(token, reader.span())),
                     }
                 }
-                token => Err(LexerError::UnexpectedToken(token)),
+                token => Err(LexerError::UnexpectedToken(token, reader.span())),
             },
-            token => Err(LexerError::UnexpectedToken(token)),
+            token => Err(LexerError::UnexpectedToken(token, reader.span())),
         }
     }
 }
@@ -38,10 +38,15 @@ impl WhileLoop {
     pub fn precompile(
         self,
         precompiler: &mut Precompiler,
+        label: Option<Identifier>,
     ) -> Result<llvm_ast::WhileLoop, PrecompilerError> {
+        let condition = self.condition.precompile(precompiler)?;
+        precompiler.enter_loop_scope(label);
+        let body = self.body.precompile(precompiler);
+        precompiler.exit_loop_scope();
         Ok(llvm_ast::WhileLoop {
-            condition: self.condition.precompile(precompiler)?,
-            body: self.body.precompile(precompiler)?,
+            condition,
+            body: body?,
         })
     }
 }
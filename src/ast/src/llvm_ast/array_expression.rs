@@ -20,6 +20,9 @@ impl ArrayExpression {
                 u32::try_from(i).expect("number overflow").into(),
                 &value,
             );
+            if value.is_tmp() {
+                value.deallocate(compiler);
+            }
         }
         Ok(res)
     }
@@ -1,12 +1,24 @@
 use super::VariableExpression;
 use crate::{Compiler, CompilerError};
-use compiler::Variable;
+use compiler::{
+    logical::{generate_logical_and, generate_logical_or},
+    Variable,
+};
+use lexer::Span;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct BinaryExpression {
     pub left: VariableExpression,
     pub right: VariableExpression,
     pub exp_type: BinaryExpType,
+    /// span of the operator, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for BinaryExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right && self.exp_type == other.exp_type
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,6 +28,8 @@ pub enum BinaryExpType {
     Or,
     Eq,
     Ne,
+    SEq,
+    SNe,
     Gt,
     Ge,
     Lt,
@@ -25,6 +39,15 @@ pub enum BinaryExpType {
     Sub,
     Div,
     Mul,
+    Mod,
+    Exp,
+    // Bitwise
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    UShr,
 }
 
 impl BinaryExpression {
@@ -60,37 +83,53 @@ impl BinaryExpression {
         match self.exp_type {
             // Logical
             BinaryExpType::And => {
+                let (left, right) = (self.left, self.right);
+                generate_logical_and(
+                    compiler,
+                    |compiler: &mut Compiler<'ctx>| left.compile_get_variable(compiler),
+                    |compiler: &mut Compiler<'ctx>| right.compile_get_variable(compiler),
+                )
+            }
+            BinaryExpType::Or => {
+                let (left, right) = (self.left, self.right);
+                generate_logical_or(
+                    compiler,
+                    |compiler: &mut Compiler<'ctx>| left.compile_get_variable(compiler),
+                    |compiler: &mut Compiler<'ctx>| right.compile_get_variable(compiler),
+                )
+            }
+            BinaryExpType::Eq => {
                 let ret = self.binary_call(compiler, |compiler, var1, var2| {
                     Ok(compiler
                         .predefined_functions()
-                        .logical_and()
+                        .logical_eq()
                         .call(compiler, var1, var2))
                 })?;
                 Ok(ret)
             }
-            BinaryExpType::Or => {
+            BinaryExpType::Ne => {
                 let ret = self.binary_call(compiler, |compiler, var1, var2| {
                     Ok(compiler
                         .predefined_functions()
-                        .logical_or()
+                        .logical_ne()
                         .call(compiler, var1, var2))
                 })?;
                 Ok(ret)
             }
-            BinaryExpType::Eq => {
+            BinaryExpType::SEq => {
                 let ret = self.binary_call(compiler, |compiler, var1, var2| {
                     Ok(compiler
                         .predefined_functions()
-                        .logical_eq()
+                        .logical_seq()
                         .call(compiler, var1, var2))
                 })?;
                 Ok(ret)
             }
-            BinaryExpType::Ne => {
+            BinaryExpType::SNe => {
                 let ret = self.binary_call(compiler, |compiler, var1, var2| {
                     Ok(compiler
                         .predefined_functions()
-                        .logical_ne()
+                        .logical_sne()
                         .call(compiler, var1, var2))
                 })?;
                 Ok(ret)
@@ -168,6 +207,79 @@ impl BinaryExpression {
                 })?;
                 Ok(ret)
             }
+            BinaryExpType::Mod => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_remainder()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            BinaryExpType::Exp => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_exponentiation()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            // Bitwise
+            BinaryExpType::BitAnd => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_bitwise_and()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            BinaryExpType::BitOr => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_bitwise_or()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            BinaryExpType::BitXor => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_bitwise_xor()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            BinaryExpType::Shl => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_left_shift()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            BinaryExpType::Shr => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_right_shift()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
+            BinaryExpType::UShr => {
+                let ret = self.binary_call(compiler, |compiler, var1, var2| {
+                    Ok(compiler
+                        .predefined_functions()
+                        .arithmetic_unsigned_right_shift()
+                        .call(compiler, var1, var2))
+                })?;
+                Ok(ret)
+            }
         }
     }
 }
@@ -0,0 +1,149 @@
+use super::{Reference, VariableExpression};
+use crate::{Compiler, CompilerError};
+use compiler::{if_else::generate_conditional, Variable};
+use lexer::Span;
+
+#[derive(Debug, Clone)]
+pub struct CompoundAssigment {
+    pub left: VariableExpression,
+    pub right: VariableExpression,
+    pub exp_type: CompoundAssigmentType,
+    /// span of the operator, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for CompoundAssigment {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right && self.exp_type == other.exp_type
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompoundAssigmentType {
+    // Arithmetic
+    Add,
+    Sub,
+    Mul,
+    Div,
+    // Logical
+    And,
+    Or,
+    Nullish,
+}
+
+impl CompoundAssigment {
+    pub fn compile(self, compiler: &mut Compiler) -> Result<(), CompilerError> {
+        // the target object and key are evaluated only once
+        let target = self.left.compile_reference(compiler)?;
+        match self.exp_type {
+            // Arithmetic
+            CompoundAssigmentType::Add => {
+                Self::compile_arithmetic(compiler, &target, self.right, |compiler, var1, var2| {
+                    compiler
+                        .predefined_functions()
+                        .arithmetic_addition()
+                        .call(compiler, var1, var2)
+                })?;
+            }
+            CompoundAssigmentType::Sub => {
+                Self::compile_arithmetic(compiler, &target, self.right, |compiler, var1, var2| {
+                    compiler
+                        .predefined_functions()
+                        .arithmetic_substraction()
+                        .call(compiler, var1, var2)
+                })?;
+            }
+            CompoundAssigmentType::Mul => {
+                Self::compile_arithmetic(compiler, &target, self.right, |compiler, var1, var2| {
+                    compiler
+                        .predefined_functions()
+                        .arithmetic_multiplication()
+                        .call(compiler, var1, var2)
+                })?;
+            }
+            CompoundAssigmentType::Div => {
+                Self::compile_arithmetic(compiler, &target, self.right, |compiler, var1, var2| {
+                    compiler
+                        .predefined_functions()
+                        .arithmetic_division()
+                        .call(compiler, var1, var2)
+                })?;
+            }
+            // Logical
+            CompoundAssigmentType::And => {
+                let value = target.get(compiler);
+                Self::compile_logical(compiler, &target, self.right, &value, true)?;
+            }
+            CompoundAssigmentType::Or => {
+                let value = target.get(compiler);
+                Self::compile_logical(compiler, &target, self.right, &value, false)?;
+            }
+            CompoundAssigmentType::Nullish => {
+                // only null and undefined are loosely equal to null
+                let value = target.get(compiler);
+                let null = Variable::new_null(compiler, true);
+                let logical_eq_fn = compiler.predefined_functions().logical_eq();
+                let is_nullish = logical_eq_fn.call(compiler, &value, &null);
+                value.deallocate(compiler);
+                null.deallocate(compiler);
+                Self::compile_logical(compiler, &target, self.right, &is_nullish, true)?;
+            }
+        }
+        target.deallocate(compiler);
+        Ok(())
+    }
+
+    fn compile_arithmetic<'ctx, F>(
+        compiler: &mut Compiler<'ctx>,
+        target: &Reference<'ctx>,
+        right: VariableExpression,
+        call: F,
+    ) -> Result<(), CompilerError>
+    where
+        F: FnOnce(&Compiler<'ctx>, &Variable<'ctx>, &Variable<'ctx>) -> Variable<'ctx>,
+    {
+        let value = target.get(compiler);
+        let right = right.compile_get_variable(compiler)?;
+        let ret = call(compiler, &value, &right);
+        value.deallocate(compiler);
+        if right.is_tmp() {
+            right.deallocate(compiler);
+        }
+        target.set(compiler, &ret, |compiler| {
+            ret.deallocate(compiler);
+            Ok(())
+        })?;
+        ret.deallocate(compiler);
+        Ok(())
+    }
+
+    /// Assigns the right side only if the `condition` is equal to `assign_if`,
+    /// otherwise the right side is not evaluated
+    fn compile_logical<'ctx>(
+        compiler: &mut Compiler<'ctx>,
+        target: &Reference<'ctx>,
+        right: VariableExpression,
+        condition: &Variable<'ctx>,
+        assign_if: bool,
+    ) -> Result<(), CompilerError> {
+        let assign = |compiler: &mut Compiler<'ctx>| {
+            let value = right.compile_get_variable(compiler)?;
+            target.set(compiler, &value, |compiler| {
+                if value.is_tmp() {
+                    value.deallocate(compiler);
+                }
+                Ok(())
+            })?;
+            Ok(value)
+        };
+        let skip = |compiler: &mut Compiler<'ctx>| Ok(Variable::new_undefined(compiler, true));
+
+        let ret = if assign_if {
+            generate_conditional(compiler, condition, assign, skip)?
+        } else {
+            generate_conditional(compiler, condition, skip, assign)?
+        };
+        ret.deallocate(compiler);
+        Ok(())
+    }
+}
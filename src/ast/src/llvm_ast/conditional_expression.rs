@@ -0,0 +1,36 @@
+use super::VariableExpression;
+use crate::{Compiler, CompilerError};
+use compiler::{if_else::generate_conditional, Variable};
+use lexer::Span;
+
+#[derive(Clone, Debug)]
+pub struct ConditionalExpression {
+    pub test: VariableExpression,
+    pub consequent: VariableExpression,
+    pub alternate: VariableExpression,
+    /// span of the "?" token, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for ConditionalExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.test == other.test
+            && self.consequent == other.consequent
+            && self.alternate == other.alternate
+    }
+}
+
+impl ConditionalExpression {
+    pub fn compile<'ctx>(
+        self,
+        compiler: &mut Compiler<'ctx>,
+    ) -> Result<Variable<'ctx>, CompilerError> {
+        let test = self.test.compile_get_variable(compiler)?;
+        let consequent =
+            |compiler: &mut Compiler<'ctx>| self.consequent.compile_get_variable(compiler);
+        let alternate =
+            |compiler: &mut Compiler<'ctx>| self.alternate.compile_get_variable(compiler);
+
+        generate_conditional(compiler, &test, consequent, alternate)
+    }
+}
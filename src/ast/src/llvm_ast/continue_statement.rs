@@ -0,0 +1,26 @@
+use super::DeallocateExpression;
+use crate::{Compiler, CompilerError};
+use compiler::loops::generate_continue;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContinueStatement {
+    /// depth of the target loop, counted from the innermost one
+    pub depth: usize,
+    /// variables deallocated before the jump into each crossed finally block, from the innermost one,
+    /// the last part is deallocated before the jump to the loop
+    pub deallocations: Vec<Vec<DeallocateExpression>>,
+}
+
+impl ContinueStatement {
+    pub fn compile(self, compiler: &mut Compiler) -> Result<(), CompilerError> {
+        let cleanups = self.deallocations.into_iter().map(|deallocations| {
+            move |compiler: &mut Compiler| -> Result<(), CompilerError> {
+                for deallocation in deallocations {
+                    deallocation.compile(compiler)?;
+                }
+                Ok(())
+            }
+        });
+        generate_continue(compiler, self.depth, cleanups)
+    }
+}
@@ -9,6 +9,8 @@ pub struct DeallocateExpression {
 impl DeallocateExpression {
     pub fn compile(self, compiler: &mut Compiler) -> Result<(), CompilerError> {
         let var = compiler.get_variable(self.name)?;
+        let gc_remove_root_fn = compiler.predefined_functions().gc_remove_root();
+        gc_remove_root_fn.call(compiler, &var);
         var.deallocate(compiler);
         Ok(())
     }
@@ -1,7 +1,8 @@
 use super::{
-    DeallocateExpression, DoWhileLoop, Identifier, IfElseStatement, ReturnStatement,
-    VariableAssigment, VariableDeclaration, VariableExpression, VariableFunctionDeclaration,
-    WhileLoop,
+    BreakStatement, CompoundAssigment, ContinueStatement, DeallocateExpression, DoWhileLoop,
+    ForLoop, Identifier, IfElseStatement, ReturnStatement, SwitchStatement, ThrowStatement,
+    TryStatement, VariableAssigment, VariableDeclaration, VariableExpression,
+    VariableFunctionDeclaration, WhileLoop,
 };
 use crate::{Compiler, CompilerError};
 use compiler::Compile;
@@ -11,12 +12,19 @@ pub enum Expression {
     VariableDeclaration(VariableDeclaration),
     VariableFunctionDeclaration(VariableFunctionDeclaration),
     VariableAssigment(VariableAssigment),
+    CompoundAssigment(CompoundAssigment),
     VariableExpression(VariableExpression),
     DeallocateExpression(DeallocateExpression),
     ReturnStatement(ReturnStatement),
     IfElseStatement(IfElseStatement),
     WhileLoop(WhileLoop),
     DoWhileLoop(DoWhileLoop),
+    ForLoop(ForLoop),
+    BreakStatement(BreakStatement),
+    ContinueStatement(ContinueStatement),
+    ThrowStatement(ThrowStatement),
+    TryStatement(TryStatement),
+    SwitchStatement(SwitchStatement),
 }
 
 impl Compile<Identifier> for Expression {
@@ -36,10 +44,15 @@ impl Compile<Identifier> for Expression {
                 variable_assigment.compile(compiler)?;
                 Ok(false)
             }
+            Self::CompoundAssigment(compound_assigment) => {
+                compound_assigment.compile(compiler)?;
+                Ok(false)
+            }
             Self::VariableExpression(variable_expression) => {
-                variable_expression
-                    .compile_get_variable(compiler)?
-                    .deallocate(compiler);
+                let value = variable_expression.compile_get_variable(compiler)?;
+                if value.is_tmp() {
+                    value.deallocate(compiler);
+                }
                 Ok(false)
             }
             Self::DeallocateExpression(deallocate_expression) => {
@@ -59,6 +72,24 @@ impl Compile<Identifier> for Expression {
                 do_while_loop.compile(compiler)?;
                 Ok(false)
             }
+            Self::ForLoop(for_loop) => {
+                for_loop.compile(compiler)?;
+                Ok(false)
+            }
+            Self::BreakStatement(break_statement) => {
+                break_statement.compile(compiler)?;
+                Ok(true)
+            }
+            Self::ContinueStatement(continue_statement) => {
+                continue_statement.compile(compiler)?;
+                Ok(true)
+            }
+            Self::ThrowStatement(throw_statement) => {
+                throw_statement.compile(compiler)?;
+                Ok(true)
+            }
+            Self::TryStatement(try_statement) => try_statement.compile(compiler),
+            Self::SwitchStatement(switch_statement) => switch_statement.compile(compiler),
         }
     }
 }
@@ -0,0 +1,21 @@
+use super::{Expression, VariableExpression};
+use crate::{Compiler, CompilerError};
+use compiler::loops::generate_for_loop;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForLoop {
+    pub init: Vec<Expression>,
+    pub condition: Option<VariableExpression>,
+    pub update: Vec<Expression>,
+    pub body: Vec<Expression>,
+}
+
+impl ForLoop {
+    pub fn compile<'ctx>(self, compiler: &mut Compiler<'ctx>) -> Result<(), CompilerError> {
+        let condition = self.condition.map(|condition| {
+            move |compiler: &mut Compiler<'ctx>| condition.compile_get_variable(compiler)
+        });
+
+        generate_for_loop(compiler, self.init, condition, self.update, self.body)
+    }
+}
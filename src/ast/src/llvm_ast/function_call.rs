@@ -1,15 +1,29 @@
-use super::{Identifier, VariableExpression};
+use super::{DeallocateExpression, Identifier, VariableExpression};
 use crate::{Compiler, CompilerError};
 use compiler::{
     self,
+    exceptions::generate_exception_check,
     predefined_functions::test_utils::{AssertEqFn, AssertFn, PrintFn},
     Variable,
 };
+use lexer::Span;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct FunctionCall {
     pub name: Identifier,
     pub args: Vec<VariableExpression>,
+    /// variables, which should be deallocated if the function throws an exception
+    pub deallocations: Vec<DeallocateExpression>,
+    /// span of the whole call, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for FunctionCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.args == other.args
+            && self.deallocations == other.deallocations
+    }
 }
 
 impl FunctionCall {
@@ -33,7 +47,9 @@ impl FunctionCall {
                 let pritn = compiler.predefined_functions().print();
                 pritn.call(
                     compiler,
-                    &iter.next().ok_or(compiler::Error::NotEnoughArguments)?,
+                    &iter
+                        .next()
+                        .ok_or_else(|| compiler::Error::NotEnoughArguments(self.name.clone()))?,
                 );
                 Ok(Variable::new_undefined(compiler, true))
             }
@@ -42,7 +58,9 @@ impl FunctionCall {
                 let assert_fn = compiler.predefined_functions().assert();
                 assert_fn.call(
                     compiler,
-                    &iter.next().ok_or(compiler::Error::NotEnoughArguments)?,
+                    &iter
+                        .next()
+                        .ok_or_else(|| compiler::Error::NotEnoughArguments(self.name.clone()))?,
                 );
                 Ok(Variable::new_undefined(compiler, true))
             }
@@ -51,14 +69,28 @@ impl FunctionCall {
                 let assert_eq_fn = compiler.predefined_functions().assert_eq();
                 assert_eq_fn.call(
                     compiler,
-                    &iter.next().ok_or(compiler::Error::NotEnoughArguments)?,
-                    &iter.next().ok_or(compiler::Error::NotEnoughArguments)?,
+                    &iter
+                        .next()
+                        .ok_or_else(|| compiler::Error::NotEnoughArguments(self.name.clone()))?,
+                    &iter
+                        .next()
+                        .ok_or_else(|| compiler::Error::NotEnoughArguments(self.name.clone()))?,
                 );
                 Ok(Variable::new_undefined(compiler, true))
             }
             _ => {
                 let var = compiler.get_variable(self.name)?;
                 let ret = var.function_call(compiler, &args);
+                generate_exception_check(compiler, |compiler| {
+                    for arg in &args {
+                        arg.deallocate(compiler);
+                    }
+                    ret.deallocate(compiler);
+                    for deallocation in self.deallocations {
+                        deallocation.compile(compiler)?;
+                    }
+                    Ok(())
+                })?;
                 Ok(ret)
             }
         };
@@ -11,8 +11,11 @@ pub struct VariableFunctionDeclaration {
 impl VariableFunctionDeclaration {
     pub fn compile(self, compiler: &mut Compiler) -> Result<(), CompilerError> {
         let function = compiler.get_function(self.name.clone())?;
-        let var = Variable::new_function(compiler, &function, false);
-        compiler.insert_variable(self.name, var)?;
+        // variable is declared before the function initialization, so the function could capture itself
+        let var = Variable::new_undefined(compiler, false);
+        compiler.insert_variable(self.name, var.clone())?;
+        let env = function.get_captured_variables(compiler)?;
+        var.assign_function(compiler, &function, &env);
         Ok(())
     }
 }
@@ -21,6 +24,8 @@ impl VariableFunctionDeclaration {
 pub struct FunctionDeclaration {
     pub name: Identifier,
     pub args: Vec<Identifier>,
+    /// variables captured from the outer scopes
+    pub captured: Vec<Identifier>,
     pub body: Vec<Expression>,
 }
 
@@ -30,6 +35,7 @@ impl FunctionDeclaration {
             compiler,
             &String::from(self.name.clone()),
             self.args,
+            self.captured,
             self.body,
         )?;
         compiler.insert_function(self.name, function)
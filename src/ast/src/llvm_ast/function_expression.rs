@@ -0,0 +1,20 @@
+use super::Identifier;
+use crate::{Compiler, CompilerError};
+use compiler::Variable;
+
+/// Anonymous function value, the function itself is declared separately
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionExpression {
+    pub name: Identifier,
+}
+
+impl FunctionExpression {
+    pub fn compile<'ctx>(
+        self,
+        compiler: &mut Compiler<'ctx>,
+    ) -> Result<Variable<'ctx>, CompilerError> {
+        let function = compiler.get_function(self.name)?;
+        let env = function.get_captured_variables(compiler)?;
+        Ok(Variable::new_function(compiler, &function, &env, true))
+    }
+}
@@ -1,14 +1,47 @@
-use std::fmt::Display;
+use lexer::Span;
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
 
-#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Identifier {
     name: String,
     index: u32,
+    /// location in the source, it is not the part of the identity
+    span: Span,
+}
+
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.index == other.index
+    }
+}
+
+impl Eq for Identifier {}
+
+impl Hash for Identifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.index.hash(state);
+    }
 }
 
 impl Identifier {
     pub fn new(name: String, index: u32) -> Self {
-        Self { name, index }
+        Self::with_span(name, index, Span::default())
+    }
+
+    pub fn with_span(name: String, index: u32, span: Span) -> Self {
+        Self { name, index, span }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
     }
 }
 
@@ -24,6 +57,10 @@ impl From<Identifier> for String {
 
 impl Display for Identifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "name: {}, index: {}", self.name, self.index)
+        write!(
+            f,
+            "name: {}, index: {}, position: {}",
+            self.name, self.index, self.span
+        )
     }
 }
@@ -1,6 +1,10 @@
-use super::{FunctionCall, Identifier, VariableExpression, VariableValue};
+use super::{
+    DeallocateExpression, FunctionCall, Identifier, PropertyKey, Reference, VariableExpression,
+    VariableValue,
+};
 use crate::{Compiler, CompilerError};
-use compiler::Variable;
+use compiler::{exceptions::generate_exception_check, Variable};
+use lexer::Span;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum PropertyType {
@@ -16,6 +20,21 @@ pub struct Property {
 }
 
 impl Property {
+    /// Splits the last property of the chain, e.g. ".b.c" into ".b" and "c"
+    fn split_last(self) -> (Option<Self>, PropertyType) {
+        match self.property {
+            Some(property) => {
+                let (rest, last) = property.split_last();
+                let rest = Self {
+                    object: self.object,
+                    property: rest.map(Box::new),
+                };
+                (Some(rest), last)
+            }
+            None => (None, self.object),
+        }
+    }
+
     fn compile_get_variable<'ctx>(
         self,
         compiler: &mut Compiler<'ctx>,
@@ -40,6 +59,22 @@ impl Property {
                 let var = variable
                     .get_property_by_str(compiler, String::from(function_call.name).as_str());
                 let ret = var.function_call(compiler, &args);
+                generate_exception_check(compiler, |compiler| {
+                    for arg in &args {
+                        arg.deallocate(compiler);
+                    }
+                    if var.is_tmp() {
+                        var.deallocate(compiler);
+                    }
+                    ret.deallocate(compiler);
+                    for deallocation in function_call.deallocations {
+                        deallocation.compile(compiler)?;
+                    }
+                    Ok(())
+                })?;
+                if var.is_tmp() {
+                    var.deallocate(compiler);
+                }
 
                 // deallocate arguments
                 for arg in args {
@@ -75,7 +110,11 @@ impl Property {
             }
         };
         if let Some(property) = self.property {
-            property.compile_get_variable(compiler, &variable)
+            let res = property.compile_get_variable(compiler, &variable)?;
+            if variable.is_tmp() {
+                variable.deallocate(compiler);
+            }
+            Ok(res)
         } else {
             Ok(variable)
         }
@@ -87,9 +126,16 @@ impl Property {
         variable: &Variable<'ctx>,
         new_value: &Variable<'ctx>,
     ) -> Result<(), CompilerError> {
-        if let Some(property) = self.property.clone() {
-            let variable = self.compile_get_variable(compiler, variable)?;
+        if let Some(property) = self.property {
+            let object = Self {
+                object: self.object,
+                property: None,
+            };
+            let variable = object.compile_get_variable(compiler, variable)?;
             property.compile_update_variable(compiler, &variable, new_value)?;
+            if variable.is_tmp() {
+                variable.deallocate(compiler);
+            }
         } else {
             match self.object {
                 PropertyType::Identifier(identifier) => {
@@ -113,7 +159,24 @@ impl Property {
 
                     let var = variable
                         .get_property_by_str(compiler, String::from(function_call.name).as_str());
-                    let _: Variable = var.function_call(compiler, &args);
+                    let ret = var.function_call(compiler, &args);
+                    generate_exception_check(compiler, |compiler| {
+                        for arg in &args {
+                            arg.deallocate(compiler);
+                        }
+                        if var.is_tmp() {
+                            var.deallocate(compiler);
+                        }
+                        ret.deallocate(compiler);
+                        for deallocation in function_call.deallocations {
+                            deallocation.compile(compiler)?;
+                        }
+                        Ok(())
+                    })?;
+                    ret.deallocate(compiler);
+                    if var.is_tmp() {
+                        var.deallocate(compiler);
+                    }
 
                     // deallocate arguments
                     for arg in args {
@@ -149,10 +212,22 @@ impl Property {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct MemberExpression {
     pub object: Box<VariableExpression>,
     pub property: Property,
+    /// variables, which should be deallocated if the property assignment throws an exception
+    pub deallocations: Vec<DeallocateExpression>,
+    /// span of the whole expression, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for MemberExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object
+            && self.property == other.property
+            && self.deallocations == other.deallocations
+    }
 }
 
 impl MemberExpression {
@@ -161,7 +236,11 @@ impl MemberExpression {
         compiler: &mut Compiler<'ctx>,
     ) -> Result<Variable<'ctx>, CompilerError> {
         let variable = self.object.compile_get_variable(compiler)?;
-        self.property.compile_get_variable(compiler, &variable)
+        let res = self.property.compile_get_variable(compiler, &variable)?;
+        if variable.is_tmp() {
+            variable.deallocate(compiler);
+        }
+        Ok(res)
     }
 
     pub fn compile_update_variable<'ctx>(
@@ -172,6 +251,77 @@ impl MemberExpression {
         let variable = self.object.compile_get_variable(compiler)?;
         self.property
             .compile_update_variable(compiler, &variable, new_value)?;
-        Ok(())
+        if variable.is_tmp() {
+            variable.deallocate(compiler);
+        }
+        // the value, which cannot have properties, throws an exception
+        generate_exception_check(compiler, |compiler| {
+            if new_value.is_tmp() {
+                new_value.deallocate(compiler);
+            }
+            for deallocation in self.deallocations {
+                deallocation.compile(compiler)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Evaluates the object and the last property key, so the property could be read and updated several times
+    pub fn compile_reference<'ctx>(
+        self,
+        compiler: &mut Compiler<'ctx>,
+    ) -> Result<Reference<'ctx>, CompilerError> {
+        let variable = self.object.compile_get_variable(compiler)?;
+        let (rest, last) = self.property.split_last();
+        let object = match rest {
+            Some(rest) => {
+                let object = rest.compile_get_variable(compiler, &variable)?;
+                if variable.is_tmp() {
+                    variable.deallocate(compiler);
+                }
+                object
+            }
+            None => variable,
+        };
+
+        let key = match last {
+            PropertyType::Identifier(identifier) => PropertyKey::String(String::from(identifier)),
+            PropertyType::VariableExpression(VariableExpression::VariableValue(
+                VariableValue::Boolean(key),
+            )) => PropertyKey::Boolean(key),
+            PropertyType::VariableExpression(VariableExpression::VariableValue(
+                VariableValue::FloatNumber(key),
+            )) => PropertyKey::Number(key),
+            PropertyType::VariableExpression(VariableExpression::VariableValue(
+                VariableValue::String(key),
+            )) => PropertyKey::String(key),
+            PropertyType::VariableExpression(variable_expression) => {
+                // the key is copied, so it is not affected by the right side of the assignment
+                let value = variable_expression.compile_get_variable(compiler)?;
+                let key = Variable::new_undefined(compiler, true);
+                key.assign_variable(compiler, &value);
+                if value.is_tmp() {
+                    value.deallocate(compiler);
+                }
+                PropertyKey::Variable(key)
+            }
+            // method call result is not assignable, it is updated as a temporary value
+            PropertyType::FunctionCall(function_call) => {
+                let property = Property {
+                    object: PropertyType::FunctionCall(function_call),
+                    property: None,
+                };
+                let value = property.compile_get_variable(compiler, &object)?;
+                if object.is_tmp() {
+                    object.deallocate(compiler);
+                }
+                return Ok(Reference::Variable(value));
+            }
+        };
+        Ok(Reference::Property {
+            object,
+            key,
+            deallocations: self.deallocations,
+        })
     }
 }
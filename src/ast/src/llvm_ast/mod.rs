@@ -1,18 +1,28 @@
 use crate::CompilerError;
 pub use array_expression::ArrayExpression;
 pub use binary_expression::{BinaryExpType, BinaryExpression};
+pub use break_statement::BreakStatement;
 use compiler::{Compiler, Context, MainFunction};
+pub use compound_assigment::{CompoundAssigment, CompoundAssigmentType};
+pub use conditional_expression::ConditionalExpression;
+pub use continue_statement::ContinueStatement;
 pub use deallocate_expression::DeallocateExpression;
 pub use do_while_loop::DoWhileLoop;
 pub use expression::Expression;
+pub use for_loop::ForLoop;
 pub use function_call::FunctionCall;
 pub use function_declaration::{FunctionDeclaration, VariableFunctionDeclaration};
+pub use function_expression::FunctionExpression;
 pub use identifier::Identifier;
 pub use if_else_statement::IfElseStatement;
 pub use member_expression::{MemberExpression, Property, PropertyType};
 pub use object_expression::ObjectExpression;
+use reference::{PropertyKey, Reference};
 pub use return_statement::ReturnStatement;
 use std::path::Path;
+pub use switch_statement::{SwitchCase, SwitchStatement};
+pub use throw_statement::ThrowStatement;
+pub use try_statement::{CatchClause, TryStatement};
 pub use unary_expression::{UnaryExpType, UnaryExpression};
 pub use variable_assigment::VariableAssigment;
 pub use variable_declaration::VariableDeclaration;
@@ -22,16 +32,26 @@ pub use while_loop::WhileLoop;
 
 mod array_expression;
 mod binary_expression;
+mod break_statement;
+mod compound_assigment;
+mod conditional_expression;
+mod continue_statement;
 mod deallocate_expression;
 mod do_while_loop;
 mod expression;
+mod for_loop;
 mod function_call;
 mod function_declaration;
+mod function_expression;
 mod identifier;
 mod if_else_statement;
 mod member_expression;
 mod object_expression;
+mod reference;
 mod return_statement;
+mod switch_statement;
+mod throw_statement;
+mod try_statement;
 mod unary_expression;
 mod variable_assigment;
 mod variable_declaration;
@@ -39,6 +59,7 @@ mod variable_expression;
 mod variable_value;
 mod while_loop;
 
+#[derive(Debug)]
 pub struct Module {
     name: String,
     functions: Vec<FunctionDeclaration>,
@@ -54,7 +75,20 @@ impl Module {
         }
     }
 
+    /// Writes the LLVM IR in the text form, like ".ll" file
     pub fn compile_to(self, path: &Path) -> Result<(), CompilerError> {
+        self.compile(|compiler| compiler.generate_llvm_ir(path))
+    }
+
+    /// Writes the LLVM bitcode, like ".bc" file
+    pub fn compile_to_bitcode(self, path: &Path) -> Result<(), CompilerError> {
+        self.compile(|compiler| compiler.generate_llvm_bitcode(path))
+    }
+
+    fn compile<F>(self, generate: F) -> Result<(), CompilerError>
+    where
+        F: FnOnce(&Compiler<Identifier>) -> Result<(), CompilerError>,
+    {
         let context = Context::new();
         let compiler = &mut Compiler::new(&context, self.name.as_str());
 
@@ -63,7 +97,6 @@ impl Module {
         }
         // define main function
         MainFunction::define(compiler, self.body)?;
-        compiler.generate_llvm_ir(path)?;
-        Ok(())
+        generate(compiler)
     }
 }
@@ -0,0 +1,107 @@
+use super::{DeallocateExpression, Identifier};
+use crate::CompilerError;
+use compiler::{exceptions::generate_exception_check, Compiler, Variable};
+
+/// Evaluated property key of the member expression
+pub enum PropertyKey<'ctx> {
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Variable(Variable<'ctx>),
+}
+
+/// Evaluated assignment target, which could be read and updated several times.
+/// The object and the key of the member expression are evaluated only once
+pub enum Reference<'ctx> {
+    Variable(Variable<'ctx>),
+    Property {
+        object: Variable<'ctx>,
+        key: PropertyKey<'ctx>,
+        /// variables, which should be deallocated if the property update throws an exception
+        deallocations: Vec<DeallocateExpression>,
+    },
+}
+
+impl<'ctx> Reference<'ctx> {
+    /// Returns the current value as a temporary variable
+    pub fn get<T>(&self, compiler: &Compiler<'ctx, T>) -> Variable<'ctx> {
+        match self {
+            Self::Variable(variable) => {
+                let value = Variable::new_undefined(compiler, true);
+                value.assign_variable(compiler, variable);
+                value
+            }
+            Self::Property { object, key, .. } => match key {
+                PropertyKey::Boolean(key) => object.get_property_by_boolean(compiler, *key),
+                PropertyKey::Number(key) => object.get_property_by_number(compiler, *key),
+                PropertyKey::String(key) => object.get_property_by_str(compiler, key),
+                PropertyKey::Variable(key) => object.get_property_by_var(compiler, key),
+            },
+        }
+    }
+
+    /// Updates the value, the property update throws an exception if the object cannot have properties.
+    /// `cleanup` is compiled before the jump to the exception handler, the reference is deallocated after it
+    pub fn set(
+        &self,
+        compiler: &mut Compiler<'ctx, Identifier>,
+        new_value: &Variable<'ctx>,
+        cleanup: impl FnOnce(&mut Compiler<'ctx, Identifier>) -> Result<(), CompilerError>,
+    ) -> Result<(), CompilerError> {
+        match self {
+            Self::Variable(variable) => variable.assign_variable(compiler, new_value),
+            Self::Property {
+                object,
+                key,
+                deallocations,
+            } => {
+                match key {
+                    PropertyKey::Boolean(key) => {
+                        object.add_property_by_boolean(compiler, *key, new_value);
+                    }
+                    PropertyKey::Number(key) => {
+                        object.add_property_by_number(compiler, *key, new_value);
+                    }
+                    PropertyKey::String(key) => {
+                        object.add_property_by_str(compiler, key, new_value);
+                    }
+                    PropertyKey::Variable(key) => {
+                        object.add_property_by_var(compiler, key, new_value);
+                    }
+                }
+                generate_exception_check(compiler, |compiler| {
+                    cleanup(compiler)?;
+                    if object.is_tmp() {
+                        object.deallocate(compiler);
+                    }
+                    if let PropertyKey::Variable(key) = key {
+                        key.deallocate(compiler);
+                    }
+                    for deallocation in deallocations.clone() {
+                        deallocation.compile(compiler)?;
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn deallocate<T>(self, compiler: &Compiler<'ctx, T>) {
+        match self {
+            Self::Variable(variable) => {
+                if variable.is_tmp() {
+                    variable.deallocate(compiler);
+                }
+            }
+            Self::Property { object, key, .. } => {
+                if object.is_tmp() {
+                    object.deallocate(compiler);
+                }
+                if let PropertyKey::Variable(key) = key {
+                    key.deallocate(compiler);
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use super::VariableExpression;
+use super::{DeallocateExpression, VariableExpression};
 use crate::{Compiler, CompilerError, Function};
 use compiler::Variable;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReturnStatement {
     pub ret: VariableExpression,
+    /// variables deallocated before the jump into each crossed finally block, from the innermost one,
+    /// the last part is deallocated before the return
+    pub deallocations: Vec<Vec<DeallocateExpression>>,
 }
 
 impl ReturnStatement {
@@ -12,8 +15,17 @@ impl ReturnStatement {
         let value = self.ret.compile_get_variable(compiler)?;
         let ret = Variable::new_undefined(compiler, true);
         ret.assign_variable(compiler, &value);
-        value.deallocate(compiler);
-        Function::return_value(compiler, &ret);
-        Ok(())
+        if value.is_tmp() {
+            value.deallocate(compiler);
+        }
+        let cleanups = self.deallocations.into_iter().map(|deallocations| {
+            move |compiler: &mut Compiler| -> Result<(), CompilerError> {
+                for deallocation in deallocations {
+                    deallocation.compile(compiler)?;
+                }
+                Ok(())
+            }
+        });
+        Function::generate_return(compiler, ret, cleanups)
     }
 }
@@ -0,0 +1,34 @@
+use super::{Expression, VariableExpression};
+use crate::{Compiler, CompilerError};
+use compiler::if_else::generate_switch;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchCase {
+    /// `None` for the "default" case
+    pub test: Option<VariableExpression>,
+    pub body: Vec<Expression>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchStatement {
+    pub discriminant: VariableExpression,
+    pub cases: Vec<SwitchCase>,
+}
+
+impl SwitchStatement {
+    pub fn compile<'ctx>(self, compiler: &mut Compiler<'ctx>) -> Result<bool, CompilerError> {
+        let discriminant = self.discriminant.compile_get_variable(compiler)?;
+        let cases = self
+            .cases
+            .into_iter()
+            .map(|case| {
+                let test = case.test.map(|test| {
+                    move |compiler: &mut Compiler<'ctx>| test.compile_get_variable(compiler)
+                });
+                (test, case.body)
+            })
+            .collect();
+
+        generate_switch(compiler, &discriminant, cases)
+    }
+}
@@ -0,0 +1,24 @@
+use super::{DeallocateExpression, VariableExpression};
+use crate::{Compiler, CompilerError};
+use compiler::exceptions::generate_throw;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrowStatement {
+    pub value: VariableExpression,
+    pub deallocations: Vec<DeallocateExpression>,
+}
+
+impl ThrowStatement {
+    pub fn compile(self, compiler: &mut Compiler) -> Result<(), CompilerError> {
+        let value = self.value.compile_get_variable(compiler)?;
+        generate_throw(compiler, &value, |compiler| {
+            if value.is_tmp() {
+                value.deallocate(compiler);
+            }
+            for deallocation in self.deallocations {
+                deallocation.compile(compiler)?;
+            }
+            Ok(())
+        })
+    }
+}
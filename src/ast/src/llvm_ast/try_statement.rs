@@ -0,0 +1,35 @@
+use super::{DeallocateExpression, Expression, Identifier};
+use crate::{Compiler, CompilerError};
+use compiler::exceptions::generate_try_statement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatchClause {
+    pub param: Option<Identifier>,
+    pub body: Vec<Expression>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TryStatement {
+    pub try_block: Vec<Expression>,
+    pub catch: Option<CatchClause>,
+    pub finally: Option<Vec<Expression>>,
+    /// variables, which should be deallocated if the exception is rethrown from the finally block
+    pub deallocations: Vec<DeallocateExpression>,
+}
+
+impl TryStatement {
+    pub fn compile(self, compiler: &mut Compiler) -> Result<bool, CompilerError> {
+        generate_try_statement(
+            compiler,
+            self.try_block,
+            self.catch.map(|catch| (catch.param, catch.body)),
+            self.finally,
+            |compiler| {
+                for deallocation in self.deallocations {
+                    deallocation.compile(compiler)?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
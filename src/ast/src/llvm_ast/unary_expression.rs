@@ -1,16 +1,35 @@
-use super::VariableExpression;
+use super::{Reference, VariableExpression};
 use crate::{Compiler, CompilerError};
 use compiler::Variable;
+use lexer::Span;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct UnaryExpression {
     pub exp: VariableExpression,
     pub exp_type: UnaryExpType,
+    /// span of the operator, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for UnaryExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.exp == other.exp && self.exp_type == other.exp_type
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UnaryExpType {
     Not,
+    BitNot,
+    Neg,
+    Plus,
+    Typeof,
+    Void,
+    // Update
+    PrefixInc,
+    PrefixDec,
+    PostfixInc,
+    PostfixDec,
 }
 
 impl UnaryExpression {
@@ -28,6 +47,103 @@ impl UnaryExpression {
                 }
                 Ok(ret)
             }
+            UnaryExpType::BitNot => {
+                let var = self.exp.compile_get_variable(compiler)?;
+                let bitwise_not_fn = compiler.predefined_functions().arithmetic_bitwise_not();
+                let ret = bitwise_not_fn.call(compiler, &var);
+                if var.is_tmp() {
+                    var.deallocate(compiler);
+                }
+                Ok(ret)
+            }
+            UnaryExpType::Neg => {
+                let var = self.exp.compile_get_variable(compiler)?;
+                let negation_fn = compiler.predefined_functions().arithmetic_negation();
+                let ret = negation_fn.call(compiler, &var);
+                if var.is_tmp() {
+                    var.deallocate(compiler);
+                }
+                Ok(ret)
+            }
+            UnaryExpType::Plus => {
+                let var = self.exp.compile_get_variable(compiler)?;
+                let convert_to_number_fn = compiler.predefined_functions().convert_to_number();
+                let ret = convert_to_number_fn.call(compiler, &var);
+                if var.is_tmp() {
+                    var.deallocate(compiler);
+                }
+                Ok(ret)
+            }
+            UnaryExpType::Typeof => {
+                let var = self.exp.compile_get_variable(compiler)?;
+                let get_type_of_fn = compiler.predefined_functions().get_type_of();
+                let ret = get_type_of_fn.call(compiler, &var);
+                if var.is_tmp() {
+                    var.deallocate(compiler);
+                }
+                Ok(ret)
+            }
+            UnaryExpType::Void => {
+                let var = self.exp.compile_get_variable(compiler)?;
+                if var.is_tmp() {
+                    var.deallocate(compiler);
+                }
+                Ok(Variable::new_undefined(compiler, true))
+            }
+            UnaryExpType::PrefixInc => {
+                let target = self.exp.compile_reference(compiler)?;
+                Self::compile_update(compiler, target, true, true)
+            }
+            UnaryExpType::PrefixDec => {
+                let target = self.exp.compile_reference(compiler)?;
+                Self::compile_update(compiler, target, false, true)
+            }
+            UnaryExpType::PostfixInc => {
+                let target = self.exp.compile_reference(compiler)?;
+                Self::compile_update(compiler, target, true, false)
+            }
+            UnaryExpType::PostfixDec => {
+                let target = self.exp.compile_reference(compiler)?;
+                Self::compile_update(compiler, target, false, false)
+            }
+        }
+    }
+
+    /// Increments or decrements the `target` by 1.
+    /// Returns the updated value for the prefix form and the old numeric value for the postfix one
+    fn compile_update<'ctx>(
+        compiler: &mut Compiler<'ctx>,
+        target: Reference<'ctx>,
+        is_inc: bool,
+        is_prefix: bool,
+    ) -> Result<Variable<'ctx>, CompilerError> {
+        let value = target.get(compiler);
+        let convert_to_number_fn = compiler.predefined_functions().convert_to_number();
+        let old_value = convert_to_number_fn.call(compiler, &value);
+        value.deallocate(compiler);
+
+        let one = Variable::new_number(compiler, 1_f64, true);
+        let new_value = if is_inc {
+            let addition_fn = compiler.predefined_functions().arithmetic_addition();
+            addition_fn.call(compiler, &old_value, &one)
+        } else {
+            let substraction_fn = compiler.predefined_functions().arithmetic_substraction();
+            substraction_fn.call(compiler, &old_value, &one)
+        };
+        one.deallocate(compiler);
+
+        target.set(compiler, &new_value, |compiler| {
+            old_value.deallocate(compiler);
+            new_value.deallocate(compiler);
+            Ok(())
+        })?;
+        target.deallocate(compiler);
+        if is_prefix {
+            old_value.deallocate(compiler);
+            Ok(new_value)
+        } else {
+            new_value.deallocate(compiler);
+            Ok(old_value)
         }
     }
 }
@@ -1,10 +1,19 @@
 use super::VariableExpression;
 use crate::{Compiler, CompilerError};
+use lexer::Span;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct VariableAssigment {
     pub left: VariableExpression,
     pub right: VariableExpression,
+    /// span of the "=" token, it is not the part of the identity
+    pub span: Span,
+}
+
+impl PartialEq for VariableAssigment {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right
+    }
 }
 
 impl VariableAssigment {
@@ -1,4 +1,7 @@
-use super::{BinaryExpression, FunctionCall, MemberExpression, UnaryExpression, VariableValue};
+use super::{
+    BinaryExpression, ConditionalExpression, FunctionCall, MemberExpression, Reference,
+    UnaryExpression, VariableValue,
+};
 use crate::{Compiler, CompilerError};
 use compiler::Variable;
 
@@ -9,6 +12,7 @@ pub enum VariableExpression {
     BinaryExpression(Box<BinaryExpression>),
     MemberExpression(Box<MemberExpression>),
     FunctionCall(FunctionCall),
+    ConditionalExpression(Box<ConditionalExpression>),
 }
 
 impl VariableExpression {
@@ -22,6 +26,7 @@ impl VariableExpression {
             Self::BinaryExpression(expr) => expr.compile(compiler),
             Self::MemberExpression(expr) => expr.compile_get_variable(compiler),
             Self::FunctionCall(function_call) => function_call.compile(compiler),
+            Self::ConditionalExpression(expr) => expr.compile(compiler),
         }
     }
 
@@ -59,10 +64,32 @@ impl VariableExpression {
                     value.deallocate(compiler);
                 }
             }
+            Self::ConditionalExpression(expr) => {
+                let value = expr.compile(compiler)?;
+                value.assign_variable(compiler, new_value);
+                if value.is_tmp() {
+                    value.deallocate(compiler);
+                }
+            }
             Self::MemberExpression(expr) => {
                 expr.compile_update_variable(compiler, new_value)?;
             }
         }
         Ok(())
     }
+
+    /// Evaluates the assignment target, so it could be read and updated several times.
+    /// The precompiler accepts only the variable or the member expression as the target
+    pub fn compile_reference<'ctx>(
+        self,
+        compiler: &mut Compiler<'ctx>,
+    ) -> Result<Reference<'ctx>, CompilerError> {
+        match self {
+            Self::VariableValue(VariableValue::Identifier(identifier)) => {
+                Ok(Reference::Variable(compiler.get_variable(identifier)?))
+            }
+            Self::MemberExpression(expr) => expr.compile_reference(compiler),
+            _ => unreachable!("invalid assignment target is rejected by the precompiler"),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::{ArrayExpression, Identifier, ObjectExpression};
+use super::{ArrayExpression, FunctionExpression, Identifier, ObjectExpression};
 use crate::{Compiler, CompilerError};
 use compiler::Variable;
 
@@ -15,6 +15,7 @@ pub enum VariableValue {
     Identifier(Identifier),
     ObjectExpression(ObjectExpression),
     ArrayExpression(ArrayExpression),
+    FunctionExpression(FunctionExpression),
 }
 
 impl VariableValue {
@@ -34,6 +35,7 @@ impl VariableValue {
             Self::Identifier(identifier) => compiler.get_variable(identifier),
             Self::ObjectExpression(object_expression) => object_expression.compile(compiler),
             Self::ArrayExpression(array_expression) => array_expression.compile(compiler),
+            Self::FunctionExpression(function_expression) => function_expression.compile(compiler),
         }
     }
 }
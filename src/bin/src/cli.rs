@@ -1,67 +1,316 @@
+use crate::diagnostic::ErrorFormat;
 use assembler::{compile_binary, AssemblerError};
-use ast::{js_ast::Module, CompilerError, LexerError, PrecompilerError};
-use clap::Parser;
+use ast::{js_ast, llvm_ast, CompilerError, LexerError, PrecompilerError};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use compiler::predefined_functions::test_utils::{AssertEqFn, AssertFn, PrintFn};
+use lexer::{Token, TokenReader};
 use std::{
-    fs::remove_file,
+    fs::{remove_file, File},
     path::{Path, PathBuf},
+    process::ExitStatus,
 };
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error(transparent)]
     CannotOpenFile(std::io::Error),
-    #[error(transparent)]
-    Lexer(#[from] LexerError),
-    #[error(transparent)]
-    Precompiler(#[from] PrecompilerError),
+    #[error("Cannot parse the module, errors count: {}", .0.len())]
+    Lexer(#[from] Vec<LexerError>),
+    #[error("Cannot precompile the module, errors count: {}", .0.len())]
+    Precompiler(#[from] Vec<PrecompilerError>),
     #[error(transparent)]
     Compiler(#[from] CompilerError),
     #[error(transparent)]
     Assembler(#[from] AssemblerError),
+    #[error("Cannot run the program: {0}")]
+    CannotRun(std::io::Error),
 }
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None, rename_all = "kebab-case")]
 pub struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+
+    /// Format of the reported errors
+    #[clap(long, global = true, value_enum, default_value_t = ErrorFormat::Human)]
+    error_format: ErrorFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compiles the input into the binary
+    Build {
+        #[clap(flatten)]
+        input: Input,
+
+        /// Binary name
+        #[clap(long, default_value = "run")]
+        binary_name: String,
+
+        /// Removes the intermediate ".ll" file
+        #[clap(long, default_value_t = false)]
+        clean: bool,
+    },
+    /// Builds the input into the temporary directory and runs it, the exit code of the program is forwarded
+    Run {
+        #[clap(flatten)]
+        input: Input,
+    },
+    /// Writes the LLVM IR, the bitcode is written if the output has the ".bc" extension
+    EmitIr {
+        #[clap(flatten)]
+        input: Input,
+
+        /// Path to the output file, the ".ll" file next to the input by default
+        #[clap(long)]
+        output: Option<PathBuf>,
+    },
+    /// Prints the AST of the parsed or precompiled module
+    EmitAst {
+        #[clap(flatten)]
+        input: Input,
+
+        #[clap(long, value_enum, default_value_t = AstKind::Js)]
+        ast: AstKind,
+    },
+    /// Prints the tokens of the input with their positions
+    Tokens {
+        #[clap(flatten)]
+        input: Input,
+    },
+    /// Reports the syntax and precompilation errors without the compilation
+    Check {
+        #[clap(flatten)]
+        input: Input,
+    },
+}
+
+#[derive(Args)]
+struct Input {
     /// Path to the input file
     #[clap(long)]
     input: PathBuf,
+}
 
-    /// Binary name
-    #[clap(long, default_value = "run")]
-    binary_name: String,
-
-    #[clap(long, default_value_t = false)]
-    clean: bool,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum AstKind {
+    /// the parsed module
+    Js,
+    /// the precompiled module, which is compiled into the LLVM IR
+    Llvm,
 }
 
 impl Cli {
-    pub fn exec(self) -> Result<(), Error> {
-        let in_file = std::fs::File::open(&self.input).map_err(Error::CannotOpenFile)?;
-        let file_name = self.input.file_stem().unwrap().to_str().unwrap();
+    pub fn input(&self) -> &Path {
+        match &self.command {
+            Command::Build { input, .. }
+            | Command::Run { input }
+            | Command::EmitIr { input, .. }
+            | Command::EmitAst { input, .. }
+            | Command::Tokens { input }
+            | Command::Check { input } => &input.input,
+        }
+    }
+
+    pub fn error_format(&self) -> ErrorFormat {
+        self.error_format
+    }
 
-        let ll_file_name = format!("{file_name}.ll");
+    /// Executes the command, returns the exit code of the program for `run` and 0 otherwise
+    pub fn exec(self) -> Result<i32, Error> {
+        match self.command {
+            Command::Build {
+                input,
+                binary_name,
+                clean,
+            } => {
+                let ll_file_path = input.with_extension("ll");
+                input.precompile()?.compile_to(&ll_file_path)?;
+                compile_binary(&ll_file_path, Path::new(&binary_name))?;
+                if clean {
+                    remove_file(&ll_file_path).unwrap();
+                }
+                Ok(0)
+            }
+            Command::Run { input } => {
+                // the private directory with the unique name, it is removed with its content on drop
+                // and the removal errors are ignored, so they do not replace the exit code
+                let dir = tempfile::Builder::new()
+                    .prefix("jsc-")
+                    .tempdir()
+                    .map_err(Error::CannotRun)?;
+                run(&input, dir.path())
+            }
+            Command::EmitIr { input, output } => {
+                let output = output.unwrap_or_else(|| input.with_extension("ll"));
+                let module = input.precompile()?;
+                if output
+                    .extension()
+                    .is_some_and(|extension| extension == "bc")
+                {
+                    module.compile_to_bitcode(&output)?;
+                } else {
+                    module.compile_to(&output)?;
+                }
+                Ok(0)
+            }
+            Command::EmitAst { input, ast } => {
+                match ast {
+                    AstKind::Js => println!("{:#?}", input.parse()?),
+                    AstKind::Llvm => println!("{:#?}", input.precompile()?),
+                }
+                Ok(0)
+            }
+            Command::Tokens { input } => {
+                print_tokens(&input)?;
+                Ok(0)
+            }
+            Command::Check { input } => {
+                input.precompile()?;
+                Ok(0)
+            }
+        }
+    }
+}
 
-        let ll_file_path = if let Some(parent) = self.input.parent() {
-            parent.join(ll_file_name)
+impl Input {
+    fn module_name(&self) -> &str {
+        self.input.file_stem().unwrap().to_str().unwrap()
+    }
+
+    /// Returns the path next to the input with the same name and the given `extension`
+    fn with_extension(&self, extension: &str) -> PathBuf {
+        let file_name = format!("{}.{extension}", self.module_name());
+        if let Some(parent) = self.input.parent() {
+            parent.join(file_name)
         } else {
-            ll_file_name.into()
-        };
+            file_name.into()
+        }
+    }
 
+    fn open(&self) -> Result<File, Error> {
+        File::open(&self.input).map_err(Error::CannotOpenFile)
+    }
+
+    fn parse(&self) -> Result<js_ast::Module, Error> {
+        Ok(js_ast::Module::new(
+            self.module_name().to_string(),
+            self.open()?,
+        )?)
+    }
+
+    fn precompile(&self) -> Result<llvm_ast::Module, Error> {
         let extern_functions = vec![
             PrintFn::NAME.to_string(),
             AssertFn::NAME.to_string(),
             AssertEqFn::NAME.to_string(),
         ];
+        Ok(self
+            .parse()?
+            .precompile(extern_functions.into_iter().map(Into::into))?)
+    }
+}
+
+/// Builds the binary inside the `dir` and runs it with the inherited standard streams
+fn run(input: &Input, dir: &Path) -> Result<i32, Error> {
+    let ll_file_path = dir.join(format!("{}.ll", input.module_name()));
+    let binary_path = dir.join(input.module_name());
+    input.precompile()?.compile_to(&ll_file_path)?;
+    compile_binary(&ll_file_path, &binary_path)?;
+    let status = std::process::Command::new(&binary_path)
+        .status()
+        .map_err(Error::CannotRun)?;
+    Ok(exit_code(status))
+}
 
-        Module::new(file_name.to_string(), in_file)?
-            .precompile(extern_functions.into_iter().map(Into::into))?
-            .compile_to(&ll_file_path)?;
-        compile_binary(&ll_file_path, Path::new(&self.binary_name))?;
-        if self.clean {
-            remove_file(&ll_file_path).unwrap();
+/// Returns the exit code of the process, the process killed by the signal exits with 128 + signal number like in shells
+fn exit_code(status: ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
         }
+    }
+    status.code().unwrap_or(1)
+}
+
+/// Prints each token on the separate line as "line:column-line:column token",
+/// invalid tokens are skipped and reported at the end
+fn print_tokens(input: &Input) -> Result<(), Error> {
+    let mut reader = TokenReader::new(input.open()?);
+    let mut errors = Vec::new();
+    loop {
+        match reader.next_token() {
+            Ok(Token::Eof) => break,
+            Ok(token) => {
+                let span = reader.span();
+                println!(
+                    "{}:{}-{}:{} {token}",
+                    span.start.line, span.start.column, span.end.line, span.end.column
+                );
+            }
+            Err(e @ LexerError::ReaderError(_)) => {
+                errors.push(e);
+                break;
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
         Ok(())
+    } else {
+        Err(errors.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_test() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from(["jsc", "build", "--input=a/b.js", "--clean"]).unwrap();
+        assert_eq!(cli.input(), Path::new("a/b.js"));
+        assert!(matches!(
+            cli.command,
+            Command::Build { ref binary_name, clean: true, .. } if binary_name == "run"
+        ));
+
+        let cli = Cli::try_parse_from(["jsc", "emit-ast", "--input=b.js", "--ast=llvm"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::EmitAst {
+                ast: AstKind::Llvm,
+                ..
+            }
+        ));
+
+        // the error format could follow the subcommand
+        let cli =
+            Cli::try_parse_from(["jsc", "check", "--input=b.js", "--error-format=json"]).unwrap();
+        assert_eq!(cli.error_format(), ErrorFormat::Json);
+
+        // the input is required by each subcommand
+        assert!(Cli::try_parse_from(["jsc", "tokens"]).is_err());
+        assert!(Cli::try_parse_from(["jsc", "--input=b.js"]).is_err());
+    }
+
+    #[test]
+    fn input_with_extension_test() {
+        let input = Input {
+            input: "dir/name.js".into(),
+        };
+        assert_eq!(input.module_name(), "name");
+        assert_eq!(input.with_extension("bc"), Path::new("dir/name.bc"));
+
+        let input = Input {
+            input: "name.js".into(),
+        };
+        assert_eq!(input.with_extension("ll"), Path::new("name.ll"));
     }
 }
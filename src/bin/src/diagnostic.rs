@@ -0,0 +1,509 @@
+use crate::cli::Error;
+use ast::{CompilerError, LexerError, PrecompilerError};
+use lexer::{Span, Token};
+use std::fmt::Write;
+
+/// Output format of the reported errors
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// compiler-style diagnostics with the source snippet
+    Human,
+    /// single line JSON object, for editors and CI
+    Json,
+}
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const CYAN: &str = "\x1b[1;36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Error prepared for the reporting
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// location in the source, `None` if it is not related to the source or not known
+    pub span: Option<Span>,
+    pub hint: Option<&'static str>,
+    /// messages of the underlying errors
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    /// Returns the diagnostics of the `error`, each of the collected syntax or precompilation errors is reported separately
+    pub fn from_error(error: &Error) -> Vec<Self> {
+        match error {
+            Error::Lexer(errors) => errors
+                .iter()
+                .map(|e| Self::new(lexer_details(e), e))
+                .collect(),
+            Error::Precompiler(errors) => errors
+                .iter()
+                .map(|e| Self::new(precompiler_details(e), e))
+                .collect(),
+            Error::Compiler(e) => vec![Self::new(compiler_details(e), e)],
+            Error::CannotOpenFile(_) | Error::Assembler(_) | Error::CannotRun(_) => {
+                vec![Self::new((error.to_string(), None, None), error)]
+            }
+        }
+    }
+
+    fn new(
+        (message, span, hint): (String, Option<Span>, Option<&'static str>),
+        error: &dyn std::error::Error,
+    ) -> Self {
+        let mut notes = Vec::new();
+        let mut source = error.source();
+        while let Some(sub_error) = source {
+            notes.push(sub_error.to_string());
+            source = sub_error.source();
+        }
+        Self {
+            message,
+            // nodes, which are not produced from the source, have the default span
+            span: span.filter(|span| span.start.line != 0),
+            hint,
+            notes,
+        }
+    }
+}
+
+fn lexer_details(error: &LexerError) -> (String, Option<Span>, Option<&'static str>) {
+    let (message, hint) = match error {
+        LexerError::UnexpectedSymbol(char, _) => (
+            format!("Unexpected symbol '{char}'"),
+            Some("this symbol could not start or continue any token here"),
+        ),
+        LexerError::UnexpectedToken(Token::Eof, _) => (
+            "Unexpected end of file".to_string(),
+            Some("check for the unclosed braces, brackets or quotes"),
+        ),
+        LexerError::UnexpectedToken(token, _) => (
+            format!("Unexpected token: {token}"),
+            Some("check the syntax of the statement, e.g. a missing separator before this token"),
+        ),
+        LexerError::InvalidEscapeSequence(_) => (
+            "Invalid escape sequence".to_string(),
+            Some(r"supported escapes are \n, \t, \r, \b, \f, \v, \0, \xHH, \uHHHH and \u{H...}"),
+        ),
+        LexerError::InvalidNumber(number, _) => (
+            format!("Invalid number: {number}"),
+            has_misplaced_separator(number)
+                .then_some(r#"numeric separators "_" are allowed only between the digits"#),
+        ),
+        LexerError::ReaderError(_) | LexerError::RpnError(_) => (error.to_string(), None),
+    };
+    (message, error.span(), hint)
+}
+
+/// Returns true if the numeric separator "_" is not surrounded by the digits of the `number`
+fn has_misplaced_separator(number: &str) -> bool {
+    let is_hex = matches!(number.get(..2), Some("0x" | "0X"));
+    let is_digit = |char: Option<&char>| {
+        char.is_some_and(|char| {
+            if is_hex {
+                char.is_ascii_hexdigit()
+            } else {
+                char.is_ascii_digit()
+            }
+        })
+    };
+    let chars: Vec<char> = number.chars().collect();
+    chars.iter().enumerate().any(|(i, char)| {
+        *char == '_' && !(i > 0 && is_digit(chars.get(i - 1)) && is_digit(chars.get(i + 1)))
+    })
+}
+
+fn precompiler_details(error: &PrecompilerError) -> (String, Option<Span>, Option<&'static str>) {
+    match error {
+        PrecompilerError::UndefinedVariable(identifier) => (
+            format!("Undefined variable \"{}\"", identifier.name),
+            Some(identifier.span.clone()),
+            Some(r#"declare it with "let", "const" or "var" before the use"#),
+        ),
+        PrecompilerError::UndefinedLabel(identifier) => (
+            format!("Undefined label \"{}\"", identifier.name),
+            Some(identifier.span.clone()),
+            Some("labels are visible only inside the labeled statement"),
+        ),
+        PrecompilerError::OutsideOfLoop(span) => (
+            "Break or continue statement outside of the loop".to_string(),
+            Some(span.clone()),
+            Some(
+                r#""break" is allowed inside loops and switch statements, "continue" only inside loops"#,
+            ),
+        ),
+        PrecompilerError::ConstantAssigment(identifier) => (
+            format!("Assignment to constant variable \"{}\"", identifier.name),
+            Some(identifier.span.clone()),
+            Some(r#"declare it with "let" to allow the reassignment"#),
+        ),
+        PrecompilerError::MissingInitializer(identifier) => (
+            format!(
+                "Missing initializer in const declaration \"{}\"",
+                identifier.name
+            ),
+            Some(identifier.span.clone()),
+            Some(r#"provide the value, e.g. "const a = 1""#),
+        ),
+        PrecompilerError::UninitializedVariable(identifier) => (
+            format!(
+                "Cannot access \"{}\" before initialization",
+                identifier.name
+            ),
+            Some(identifier.span.clone()),
+            Some(r#""let" and "const" variables are accessible only after their declaration"#),
+        ),
+        PrecompilerError::InvalidAssigmentTarget(span) => (
+            "Invalid left-hand side in assignment".to_string(),
+            Some(span.clone()),
+            Some("only the variable or the object property could be assigned"),
+        ),
+    }
+}
+
+fn compiler_details(error: &CompilerError) -> (String, Option<Span>, Option<&'static str>) {
+    match error {
+        CompilerError::UndefinedVariable(identifier) => (
+            format!("Undefined variable \"{}\"", identifier.name()),
+            Some(identifier.span().clone()),
+            None,
+        ),
+        CompilerError::AlreadyDeclaredVariable(identifier) => (
+            format!("Variable \"{}\" already declared", identifier.name()),
+            Some(identifier.span().clone()),
+            Some("rename one of the variables"),
+        ),
+        CompilerError::UndefinedFunction(identifier) => (
+            format!("Undefined function \"{}\"", identifier.name()),
+            Some(identifier.span().clone()),
+            None,
+        ),
+        CompilerError::AlreadyDeclaredFunction(identifier) => (
+            format!("Function \"{}\" already declared", identifier.name()),
+            Some(identifier.span().clone()),
+            Some("rename one of the functions"),
+        ),
+        CompilerError::NotEnoughArguments(identifier) => (
+            format!(
+                "Not enough arguments for the function \"{}\"",
+                identifier.name()
+            ),
+            Some(identifier.span().clone()),
+            Some("provide all arguments of the built-in function"),
+        ),
+        CompilerError::InvalidModule(_)
+        | CompilerError::CannotWriteModule(_)
+        | CompilerError::UndeclaredFunction(_) => (error.to_string(), None, None),
+    }
+}
+
+/// Wraps the `text` into the ANSI `style` codes if the colors are enabled
+fn paint(text: &str, style: &str, colored: bool) -> String {
+    if colored {
+        format!("{style}{text}{RESET}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Renders all `diagnostics` separated by the empty lines, followed by the summary if there are several of them
+pub fn render_all(
+    diagnostics: &[Diagnostic],
+    file: &str,
+    source: Option<&str>,
+    colored: bool,
+) -> String {
+    let mut res: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.render(file, source, colored))
+        .collect();
+    if diagnostics.len() > 1 {
+        res.push(format!(
+            "{}{}\n",
+            paint("error", RED, colored),
+            paint(
+                &format!(": aborting due to {} previous errors", diagnostics.len()),
+                BOLD,
+                colored
+            )
+        ));
+    }
+    res.join("\n")
+}
+
+/// Escapes the `string` as the JSON string literal, including the quotes
+fn json_string(string: &str) -> String {
+    let mut res = String::from('"');
+    for char in string.chars() {
+        match char {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(res, "\\u{:04x}", u32::from(char));
+            }
+            char => res.push(char),
+        }
+    }
+    res.push('"');
+    res
+}
+
+impl Diagnostic {
+    /// Renders the diagnostic with the `source` line and the caret underline under the span.
+    /// The snippet is omitted if the `source` is not available
+    pub fn render(&self, file: &str, source: Option<&str>, colored: bool) -> String {
+        let mut res = format!(
+            "{}{}\n",
+            paint("error", RED, colored),
+            paint(&format!(": {}", self.message), BOLD, colored)
+        );
+
+        let line = self.span.as_ref().map(|span| span.start.line.to_string());
+        // the gutter is as wide as the line number
+        let gutter = " ".repeat(line.as_ref().map_or(0, String::len));
+        match &self.span {
+            Some(span) => {
+                let _ = writeln!(
+                    res,
+                    "{gutter}{} {file}:{}:{}",
+                    paint("-->", BLUE, colored),
+                    span.start.line,
+                    span.start.column
+                );
+            }
+            None => {
+                let _ = writeln!(res, "{gutter}{} {file}", paint("-->", BLUE, colored));
+            }
+        }
+
+        let source_line = self.span.as_ref().and_then(|span| {
+            let index = usize::try_from(span.start.line).ok()?.checked_sub(1)?;
+            source?.lines().nth(index).map(|line| (span, line))
+        });
+        if let Some((span, source_line)) = source_line {
+            let source_line = source_line.trim_end_matches('\r');
+            let column = usize::try_from(span.start.column).unwrap_or(1).max(1);
+            let len = source_line.chars().count();
+            // multiline spans are underlined until the end of the first line
+            let end = if span.end.line == span.start.line {
+                usize::try_from(span.end.column).unwrap_or(column)
+            } else {
+                len
+            };
+            // tabs are kept, so the caret is aligned with the source symbols
+            let padding: String = source_line
+                .chars()
+                .take(column - 1)
+                .map(|char| if char == '\t' { '\t' } else { ' ' })
+                .collect();
+            let carets = "^".repeat(end.saturating_sub(column) + 1);
+
+            let bar = paint("|", BLUE, colored);
+            let _ = writeln!(res, "{gutter} {bar}");
+            let _ = writeln!(
+                res,
+                "{} {bar} {source_line}",
+                paint(line.as_deref().unwrap_or_default(), BLUE, colored)
+            );
+            let _ = writeln!(
+                res,
+                "{gutter} {bar} {padding}{}",
+                paint(&carets, RED, colored)
+            );
+        }
+
+        if let Some(hint) = self.hint {
+            let _ = writeln!(
+                res,
+                "{gutter} {} {}: {hint}",
+                paint("=", BLUE, colored),
+                paint("hint", CYAN, colored)
+            );
+        }
+        for note in &self.notes {
+            let _ = writeln!(
+                res,
+                "{gutter} {} {}: {note}",
+                paint("=", BLUE, colored),
+                paint("note", BOLD, colored)
+            );
+        }
+        res
+    }
+
+    /// Renders the diagnostic as the single line JSON object
+    pub fn to_json(&self, file: &str) -> String {
+        let position =
+            |value: Option<u64>| value.map_or("null".to_string(), |value| value.to_string());
+        let span = self.span.as_ref();
+        let notes: Vec<_> = self.notes.iter().map(|note| json_string(note)).collect();
+        format!(
+            r#"{{"level":"error","message":{},"file":{},"line":{},"column":{},"end_line":{},"end_column":{},"hint":{},"notes":[{}]}}"#,
+            json_string(&self.message),
+            json_string(file),
+            position(span.map(|span| span.start.line)),
+            position(span.map(|span| span.start.column)),
+            position(span.map(|span| span.end.line)),
+            position(span.map(|span| span.end.column)),
+            self.hint.map_or("null".to_string(), json_string),
+            notes.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lexer::Position;
+
+    #[test]
+    fn diagnostic_from_error_test() {
+        let error = Error::Lexer(vec![
+            LexerError::UnexpectedToken(
+                Token::Eof,
+                Span::new(Position::new(2, 1), Position::new(2, 1)),
+            ),
+            LexerError::InvalidEscapeSequence(Position::new(1, 4)),
+        ]);
+        let diagnostics = Diagnostic::from_error(&error);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0],
+            Diagnostic {
+                message: "Unexpected end of file".to_string(),
+                span: Some(Span::new(Position::new(2, 1), Position::new(2, 1))),
+                hint: Some("check for the unclosed braces, brackets or quotes"),
+                notes: Vec::new(),
+            }
+        );
+        assert_eq!(
+            diagnostics[1].span,
+            Some(Span::new(Position::new(1, 4), Position::new(1, 4)))
+        );
+
+        // the default span is not the source location
+        let error = Error::Precompiler(vec![PrecompilerError::UndefinedVariable(
+            "a".to_string().into(),
+        )]);
+        assert_eq!(Diagnostic::from_error(&error)[0].span, None);
+    }
+
+    #[test]
+    fn invalid_number_hint_test() {
+        let hint = |number: &str| {
+            let error = Error::Lexer(vec![LexerError::InvalidNumber(
+                number.to_string(),
+                Position::new(1, 1),
+            )]);
+            Diagnostic::from_error(&error).remove(0).hint
+        };
+        for number in ["1__0", "1_", "1_.5", "0x_1", "1_e5", "0b_1"] {
+            assert_eq!(
+                hint(number),
+                Some(r#"numeric separators "_" are allowed only between the digits"#),
+                "{number}"
+            );
+        }
+        // the number is invalid for the other reason
+        for number in ["1.2.3", "0xZZ", "1e", "0xF_FG", "1_000.2.3"] {
+            assert_eq!(hint(number), None, "{number}");
+        }
+    }
+
+    #[test]
+    fn render_diagnostic_test() {
+        let diagnostic = Diagnostic {
+            message: "Undefined variable \"foo\"".to_string(),
+            span: Some(Span::new(Position::new(2, 9), Position::new(2, 11))),
+            hint: Some("declare it"),
+            notes: Vec::new(),
+        };
+        assert_eq!(
+            diagnostic.render("test.js", Some("let a = 1;\r\nlet b = foo;\n"), false),
+            "error: Undefined variable \"foo\"\n \
+             --> test.js:2:9\n  \
+             |\n\
+             2 | let b = foo;\n  \
+             |         ^^^\n  \
+             = hint: declare it\n"
+        );
+        assert_eq!(
+            diagnostic.render("test.js", None, false),
+            "error: Undefined variable \"foo\"\n \
+             --> test.js:2:9\n  \
+             = hint: declare it\n"
+        );
+        assert_eq!(
+            diagnostic
+                .render("test.js", Some("\tb = foo"), true)
+                .lines()
+                .nth(1),
+            Some(" \x1b[1;34m-->\x1b[0m test.js:2:9")
+        );
+
+        let diagnostic = Diagnostic {
+            message: "Cannot open file".to_string(),
+            span: None,
+            hint: None,
+            notes: vec!["No such file".to_string()],
+        };
+        assert_eq!(
+            diagnostic.render("test.js", None, false),
+            "error: Cannot open file\n--> test.js\n = note: No such file\n"
+        );
+    }
+
+    #[test]
+    fn render_all_diagnostics_test() {
+        let diagnostic = |message: &str| Diagnostic {
+            message: message.to_string(),
+            span: None,
+            hint: None,
+            notes: Vec::new(),
+        };
+        assert_eq!(
+            render_all(&[diagnostic("first")], "test.js", None, false),
+            "error: first\n--> test.js\n"
+        );
+        assert_eq!(
+            render_all(
+                &[diagnostic("first"), diagnostic("second")],
+                "test.js",
+                None,
+                false
+            ),
+            "error: first\n--> test.js\n\n\
+             error: second\n--> test.js\n\n\
+             error: aborting due to 2 previous errors\n"
+        );
+    }
+
+    #[test]
+    fn diagnostic_to_json_test() {
+        let diagnostic = Diagnostic {
+            message: "Invalid number: 1__0".to_string(),
+            span: Some(Span::new(Position::new(1, 5), Position::new(1, 5))),
+            hint: Some(r#"separators "_""#),
+            notes: vec!["line\nbreak\u{1}".to_string()],
+        };
+        assert_eq!(
+            diagnostic.to_json("dir\\test.js"),
+            r#"{"level":"error","message":"Invalid number: 1__0","file":"dir\\test.js","line":1,"column":5,"end_line":1,"end_column":5,"hint":"separators \"_\"","notes":["line\nbreak\u0001"]}"#
+        );
+
+        let diagnostic = Diagnostic {
+            message: "Cannot open file".to_string(),
+            span: None,
+            hint: None,
+            notes: Vec::new(),
+        };
+        assert_eq!(
+            diagnostic.to_json("test.js"),
+            r#"{"level":"error","message":"Cannot open file","file":"test.js","line":null,"column":null,"end_line":null,"end_column":null,"hint":null,"notes":[]}"#
+        );
+    }
+}
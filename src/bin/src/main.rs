@@ -8,20 +8,41 @@
 
 use clap::Parser;
 use cli::Cli;
-use std::error::Error;
+use diagnostic::{render_all, Diagnostic, ErrorFormat};
+use std::{io::IsTerminal, path::Path};
 
 mod cli;
+mod diagnostic;
 
 fn main() {
-    Cli::parse().exec().unwrap_or_else(|e| report_error(&e));
+    let cli = Cli::parse();
+    let input = cli.input().to_path_buf();
+    let error_format = cli.error_format();
+    match cli.exec() {
+        Ok(exit_code) => std::process::exit(exit_code),
+        Err(e) => report_error(&e, &input, error_format),
+    }
 }
 
-fn report_error(error: &cli::Error) {
-    eprintln!("{error}");
-    let mut source = error.source();
-    while let Some(sub_error) = source {
-        eprintln!("  |-> {sub_error}");
-        source = sub_error.source();
+fn report_error(error: &cli::Error, input: &Path, error_format: ErrorFormat) -> ! {
+    let diagnostics = Diagnostic::from_error(error);
+    let file = input.display().to_string();
+    match error_format {
+        ErrorFormat::Human => {
+            // the source snippet is skipped if the file could not be read
+            let source = std::fs::read_to_string(input).ok();
+            let colored = std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+            eprint!(
+                "{}",
+                render_all(&diagnostics, &file, source.as_deref(), colored)
+            );
+        }
+        // one JSON object per line
+        ErrorFormat::Json => {
+            for diagnostic in &diagnostics {
+                eprintln!("{}", diagnostic.to_json(&file));
+            }
+        }
     }
     std::process::exit(1)
 }
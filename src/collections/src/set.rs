@@ -1,5 +1,5 @@
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::HashMap,
     fmt::{Debug, Display},
     hash::Hash,
 };
@@ -7,7 +7,8 @@ use std::{
 #[derive(Debug, Default)]
 pub struct Set<V: Clone + Eq + Hash + Display> {
     stack: Vec<V>,
-    hash_map: HashMap<V, u32>,
+    /// mutability of each inserted entry of the value, only the last one is visible
+    hash_map: HashMap<V, Vec<bool>>,
 }
 
 impl<V: Clone + Eq + Hash + Display + Debug> Set<V> {
@@ -19,22 +20,36 @@ impl<V: Clone + Eq + Hash + Display + Debug> Set<V> {
     }
 
     pub fn insert(&mut self, value: V) -> u32 {
-        match self.hash_map.entry(value.clone()) {
-            Entry::Vacant(e) => {
-                self.stack.push(value);
-                e.insert(0);
-                0_u32
-            }
-            Entry::Occupied(mut e) => {
-                self.stack.push(value);
-                e.insert(e.get() + 1);
-                *e.get()
-            }
-        }
+        self.insert_impl(value, true)
+    }
+
+    /// Inserts the value, which could not be changed after the initialization
+    pub fn insert_immutable(&mut self, value: V) -> u32 {
+        self.insert_impl(value, false)
+    }
+
+    fn insert_impl(&mut self, value: V, is_mutable: bool) -> u32 {
+        self.stack.push(value.clone());
+        let entries = self.hash_map.entry(value).or_default();
+        entries.push(is_mutable);
+        Self::counter(entries)
     }
 
     pub fn get(&self, value: &V) -> Option<u32> {
-        self.hash_map.get(value).cloned()
+        self.hash_map
+            .get(value)
+            .map(|entries| Self::counter(entries))
+    }
+
+    /// Returns the mutability of the last inserted entry of the value
+    pub fn is_mutable(&self, value: &V) -> Option<bool> {
+        self.hash_map
+            .get(value)
+            .and_then(|entries| entries.last().copied())
+    }
+
+    fn counter(entries: &[bool]) -> u32 {
+        (entries.len() - 1) as u32
     }
 
     pub fn remove_last_added(&mut self, mut size: usize) -> Vec<(V, u32)> {
@@ -42,17 +57,16 @@ impl<V: Clone + Eq + Hash + Display + Debug> Set<V> {
         while size > 0 {
             match self.stack.pop() {
                 Some(value) => {
-                    let counter = self
+                    let entries = self
                         .hash_map
                         .get_mut(&value)
                         .unwrap_or_else(|| panic!("HashMap must contains key: {value}"));
-                    res.push((value.clone(), *counter));
-                    if *counter == 0 {
+                    res.push((value.clone(), Self::counter(entries)));
+                    entries.pop();
+                    if entries.is_empty() {
                         self.hash_map
                             .remove(&value)
                             .unwrap_or_else(|| panic!("HashMap must contains key: {value}"));
-                    } else {
-                        *counter -= 1;
                     }
                 }
                 None => break,
@@ -62,6 +76,30 @@ impl<V: Clone + Eq + Hash + Display + Debug> Set<V> {
         res
     }
 
+    pub fn get_last_added(&self, size: usize) -> Vec<(V, u32)> {
+        // the same value could be inserted several times, so each previous entry has a lower counter
+        let mut shadowed: HashMap<&V, u32> = HashMap::new();
+        self.stack
+            .iter()
+            .rev()
+            .take(size)
+            .map(|value| {
+                let counter = self
+                    .get(value)
+                    .unwrap_or_else(|| panic!("HashMap must contains key: {value}"));
+                let shadowed = shadowed.entry(value).or_insert(0);
+                let res = (value.clone(), counter - *shadowed);
+                *shadowed += 1;
+                res
+            })
+            .collect()
+    }
+
+    /// Checks whether the value was inserted within the last `size` insertions
+    pub fn contains_last_added(&self, value: &V, size: usize) -> bool {
+        self.stack.iter().rev().take(size).any(|el| el == value)
+    }
+
     pub fn len(&self) -> usize {
         self.stack.len()
     }
@@ -120,6 +158,59 @@ mod tests {
         assert_eq!(set.len(), 0);
     }
 
+    #[test]
+    fn get_last_added_test() {
+        let mut set = Set::new();
+
+        assert_eq!(set.get_last_added(10), vec![]);
+
+        assert_eq!(set.insert(5), 0);
+        assert_eq!(set.insert(6), 0);
+        assert_eq!(set.insert(5), 1);
+
+        assert_eq!(set.get_last_added(1), vec![(5, 1)]);
+        assert_eq!(set.get_last_added(2), vec![(5, 1), (6, 0)]);
+        assert_eq!(set.get_last_added(10), vec![(5, 1), (6, 0), (5, 0)]);
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.remove_last_added(3), vec![(5, 1), (6, 0), (5, 0)]);
+    }
+
+    #[test]
+    fn contains_last_added_test() {
+        let mut set = Set::new();
+
+        assert!(!set.contains_last_added(&5, 10));
+
+        assert_eq!(set.insert(5), 0);
+        assert_eq!(set.insert(6), 0);
+
+        assert!(!set.contains_last_added(&5, 1));
+        assert!(set.contains_last_added(&5, 2));
+        assert!(set.contains_last_added(&6, 1));
+        assert!(!set.contains_last_added(&7, 2));
+    }
+
+    #[test]
+    fn is_mutable_test() {
+        let mut set = Set::new();
+
+        assert_eq!(set.is_mutable(&5), None);
+
+        assert_eq!(set.insert(5), 0);
+        assert_eq!(set.insert_immutable(6), 0);
+        assert_eq!(set.is_mutable(&5), Some(true));
+        assert_eq!(set.is_mutable(&6), Some(false));
+
+        // the last inserted entry shadows the previous one
+        assert_eq!(set.insert_immutable(5), 1);
+        assert_eq!(set.get(&5), Some(1));
+        assert_eq!(set.is_mutable(&5), Some(false));
+
+        assert_eq!(set.remove_last_added(1), vec![(5, 1)]);
+        assert_eq!(set.get(&5), Some(0));
+        assert_eq!(set.is_mutable(&5), Some(true));
+    }
+
     #[test]
     fn from_iter_test() {
         let vec = vec![1, 2, 3, 4, 5];
@@ -0,0 +1,459 @@
+use crate::{Compile, Compiler, Error, Function, Variable};
+use inkwell::{
+    basic_block::BasicBlock,
+    values::{PhiValue, PointerValue},
+};
+use std::hash::Hash;
+
+/// Finally block of the currently compiled try statement, it is entered by the jumps out of the try and catch blocks
+pub(crate) struct FinallyTarget<'ctx> {
+    body_block: BasicBlock<'ctx>,
+    /// loops count at the beginning of the try statement, the jumps to the outer loops pass through the finally block
+    pub(crate) loops_len: usize,
+    jumps: Vec<FinallyJump<'ctx>>,
+    /// value passed through the finally block by the `return`
+    value: Option<PhiValue<'ctx>>,
+}
+
+/// Jump into the finally block, the execution is resumed after the finally block
+struct FinallyJump<'ctx> {
+    from_block: BasicBlock<'ctx>,
+    resume_block: BasicBlock<'ctx>,
+    has_value: bool,
+}
+
+/// Checks whether the previous call has thrown an exception, if so jumps to the innermost exception handler.
+/// `cleanup` is compiled before the jump
+pub fn generate_exception_check<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    cleanup: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+) -> Result<(), Error<T>> {
+    let has_exception_fn = compiler.predefined_functions().has_exception();
+    let has_exception = has_exception_fn.call(compiler);
+    let has_exception = compiler.inkwell_context.builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        has_exception,
+        compiler
+            .inkwell_context
+            .context
+            .i8_type()
+            .const_int(1_u64, false),
+        "",
+    );
+
+    let exception_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let continue_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+
+    compiler.inkwell_context.builder.build_conditional_branch(
+        has_exception,
+        exception_block,
+        continue_block,
+    );
+
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(exception_block);
+    cleanup(compiler)?;
+    generate_exception_jump(compiler);
+
+    //
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(continue_block);
+    Ok(())
+}
+
+/// Throws the value, `cleanup` is compiled before the jump to the innermost exception handler
+pub fn generate_throw<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    value: &Variable<'ctx>,
+    cleanup: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+) -> Result<(), Error<T>> {
+    let throw_exception_fn = compiler.predefined_functions().throw_exception();
+    throw_exception_fn.call(compiler, value);
+    cleanup(compiler)?;
+    generate_exception_jump(compiler);
+    Ok(())
+}
+
+/// Generates the jump to the innermost exception handler of the current function.
+/// Without the handler the exception is propagated to the caller,
+/// the main function terminates the program
+fn generate_exception_jump<T>(compiler: &mut Compiler<'_, T>) {
+    if let Some(handler) = compiler.exception_handlers.last() {
+        compiler
+            .inkwell_context
+            .builder
+            .build_unconditional_branch(*handler);
+    } else if compiler.cur_function.as_ref().unwrap().args.is_some() {
+        // returned value is ignored by the caller, the exception remains pending
+        let ret = Variable::new_undefined(compiler, true);
+        Function::return_value(compiler, &ret);
+    } else {
+        let uncaught_exception_fn = compiler.predefined_functions().uncaught_exception();
+        uncaught_exception_fn.call(compiler);
+        compiler.inkwell_context.builder.build_unreachable();
+    }
+}
+
+/// Generates the jumps through the finally blocks of the `crossed` try statements, from the innermost one.
+/// `cleanups` are compiled before the jump into each finally block, the last one after all of them.
+/// `value` is passed through the finally blocks, the passed value is returned
+pub(crate) fn generate_finally_jumps<'ctx, T, C>(
+    compiler: &mut Compiler<'ctx, T>,
+    crossed: impl Fn(&FinallyTarget<'ctx>) -> bool,
+    mut value: Option<Variable<'ctx>>,
+    cleanups: impl IntoIterator<Item = C>,
+) -> Result<Option<Variable<'ctx>>, Error<T>>
+where
+    C: FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+{
+    let mut cleanups = cleanups.into_iter();
+    let crossed_len = compiler
+        .finally_targets
+        .iter()
+        .rev()
+        .take_while(|target| crossed(target))
+        .count();
+    let targets_len = compiler.finally_targets.len();
+    for index in (targets_len - crossed_len..targets_len).rev() {
+        if let Some(cleanup) = cleanups.next() {
+            cleanup(compiler)?;
+        }
+        value = generate_finally_jump(compiler, index, value);
+    }
+    for cleanup in cleanups {
+        cleanup(compiler)?;
+    }
+    Ok(value)
+}
+
+/// Jumps into the finally block with the provided index, the new block receives the execution after the finally block
+fn generate_finally_jump<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    index: usize,
+    value: Option<Variable<'ctx>>,
+) -> Option<Variable<'ctx>> {
+    let from_block = compiler.inkwell_context.builder.get_insert_block().unwrap();
+    let resume_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let body_block = compiler.finally_targets[index].body_block;
+
+    let value = value.map(|value| {
+        let phi = if let Some(phi) = compiler.finally_targets[index].value {
+            phi
+        } else {
+            // the finally block is compiled later, so the phi is the first instruction of its block
+            compiler.inkwell_context.builder.position_at_end(body_block);
+            let phi = compiler
+                .inkwell_context
+                .builder
+                .build_phi(compiler.inkwell_context.variable_type, "");
+            compiler.inkwell_context.builder.position_at_end(from_block);
+            compiler.finally_targets[index].value = Some(phi);
+            phi
+        };
+        phi.add_incoming(&[(&value.value, from_block)]);
+        Variable {
+            value: phi.as_basic_value().into_pointer_value(),
+            is_tmp: value.is_tmp,
+        }
+    });
+    compiler.finally_targets[index].jumps.push(FinallyJump {
+        from_block,
+        resume_block,
+        has_value: value.is_some(),
+    });
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(body_block);
+
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(resume_block);
+    value
+}
+
+/// Generates the `try` statement, at least one of the `catch` or `finally` blocks should be provided.
+/// `catch` contains the name of the variable, which receives the exception.
+/// `cleanup` is compiled before the exception is rethrown from the `finally` block.
+/// The `return`, `break` and `continue`, which leave the `try` or `catch` blocks, pass through the `finally` block
+pub fn generate_try_statement<'ctx, T, Expr: Compile<T, Output = bool>>(
+    compiler: &mut Compiler<'ctx, T>,
+    try_block: Vec<Expr>,
+    catch_block: Option<(Option<T>, Vec<Expr>)>,
+    finally_block: Option<Vec<Expr>>,
+    cleanup: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+) -> Result<bool, Error<T>>
+where
+    T: Clone + Hash + PartialEq + Eq,
+{
+    let catch_bb = catch_block.as_ref().map(|_| {
+        compiler
+            .inkwell_context
+            .context
+            .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "")
+    });
+    // entry of the finally block, when an exception is pending
+    let finally_exception_bb = finally_block.as_ref().map(|_| {
+        compiler
+            .inkwell_context
+            .context
+            .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "")
+    });
+    let finally_bb = finally_block.as_ref().map(|_| {
+        compiler
+            .inkwell_context
+            .context
+            .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "")
+    });
+    let continue_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let after_block = finally_bb.unwrap_or(continue_block);
+    // blocks, which enter the finally block normally
+    let mut finally_incoming = Vec::new();
+
+    if let Some(finally_bb) = finally_bb {
+        compiler.finally_targets.push(FinallyTarget {
+            body_block: finally_bb,
+            loops_len: compiler.loops.len(),
+            jumps: Vec::new(),
+            value: None,
+        });
+    }
+
+    // describe try block
+    compiler
+        .exception_handlers
+        .push(catch_bb.or(finally_exception_bb).unwrap());
+    let is_try_returned = generate_body(compiler, try_block)?;
+    compiler.exception_handlers.pop();
+    if !is_try_returned {
+        finally_incoming.push(compiler.inkwell_context.builder.get_insert_block().unwrap());
+        compiler
+            .inkwell_context
+            .builder
+            .build_unconditional_branch(after_block);
+    }
+
+    // describe catch block
+    if let (Some(catch_bb), Some((param, body))) = (catch_bb, catch_block) {
+        compiler.inkwell_context.builder.position_at_end(catch_bb);
+        let take_exception_fn = compiler.predefined_functions().take_exception();
+        let exception = take_exception_fn.call(compiler);
+        match param {
+            Some(param) => {
+                let var = Variable::new_undefined(compiler, false);
+                var.assign_variable(compiler, &exception);
+                exception.deallocate(compiler);
+                compiler.insert_variable(param, var)?;
+            }
+            None => exception.deallocate(compiler),
+        }
+
+        if let Some(finally_exception_bb) = finally_exception_bb {
+            compiler.exception_handlers.push(finally_exception_bb);
+        }
+        let is_catch_returned = generate_body(compiler, body)?;
+        if finally_exception_bb.is_some() {
+            compiler.exception_handlers.pop();
+        }
+        if !is_catch_returned {
+            finally_incoming.push(compiler.inkwell_context.builder.get_insert_block().unwrap());
+            compiler
+                .inkwell_context
+                .builder
+                .build_unconditional_branch(after_block);
+        }
+    }
+
+    // describe finally block
+    if let (Some(finally_exception_bb), Some(finally_bb), Some(body)) =
+        (finally_exception_bb, finally_bb, finally_block)
+    {
+        let target = compiler.finally_targets.pop().unwrap();
+        let finally_blocks = FinallyBlocks {
+            exception_block: finally_exception_bb,
+            body_block: finally_bb,
+            continue_block,
+            incoming: finally_incoming,
+            jumps: target.jumps,
+            value: target.value,
+        };
+        generate_finally(compiler, finally_blocks, body, cleanup)?;
+    }
+
+    //
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(continue_block);
+    Ok(false)
+}
+
+struct FinallyBlocks<'ctx> {
+    /// entry with the pending exception
+    exception_block: BasicBlock<'ctx>,
+    body_block: BasicBlock<'ctx>,
+    continue_block: BasicBlock<'ctx>,
+    /// blocks, which enter the body normally
+    incoming: Vec<BasicBlock<'ctx>>,
+    /// jumps out of the try statement, which are continued after the body
+    jumps: Vec<FinallyJump<'ctx>>,
+    value: Option<PhiValue<'ctx>>,
+}
+
+/// Generates the `finally` block, the exception, which was pending on the entry, is rethrown after the block.
+/// Otherwise the jump, which has entered the block, is continued
+fn generate_finally<'ctx, T, Expr: Compile<T, Output = bool>>(
+    compiler: &mut Compiler<'ctx, T>,
+    blocks: FinallyBlocks<'ctx>,
+    body: Vec<Expr>,
+    cleanup: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+) -> Result<(), Error<T>> {
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(blocks.exception_block);
+    let take_exception_fn = compiler.predefined_functions().take_exception();
+    let exception = take_exception_fn.call(compiler);
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(blocks.body_block);
+
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(blocks.body_block);
+    // pending exception, null if the block is entered normally or by the jump
+    let null = compiler.inkwell_context.variable_type.const_null();
+    let phi = compiler
+        .inkwell_context
+        .builder
+        .build_phi(compiler.inkwell_context.variable_type, "");
+    phi.add_incoming(&[(&exception.value, blocks.exception_block)]);
+    for block in &blocks.incoming {
+        phi.add_incoming(&[(&null, *block)]);
+    }
+    for jump in &blocks.jumps {
+        phi.add_incoming(&[(&null, jump.from_block)]);
+    }
+    let exception = Variable {
+        value: phi.as_basic_value().into_pointer_value(),
+        is_tmp: true,
+    };
+    let jumps_block = generate_jumps_phi(compiler, &blocks, &null);
+
+    if !generate_body(compiler, body)? {
+        let rethrow_block = compiler
+            .inkwell_context
+            .context
+            .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+        let is_null = compiler
+            .inkwell_context
+            .builder
+            .build_is_null(exception.value, "");
+        compiler.inkwell_context.builder.build_conditional_branch(
+            is_null,
+            jumps_block.unwrap_or(blocks.continue_block),
+            rethrow_block,
+        );
+
+        // rethrow the exception
+        compiler
+            .inkwell_context
+            .builder
+            .position_at_end(rethrow_block);
+        let throw_exception_fn = compiler.predefined_functions().throw_exception();
+        throw_exception_fn.call(compiler, &exception);
+        exception.deallocate(compiler);
+        cleanup(compiler)?;
+        generate_exception_jump(compiler);
+    }
+    Ok(())
+}
+
+/// Generates the phi values of the finally block entered by the jumps, each jump has its own index.
+/// Returns the block, which continues the jumps after the body
+fn generate_jumps_phi<'ctx, T>(
+    compiler: &Compiler<'ctx, T>,
+    blocks: &FinallyBlocks<'ctx>,
+    null: &PointerValue<'ctx>,
+) -> Option<BasicBlock<'ctx>> {
+    // returned value is only passed by the jumps with the value
+    if let Some(value) = blocks.value {
+        value.add_incoming(&[(null, blocks.exception_block)]);
+        for block in &blocks.incoming {
+            value.add_incoming(&[(null, *block)]);
+        }
+        for jump in blocks.jumps.iter().filter(|jump| !jump.has_value) {
+            value.add_incoming(&[(null, jump.from_block)]);
+        }
+    }
+    if blocks.jumps.is_empty() {
+        return None;
+    }
+
+    // index of the jump, zero if the block is entered normally or with the exception
+    let index_type = compiler.inkwell_context.context.i32_type();
+    let zero = index_type.const_zero();
+    let index = compiler.inkwell_context.builder.build_phi(index_type, "");
+    index.add_incoming(&[(&zero, blocks.exception_block)]);
+    for block in &blocks.incoming {
+        index.add_incoming(&[(&zero, *block)]);
+    }
+    let mut cases = Vec::with_capacity(blocks.jumps.len());
+    for (i, jump) in (1_u64..).zip(&blocks.jumps) {
+        let jump_index = index_type.const_int(i, false);
+        index.add_incoming(&[(&jump_index, jump.from_block)]);
+        cases.push((jump_index, jump.resume_block));
+    }
+
+    let current_block = compiler.inkwell_context.builder.get_insert_block().unwrap();
+    let jumps_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(jumps_block);
+    compiler.inkwell_context.builder.build_switch(
+        index.as_basic_value().into_int_value(),
+        blocks.continue_block,
+        &cases,
+    );
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(current_block);
+    Some(jumps_block)
+}
+
+/// Compiles the block body, returns true if the block is terminated
+fn generate_body<T, Expr: Compile<T, Output = bool>>(
+    compiler: &mut Compiler<'_, T>,
+    body: Vec<Expr>,
+) -> Result<bool, Error<T>> {
+    for expr in body {
+        if expr.compile(compiler)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
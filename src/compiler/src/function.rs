@@ -1,4 +1,4 @@
-use crate::{Compile, Compiler, Error, Variable};
+use crate::{exceptions::generate_finally_jumps, Compile, Compiler, Error, Variable};
 use inkwell::{
     values::{ArrayValue, FunctionValue},
     AddressSpace,
@@ -10,6 +10,9 @@ pub struct Function<'ctx, T> {
     pub(crate) arg_names: Vec<T>,
     pub(crate) function: FunctionValue<'ctx>,
     pub(crate) args: Option<ArrayValue<'ctx>>,
+    /// variables captured from the outer scopes
+    pub(crate) env_names: Vec<T>,
+    pub(crate) env: Option<ArrayValue<'ctx>>,
     pub(crate) variables: HashMap<T, Variable<'ctx>>,
 }
 
@@ -21,11 +24,17 @@ where
         compiler: &mut Compiler<'ctx, T>,
         name: &str,
         arg_names: Vec<T>,
+        env_names: Vec<T>,
         body: Vec<Expr>,
     ) -> Result<Self, Error<T>> {
         let var_type = compiler.inkwell_context.variable_type;
-        let function_type =
-            var_type.fn_type(&[var_type.ptr_type(AddressSpace::from(0)).into()], false);
+        let function_type = var_type.fn_type(
+            &[
+                var_type.ptr_type(AddressSpace::from(0)).into(),
+                var_type.ptr_type(AddressSpace::from(0)).into(),
+            ],
+            false,
+        );
         let function = compiler
             .inkwell_context
             .module
@@ -41,35 +50,15 @@ where
             .builder
             .position_at_end(basic_block);
 
-        // args
-        let args = function
-            .get_params()
-            .first()
-            .expect("")
-            .into_pointer_value();
-        let args = compiler
-            .inkwell_context
-            .builder
-            .build_bitcast(
-                args,
-                var_type
-                    .array_type(arg_names.len().try_into().unwrap())
-                    .ptr_type(AddressSpace::from(0)),
-                "",
-            )
-            .into_pointer_value();
-        let args = Some(
-            compiler
-                .inkwell_context
-                .builder
-                .build_load(args, "")
-                .into_array_value(),
-        );
+        let args = Some(Self::load_param(compiler, function, 0, arg_names.len()));
+        let env = Some(Self::load_param(compiler, function, 1, env_names.len()));
 
         let func = Self {
             function,
             args,
             arg_names,
+            env,
+            env_names,
             variables: HashMap::new(),
         };
 
@@ -94,6 +83,48 @@ where
         Ok(func)
     }
 
+    /// Loads the array of variables passed by the caller
+    fn load_param(
+        compiler: &Compiler<'ctx, T>,
+        function: FunctionValue<'ctx>,
+        index: u32,
+        len: usize,
+    ) -> ArrayValue<'ctx> {
+        let var_type = compiler.inkwell_context.variable_type;
+        let param = function
+            .get_nth_param(index)
+            .expect("")
+            .into_pointer_value();
+        let param = compiler
+            .inkwell_context
+            .builder
+            .build_bitcast(
+                param,
+                var_type
+                    .array_type(len.try_into().unwrap())
+                    .ptr_type(AddressSpace::from(0)),
+                "",
+            )
+            .into_pointer_value();
+        compiler
+            .inkwell_context
+            .builder
+            .build_load(param, "")
+            .into_array_value()
+    }
+
+    /// Returns the outer scope variables captured by the function,
+    /// should be called in the scope where the function is declared
+    pub fn get_captured_variables(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+    ) -> Result<Vec<Variable<'ctx>>, Error<T>> {
+        self.env_names
+            .iter()
+            .map(|name| compiler.get_variable(name.clone()))
+            .collect()
+    }
+
     pub(crate) fn insert_variable(
         &mut self,
         name: T,
@@ -110,20 +141,22 @@ where
         compiler: &Compiler<'ctx, T>,
         name: T,
     ) -> Result<Variable<'ctx>, Error<T>> {
-        if let Some(args) = self.args {
-            // firstly look into the function arguments
-            for (i, arg_name) in self.arg_names.iter().enumerate() {
-                if name.eq(arg_name) {
-                    let arg = compiler
-                        .inkwell_context
-                        .builder
-                        .build_extract_value(args, i.try_into().unwrap(), "")
-                        .unwrap()
-                        .into_pointer_value();
-                    return Ok(Variable {
-                        value: arg,
-                        is_tmp: false,
-                    });
+        // firstly look into the function arguments, then into the captured variables
+        for (names, values) in [(&self.arg_names, self.args), (&self.env_names, self.env)] {
+            if let Some(values) = values {
+                for (i, var_name) in names.iter().enumerate() {
+                    if name.eq(var_name) {
+                        let value = compiler
+                            .inkwell_context
+                            .builder
+                            .build_extract_value(values, i.try_into().unwrap(), "")
+                            .unwrap()
+                            .into_pointer_value();
+                        return Ok(Variable {
+                            value,
+                            is_tmp: false,
+                        });
+                    }
                 }
             }
         }
@@ -134,6 +167,21 @@ where
             .ok_or(Error::UndefinedVariable(name))
     }
 
+    /// Returns the value from the function through the finally blocks of the current try statements,
+    /// `cleanups` are compiled before the jump into each of them and the last one before the return
+    pub fn generate_return<C>(
+        compiler: &mut Compiler<'ctx, T>,
+        ret: Variable<'ctx>,
+        cleanups: impl IntoIterator<Item = C>,
+    ) -> Result<(), Error<T>>
+    where
+        C: FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+    {
+        let ret = generate_finally_jumps(compiler, |_| true, Some(ret), cleanups)?.unwrap();
+        Self::return_value(compiler, &ret);
+        Ok(())
+    }
+
     pub fn return_value(compiler: &mut Compiler<'ctx, T>, ret: &Variable<'ctx>) {
         compiler
             .inkwell_context
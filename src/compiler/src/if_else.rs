@@ -1,4 +1,5 @@
-use crate::{Compile, Compiler, Error, Variable};
+use crate::{loops::LoopBlocks, Compile, Compiler, Error, Variable};
+use inkwell::basic_block::BasicBlock;
 
 pub fn generate_if_else<'ctx, T, Expr: Compile<T, Output = bool>>(
     compiler: &mut Compiler<'ctx, T>,
@@ -87,3 +88,235 @@ pub fn generate_if_else<'ctx, T, Expr: Compile<T, Output = bool>>(
         .position_at_end(continue_block);
     Ok(false)
 }
+
+/// Generates the conditional expression `condition ? consequent : alternate`,
+/// only the chosen branch is evaluated. Returns the temporary variable with the result
+pub fn generate_conditional<'ctx, T, Consequent, Alternate>(
+    compiler: &mut Compiler<'ctx, T>,
+    condition: &Variable<'ctx>,
+    consequent: Consequent,
+    alternate: Alternate,
+) -> Result<Variable<'ctx>, Error<T>>
+where
+    Consequent: FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+    Alternate: FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+{
+    let get_boolean_fn = compiler.predefined_functions().get_boolean();
+
+    let condition_boolean = get_boolean_fn.call(compiler, condition);
+    if condition.is_tmp() {
+        condition.deallocate(compiler);
+    }
+
+    let condition_boolean = compiler.inkwell_context.builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        condition_boolean,
+        compiler
+            .inkwell_context
+            .context
+            .i8_type()
+            .const_int(1_u64, false),
+        "",
+    );
+
+    // allocated before the branch, so it is available in both of them
+    let ret = Variable::new_undefined(compiler, true);
+
+    let true_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let false_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let continue_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+
+    compiler.inkwell_context.builder.build_conditional_branch(
+        condition_boolean,
+        true_block,
+        false_block,
+    );
+
+    // describe true case
+    compiler.inkwell_context.builder.position_at_end(true_block);
+    let value = consequent(compiler)?;
+    ret.assign_variable(compiler, &value);
+    if value.is_tmp() {
+        value.deallocate(compiler);
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(continue_block);
+
+    // describe false case
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(false_block);
+    let value = alternate(compiler)?;
+    ret.assign_variable(compiler, &value);
+    if value.is_tmp() {
+        value.deallocate(compiler);
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(continue_block);
+
+    //
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(continue_block);
+    Ok(ret)
+}
+
+/// Generates the switch statement, `cases` contains the optional case test and the case body, `None` test is the default case.
+/// Case tests are compared with the `discriminant` by the strict equality, in the declaration order.
+/// The matched case body falls through into the next one, unless it is left by the `break`
+pub fn generate_switch<'ctx, T, Expr: Compile<T, Output = bool>, Test>(
+    compiler: &mut Compiler<'ctx, T>,
+    discriminant: &Variable<'ctx>,
+    cases: Vec<(Option<Test>, Vec<Expr>)>,
+) -> Result<bool, Error<T>>
+where
+    Test: FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+{
+    let body_blocks: Vec<_> = cases
+        .iter()
+        .map(|_| {
+            compiler
+                .inkwell_context
+                .context
+                .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "")
+        })
+        .collect();
+    let continue_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let default_block = cases
+        .iter()
+        .position(|(test, _)| test.is_none())
+        .map_or(continue_block, |index| body_blocks[index]);
+
+    // describe case tests
+    let mut bodies = Vec::with_capacity(cases.len());
+    for ((test, body), body_block) in cases.into_iter().zip(body_blocks.iter()) {
+        bodies.push(body);
+        let Some(test) = test else {
+            continue;
+        };
+
+        let test = test(compiler)?;
+        generate_case_test(compiler, discriminant, &test, *body_block);
+    }
+    // no case is matched
+    if discriminant.is_tmp() {
+        discriminant.deallocate(compiler);
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(default_block);
+
+    // describe case bodies
+    compiler.loops.push(LoopBlocks {
+        break_block: continue_block,
+        continue_block: None,
+    });
+    for (i, body) in bodies.into_iter().enumerate() {
+        compiler
+            .inkwell_context
+            .builder
+            .position_at_end(body_blocks[i]);
+        let mut is_returned = false;
+        for expr in body {
+            let is_return = expr.compile(compiler)?;
+            if is_return {
+                is_returned = true;
+                break;
+            }
+        }
+        if !is_returned {
+            // fall through into the next case
+            let next_block = body_blocks.get(i + 1).copied().unwrap_or(continue_block);
+            compiler
+                .inkwell_context
+                .builder
+                .build_unconditional_branch(next_block);
+        }
+    }
+    compiler.loops.pop();
+
+    //
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(continue_block);
+    Ok(false)
+}
+
+/// Jumps to the case body, if the test is strictly equal to the discriminant
+fn generate_case_test<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    discriminant: &Variable<'ctx>,
+    test: &Variable<'ctx>,
+    body_block: BasicBlock<'ctx>,
+) {
+    let logical_seq_fn = compiler.predefined_functions().logical_seq();
+    let get_boolean_fn = compiler.predefined_functions().get_boolean();
+    let is_equal = logical_seq_fn.call(compiler, discriminant, test);
+    if test.is_tmp() {
+        test.deallocate(compiler);
+    }
+    let is_equal_boolean = get_boolean_fn.call(compiler, &is_equal);
+    is_equal.deallocate(compiler);
+    let is_equal_boolean = compiler.inkwell_context.builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        is_equal_boolean,
+        compiler
+            .inkwell_context
+            .context
+            .i8_type()
+            .const_int(1_u64, false),
+        "",
+    );
+
+    let matched_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let next_test_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    compiler.inkwell_context.builder.build_conditional_branch(
+        is_equal_boolean,
+        matched_block,
+        next_test_block,
+    );
+
+    // discriminant is not needed after the match
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(matched_block);
+    if discriminant.is_tmp() {
+        discriminant.deallocate(compiler);
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(body_block);
+
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(next_test_block);
+}
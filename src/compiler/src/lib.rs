@@ -8,15 +8,19 @@
 
 pub use context::Context;
 pub use function::Function;
-use inkwell::{builder::Builder, module::Module, types::PointerType, AddressSpace};
+use inkwell::{
+    basic_block::BasicBlock, builder::Builder, module::Module, types::PointerType, AddressSpace,
+};
 pub use main_function::MainFunction;
 use predefined_functions::PredefineFunctions;
 use std::{collections::HashMap, hash::Hash, path::Path};
 pub use variable::Variable;
 
 mod context;
+pub mod exceptions;
 mod function;
 pub mod if_else;
+pub mod logical;
 pub mod loops;
 mod main_function;
 pub mod predefined_functions;
@@ -32,8 +36,8 @@ pub enum Error<T> {
     UndefinedFunction(T),
     #[error("Function with this identifier {0} already declared")]
     AlreadyDeclaredFunction(T),
-    #[error("Not enough arguments")]
-    NotEnoughArguments,
+    #[error("Not enough arguments for the function {0}")]
+    NotEnoughArguments(T),
     #[error("Invalid compiled module, {0}")]
     InvalidModule(String),
     #[error("Cannot write module, {0}")]
@@ -62,6 +66,11 @@ pub struct Compiler<'ctx, T> {
     predefined_functions: PredefineFunctions<'ctx>,
 
     cur_function: Option<Function<'ctx, T>>,
+    loops: Vec<loops::LoopBlocks<'ctx>>,
+    /// blocks handling the exceptions of the currently compiled try statements
+    exception_handlers: Vec<BasicBlock<'ctx>>,
+    /// finally blocks of the currently compiled try statements
+    finally_targets: Vec<exceptions::FinallyTarget<'ctx>>,
 }
 
 impl<'ctx, T> Compiler<'ctx, T> {
@@ -80,6 +89,9 @@ impl<'ctx, T> Compiler<'ctx, T> {
             functions: HashMap::new(),
             predefined_functions,
             cur_function: None,
+            loops: Vec::new(),
+            exception_handlers: Vec::new(),
+            finally_targets: Vec::new(),
         }
     }
 
@@ -111,6 +123,9 @@ where
     }
 
     pub fn insert_variable(&mut self, name: T, variable: Variable<'ctx>) -> Result<(), Error<T>> {
+        // declared variables are the garbage collector roots
+        let gc_add_root_fn = self.predefined_functions().gc_add_root();
+        gc_add_root_fn.call(self, &variable);
         self.cur_function
             .as_mut()
             .unwrap()
@@ -124,14 +139,22 @@ where
 
     pub fn generate_llvm_ir(&self, path: &Path) -> Result<(), Error<T>> {
         self.verify()?;
-        // self.inkwell_context.module.write_bitcode_to_path(path);
         self.inkwell_context
             .module
             .print_to_file(path)
-            .map_err(|e| Error::InvalidModule(e.to_string()))?;
+            .map_err(|e| Error::CannotWriteModule(e.to_string()))?;
         Ok(())
     }
 
+    pub fn generate_llvm_bitcode(&self, path: &Path) -> Result<(), Error<T>> {
+        self.verify()?;
+        if self.inkwell_context.module.write_bitcode_to_path(path) {
+            Ok(())
+        } else {
+            Err(Error::CannotWriteModule(path.display().to_string()))
+        }
+    }
+
     pub fn verify(&self) -> Result<(), Error<T>> {
         self.inkwell_context
             .module
@@ -0,0 +1,84 @@
+use crate::{Compiler, Error, Variable};
+
+/// Generates `left && right`, the right operand is evaluated only if the left one is truthy
+pub fn generate_logical_and<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    left: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+    right: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+) -> Result<Variable<'ctx>, Error<T>> {
+    generate_short_circuit(compiler, left, right, true)
+}
+
+/// Generates `left || right`, the right operand is evaluated only if the left one is falsy
+pub fn generate_logical_or<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    left: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+    right: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+) -> Result<Variable<'ctx>, Error<T>> {
+    generate_short_circuit(compiler, left, right, false)
+}
+
+fn generate_short_circuit<'ctx, T>(
+    compiler: &mut Compiler<'ctx, T>,
+    left: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+    right: impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>,
+    evaluate_right_if: bool,
+) -> Result<Variable<'ctx>, Error<T>> {
+    // result holds the left operand value until the right one overrides it
+    let left = left(compiler)?;
+    let ret = Variable::new_undefined(compiler, true);
+    ret.assign_variable(compiler, &left);
+    if left.is_tmp() {
+        left.deallocate(compiler);
+    }
+
+    let get_boolean_fn = compiler.predefined_functions().get_boolean();
+    let condition_boolean = get_boolean_fn.call(compiler, &ret);
+    let condition_boolean = compiler.inkwell_context.builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        condition_boolean,
+        compiler
+            .inkwell_context
+            .context
+            .i8_type()
+            .const_int(u64::from(evaluate_right_if), false),
+        "",
+    );
+
+    let right_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let continue_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+
+    compiler.inkwell_context.builder.build_conditional_branch(
+        condition_boolean,
+        right_block,
+        continue_block,
+    );
+
+    // describe right operand evaluation
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(right_block);
+    let right = right(compiler)?;
+    ret.assign_variable(compiler, &right);
+    if right.is_tmp() {
+        right.deallocate(compiler);
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(continue_block);
+
+    //
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(continue_block);
+    Ok(ret)
+}
@@ -1,4 +1,12 @@
-use crate::{Compile, Compiler, Error, Variable};
+use crate::{exceptions::generate_finally_jumps, Compile, Compiler, Error, Variable};
+use inkwell::basic_block::BasicBlock;
+
+/// Jump targets of the loop, which is currently compiled
+pub(crate) struct LoopBlocks<'ctx> {
+    pub(crate) break_block: BasicBlock<'ctx>,
+    /// switch statement has no continue target
+    pub(crate) continue_block: Option<BasicBlock<'ctx>>,
+}
 
 pub fn generate_while_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
     compiler: &mut Compiler<'ctx, T>,
@@ -56,6 +64,10 @@ pub fn generate_while_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
 
     // describe body
     compiler.inkwell_context.builder.position_at_end(body_block);
+    compiler.loops.push(LoopBlocks {
+        break_block: continue_block,
+        continue_block: Some(condition_block),
+    });
     let mut is_returned = false;
     for expr in body {
         let is_return = expr.compile(compiler)?;
@@ -64,6 +76,7 @@ pub fn generate_while_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
             break;
         }
     }
+    compiler.loops.pop();
     if !is_returned {
         compiler
             .inkwell_context
@@ -136,6 +149,10 @@ pub fn generate_do_while_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
 
     // describe body
     compiler.inkwell_context.builder.position_at_end(body_block);
+    compiler.loops.push(LoopBlocks {
+        break_block: continue_block,
+        continue_block: Some(condition_block),
+    });
     let mut is_returned = false;
     for expr in body {
         let is_return = expr.compile(compiler)?;
@@ -144,6 +161,7 @@ pub fn generate_do_while_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
             break;
         }
     }
+    compiler.loops.pop();
     if !is_returned {
         compiler
             .inkwell_context
@@ -159,3 +177,158 @@ pub fn generate_do_while_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
 
     Ok(())
 }
+
+pub fn generate_for_loop<'ctx, T, Expr: Compile<T, Output = bool>>(
+    compiler: &mut Compiler<'ctx, T>,
+    init: Vec<Expr>,
+    condition: Option<impl FnOnce(&mut Compiler<'ctx, T>) -> Result<Variable<'ctx>, Error<T>>>,
+    update: Vec<Expr>,
+    body: Vec<Expr>,
+) -> Result<(), Error<T>> {
+    let condition_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let body_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let update_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+    let continue_block = compiler
+        .inkwell_context
+        .context
+        .append_basic_block(compiler.cur_function.as_ref().unwrap().function, "");
+
+    // describe init
+    for expr in init {
+        expr.compile(compiler)?;
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(condition_block);
+
+    // describe condition, loop without condition is infinite
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(condition_block);
+    if let Some(condition) = condition {
+        let condition = condition(compiler)?;
+        let get_boolean_fn = compiler.predefined_functions().get_boolean();
+        let convert_to_boolean = compiler.predefined_functions().convert_to_boolean();
+        let boolean_condition = convert_to_boolean.call(compiler, &condition);
+        if condition.is_tmp() {
+            condition.deallocate(compiler);
+        }
+        let int_val = get_boolean_fn.call(compiler, &boolean_condition);
+        if boolean_condition.is_tmp() {
+            boolean_condition.deallocate(compiler);
+        }
+        let int_val = compiler.inkwell_context.builder.build_int_compare(
+            inkwell::IntPredicate::EQ,
+            int_val,
+            compiler
+                .inkwell_context
+                .context
+                .i8_type()
+                .const_int(1_u64, false),
+            "",
+        );
+        compiler.inkwell_context.builder.build_conditional_branch(
+            int_val,
+            body_block,
+            continue_block,
+        );
+    } else {
+        compiler
+            .inkwell_context
+            .builder
+            .build_unconditional_branch(body_block);
+    }
+
+    // describe body
+    compiler.inkwell_context.builder.position_at_end(body_block);
+    compiler.loops.push(LoopBlocks {
+        break_block: continue_block,
+        continue_block: Some(update_block),
+    });
+    let mut is_returned = false;
+    for expr in body {
+        let is_return = expr.compile(compiler)?;
+        if is_return {
+            is_returned = true;
+            break;
+        }
+    }
+    compiler.loops.pop();
+    if !is_returned {
+        compiler
+            .inkwell_context
+            .builder
+            .build_unconditional_branch(update_block);
+    }
+
+    // describe update
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(update_block);
+    for expr in update {
+        expr.compile(compiler)?;
+    }
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(condition_block);
+
+    //
+    compiler
+        .inkwell_context
+        .builder
+        .position_at_end(continue_block);
+
+    Ok(())
+}
+
+/// Generates the jump out of the loop with the provided depth, counted from the innermost one.
+/// The jump passes through the finally blocks of the try statements inside the loop,
+/// `cleanups` are compiled before the jump into each of them and the last one before the jump out of the loop
+pub fn generate_break<'ctx, T, C>(
+    compiler: &mut Compiler<'ctx, T>,
+    depth: usize,
+    cleanups: impl IntoIterator<Item = C>,
+) -> Result<(), Error<T>>
+where
+    C: FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+{
+    let index = compiler.loops.len() - 1 - depth;
+    generate_finally_jumps(compiler, |target| target.loops_len > index, None, cleanups)?;
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(compiler.loops[index].break_block);
+    Ok(())
+}
+
+/// Generates the jump to the next iteration of the loop with the provided depth, counted from the innermost one.
+/// The jump passes through the finally blocks like the `generate_break`
+pub fn generate_continue<'ctx, T, C>(
+    compiler: &mut Compiler<'ctx, T>,
+    depth: usize,
+    cleanups: impl IntoIterator<Item = C>,
+) -> Result<(), Error<T>>
+where
+    C: FnOnce(&mut Compiler<'ctx, T>) -> Result<(), Error<T>>,
+{
+    let index = compiler.loops.len() - 1 - depth;
+    generate_finally_jumps(compiler, |target| target.loops_len > index, None, cleanups)?;
+    compiler
+        .inkwell_context
+        .builder
+        .build_unconditional_branch(compiler.loops[index].continue_block.unwrap());
+    Ok(())
+}
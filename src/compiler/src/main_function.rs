@@ -15,6 +15,8 @@ impl<'ctx> MainFunction {
             function,
             args: None,
             arg_names: Vec::new(),
+            env: None,
+            env_names: Vec::new(),
             variables: HashMap::new(),
         });
 
@@ -29,6 +31,9 @@ impl<'ctx> MainFunction {
         for expr in body {
             expr.compile(compiler)?;
         }
+        // all variables are deallocated, frees the remaining cyclic values
+        let gc_collect_fn = compiler.predefined_functions().gc_collect();
+        gc_collect_fn.call(compiler);
         compiler.inkwell_context.builder.build_return(Some(
             &compiler
                 .inkwell_context
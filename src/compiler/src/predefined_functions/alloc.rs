@@ -65,3 +65,89 @@ impl<'ctx> DeallocateFn<'ctx> {
             .build_call(self.func, &[val.value.into()], "");
     }
 }
+
+#[derive(Clone)]
+pub struct GcAddRootFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> GcAddRootFn<'ctx> {
+    const NAME: &'static str = "gc_add_root";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = inkwell_context
+            .context
+            .void_type()
+            .fn_type(&[var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>, val: &Variable<'ctx>) {
+        compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val.value.into()], "");
+    }
+}
+
+#[derive(Clone)]
+pub struct GcRemoveRootFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> GcRemoveRootFn<'ctx> {
+    const NAME: &'static str = "gc_remove_root";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = inkwell_context
+            .context
+            .void_type()
+            .fn_type(&[var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>, val: &Variable<'ctx>) {
+        compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val.value.into()], "");
+    }
+}
+
+#[derive(Clone)]
+pub struct GcCollectFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> GcCollectFn<'ctx> {
+    const NAME: &'static str = "gc_collect";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let function_type = inkwell_context.context.void_type().fn_type(&[], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>) {
+        compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[], "");
+    }
+}
@@ -157,3 +157,383 @@ impl<'ctx> ArithmeticDivisionFn<'ctx> {
         }
     }
 }
+
+#[derive(Clone)]
+pub struct ArithmeticRemainderFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticRemainderFn<'ctx> {
+    const NAME: &'static str = "arithmetic_remainder";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticExponentiationFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticExponentiationFn<'ctx> {
+    const NAME: &'static str = "arithmetic_exponentiation";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticNegationFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticNegationFn<'ctx> {
+    const NAME: &'static str = "arithmetic_negation";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>, val: &Variable<'ctx>) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticBitwiseAndFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticBitwiseAndFn<'ctx> {
+    const NAME: &'static str = "arithmetic_bitwise_and";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticBitwiseOrFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticBitwiseOrFn<'ctx> {
+    const NAME: &'static str = "arithmetic_bitwise_or";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticBitwiseXorFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticBitwiseXorFn<'ctx> {
+    const NAME: &'static str = "arithmetic_bitwise_xor";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticBitwiseNotFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticBitwiseNotFn<'ctx> {
+    const NAME: &'static str = "arithmetic_bitwise_not";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>, val: &Variable<'ctx>) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticLeftShiftFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticLeftShiftFn<'ctx> {
+    const NAME: &'static str = "arithmetic_left_shift";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticRightShiftFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticRightShiftFn<'ctx> {
+    const NAME: &'static str = "arithmetic_right_shift";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArithmeticUnsignedRightShiftFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ArithmeticUnsignedRightShiftFn<'ctx> {
+    const NAME: &'static str = "arithmetic_unsigned_right_shift";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into(), var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        val1: &Variable<'ctx>,
+        val2: &Variable<'ctx>,
+    ) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val1.value.into(), val2.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::Compiler;
+use crate::{InkwellContext, Variable};
+use inkwell::{
+    module::Linkage,
+    values::{FunctionValue, IntValue},
+};
+
+#[derive(Clone)]
+pub struct ThrowExceptionFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> ThrowExceptionFn<'ctx> {
+    const NAME: &'static str = "throw_exception";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = inkwell_context
+            .context
+            .void_type()
+            .fn_type(&[var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>, val: &Variable<'ctx>) {
+        compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val.value.into()], "");
+    }
+}
+
+#[derive(Clone)]
+pub struct HasExceptionFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> HasExceptionFn<'ctx> {
+    const NAME: &'static str = "has_exception";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let function_type = inkwell_context.context.i8_type().fn_type(&[], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub(crate) fn call<T>(&self, compiler: &Compiler<'ctx, T>) -> IntValue<'ctx> {
+        compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
+    }
+}
+
+#[derive(Clone)]
+pub struct TakeExceptionFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> TakeExceptionFn<'ctx> {
+    const NAME: &'static str = "take_exception";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UncaughtExceptionFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> UncaughtExceptionFn<'ctx> {
+    const NAME: &'static str = "uncaught_exception";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let function_type = inkwell_context.context.void_type().fn_type(&[], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>) {
+        compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[], "");
+    }
+}
@@ -37,12 +37,12 @@ impl<'ctx> LogicalNotFn<'ctx> {
 }
 
 #[derive(Clone)]
-pub struct LogicalAndFn<'ctx> {
+pub struct LogicalEqFn<'ctx> {
     func: FunctionValue<'ctx>,
 }
 
-impl<'ctx> LogicalAndFn<'ctx> {
-    const NAME: &'static str = "logical_and";
+impl<'ctx> LogicalEqFn<'ctx> {
+    const NAME: &'static str = "logical_eq";
 
     pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
         let var_type = inkwell_context.variable_type;
@@ -76,12 +76,12 @@ impl<'ctx> LogicalAndFn<'ctx> {
 }
 
 #[derive(Clone)]
-pub struct LogicalOrFn<'ctx> {
+pub struct LogicalNeFn<'ctx> {
     func: FunctionValue<'ctx>,
 }
 
-impl<'ctx> LogicalOrFn<'ctx> {
-    const NAME: &'static str = "logical_or";
+impl<'ctx> LogicalNeFn<'ctx> {
+    const NAME: &'static str = "logical_ne";
 
     pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
         let var_type = inkwell_context.variable_type;
@@ -115,12 +115,12 @@ impl<'ctx> LogicalOrFn<'ctx> {
 }
 
 #[derive(Clone)]
-pub struct LogicalEqFn<'ctx> {
+pub struct LogicalSeqFn<'ctx> {
     func: FunctionValue<'ctx>,
 }
 
-impl<'ctx> LogicalEqFn<'ctx> {
-    const NAME: &'static str = "logical_eq";
+impl<'ctx> LogicalSeqFn<'ctx> {
+    const NAME: &'static str = "logical_seq";
 
     pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
         let var_type = inkwell_context.variable_type;
@@ -154,12 +154,12 @@ impl<'ctx> LogicalEqFn<'ctx> {
 }
 
 #[derive(Clone)]
-pub struct LogicalNeFn<'ctx> {
+pub struct LogicalSneFn<'ctx> {
     func: FunctionValue<'ctx>,
 }
 
-impl<'ctx> LogicalNeFn<'ctx> {
-    const NAME: &'static str = "logical_ne";
+impl<'ctx> LogicalSneFn<'ctx> {
+    const NAME: &'static str = "logical_sne";
 
     pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
         let var_type = inkwell_context.variable_type;
@@ -1,13 +1,17 @@
 use self::{
-    alloc::{AllocateFn, DeallocateFn},
+    alloc::{AllocateFn, DeallocateFn, GcAddRootFn, GcCollectFn, GcRemoveRootFn},
     arithmetic::{
-        ArithmeticAdditionFn, ArithmeticDivisionFn, ArithmeticMultiplicationFn,
-        ArithmeticSubstractionFn,
+        ArithmeticAdditionFn, ArithmeticBitwiseAndFn, ArithmeticBitwiseNotFn,
+        ArithmeticBitwiseOrFn, ArithmeticBitwiseXorFn, ArithmeticDivisionFn,
+        ArithmeticExponentiationFn, ArithmeticLeftShiftFn, ArithmeticMultiplicationFn,
+        ArithmeticNegationFn, ArithmeticRemainderFn, ArithmeticRightShiftFn,
+        ArithmeticSubstractionFn, ArithmeticUnsignedRightShiftFn,
     },
     convert::{ConvertToBooleanFn, ConvertToNumberFn, ConvertToStringFn},
+    exception::{HasExceptionFn, TakeExceptionFn, ThrowExceptionFn, UncaughtExceptionFn},
     logical::{
-        LogicalAndFn, LogicalEqFn, LogicalGeFn, LogicalGtFn, LogicalLeFn, LogicalLtFn, LogicalNeFn,
-        LogicalNotFn, LogicalOrFn,
+        LogicalEqFn, LogicalGeFn, LogicalGtFn, LogicalLeFn, LogicalLtFn, LogicalNeFn, LogicalNotFn,
+        LogicalSeqFn, LogicalSneFn,
     },
     object::{
         AddPropertyByBooleanFn, AddPropertyByNumberFn, AddPropertyByStrFn, AddPropertyByVarFn,
@@ -16,16 +20,18 @@ use self::{
     },
     test_utils::{AssertEqFn, AssertFn, PrintFn},
     variable::{
-        GetBooleanFn, SetBooleanFn, SetEmptyArrayFn, SetEmptyObjectFn, SetFunctionFn,
+        GetBooleanFn, GetTypeOfFn, SetBooleanFn, SetEmptyArrayFn, SetEmptyObjectFn, SetFunctionFn,
         SetInfinityFn, SetNaNFn, SetNegInfinityFn, SetNullFn, SetNumberFn, SetStringFn,
         SetUndefinedFn, SetVariableFn,
     },
 };
-use crate::{Compiler, InkwellContext};
+use crate::{Compiler, InkwellContext, Variable};
+use inkwell::{values::BasicValueEnum, AddressSpace};
 
 pub mod alloc;
 pub mod arithmetic;
 pub mod convert;
+pub mod exception;
 pub mod logical;
 pub mod object;
 pub mod test_utils;
@@ -48,6 +54,7 @@ pub struct PredefineFunctions<'ctx> {
     set_function: SetFunctionFn<'ctx>,
     set_variable: SetVariableFn<'ctx>,
     get_boolean: GetBooleanFn<'ctx>,
+    get_type_of: GetTypeOfFn<'ctx>,
     function_call: FunctionCallFn<'ctx>,
     // object functions
     add_property_by_boolean: AddPropertyByBooleanFn<'ctx>,
@@ -59,12 +66,21 @@ pub struct PredefineFunctions<'ctx> {
     get_property_by_str: GetPropertyByStrFn<'ctx>,
     get_property_by_var: GetPropertyByVarFn<'ctx>,
     remove_property: RemovePropertyFn<'ctx>,
+    // gc functions
+    gc_add_root: GcAddRootFn<'ctx>,
+    gc_remove_root: GcRemoveRootFn<'ctx>,
+    gc_collect: GcCollectFn<'ctx>,
+    // exception functions
+    throw_exception: ThrowExceptionFn<'ctx>,
+    has_exception: HasExceptionFn<'ctx>,
+    take_exception: TakeExceptionFn<'ctx>,
+    uncaught_exception: UncaughtExceptionFn<'ctx>,
     // logical functions
     logical_not: LogicalNotFn<'ctx>,
-    logical_and: LogicalAndFn<'ctx>,
-    logical_or: LogicalOrFn<'ctx>,
     logical_eq: LogicalEqFn<'ctx>,
     logical_ne: LogicalNeFn<'ctx>,
+    logical_seq: LogicalSeqFn<'ctx>,
+    logical_sne: LogicalSneFn<'ctx>,
     logical_gt: LogicalGtFn<'ctx>,
     logical_ge: LogicalGeFn<'ctx>,
     logical_lt: LogicalLtFn<'ctx>,
@@ -74,6 +90,16 @@ pub struct PredefineFunctions<'ctx> {
     arithmetic_substraction: ArithmeticSubstractionFn<'ctx>,
     arithmetic_multiplication: ArithmeticMultiplicationFn<'ctx>,
     arithmetic_division: ArithmeticDivisionFn<'ctx>,
+    arithmetic_remainder: ArithmeticRemainderFn<'ctx>,
+    arithmetic_exponentiation: ArithmeticExponentiationFn<'ctx>,
+    arithmetic_negation: ArithmeticNegationFn<'ctx>,
+    arithmetic_bitwise_and: ArithmeticBitwiseAndFn<'ctx>,
+    arithmetic_bitwise_or: ArithmeticBitwiseOrFn<'ctx>,
+    arithmetic_bitwise_xor: ArithmeticBitwiseXorFn<'ctx>,
+    arithmetic_bitwise_not: ArithmeticBitwiseNotFn<'ctx>,
+    arithmetic_left_shift: ArithmeticLeftShiftFn<'ctx>,
+    arithmetic_right_shift: ArithmeticRightShiftFn<'ctx>,
+    arithmetic_unsigned_right_shift: ArithmeticUnsignedRightShiftFn<'ctx>,
     // convert
     convert_to_boolean: ConvertToBooleanFn<'ctx>,
     convert_to_number: ConvertToNumberFn<'ctx>,
@@ -103,6 +129,7 @@ impl<'ctx> PredefineFunctions<'ctx> {
             set_function: SetFunctionFn::declare(inkwell_context),
             set_variable: SetVariableFn::declare(inkwell_context),
             get_boolean: GetBooleanFn::declare(inkwell_context),
+            get_type_of: GetTypeOfFn::declare(inkwell_context),
             function_call: FunctionCallFn::declare(inkwell_context),
             // object functions
             add_property_by_boolean: AddPropertyByBooleanFn::declare(inkwell_context),
@@ -114,12 +141,21 @@ impl<'ctx> PredefineFunctions<'ctx> {
             get_property_by_str: GetPropertyByStrFn::declare(inkwell_context),
             get_property_by_var: GetPropertyByVarFn::declare(inkwell_context),
             remove_property: RemovePropertyFn::declare(inkwell_context),
+            // gc functions
+            gc_add_root: GcAddRootFn::declare(inkwell_context),
+            gc_remove_root: GcRemoveRootFn::declare(inkwell_context),
+            gc_collect: GcCollectFn::declare(inkwell_context),
+            // exception functions
+            throw_exception: ThrowExceptionFn::declare(inkwell_context),
+            has_exception: HasExceptionFn::declare(inkwell_context),
+            take_exception: TakeExceptionFn::declare(inkwell_context),
+            uncaught_exception: UncaughtExceptionFn::declare(inkwell_context),
             // logical functions
             logical_not: LogicalNotFn::declare(inkwell_context),
-            logical_and: LogicalAndFn::declare(inkwell_context),
-            logical_or: LogicalOrFn::declare(inkwell_context),
             logical_eq: LogicalEqFn::declare(inkwell_context),
             logical_ne: LogicalNeFn::declare(inkwell_context),
+            logical_seq: LogicalSeqFn::declare(inkwell_context),
+            logical_sne: LogicalSneFn::declare(inkwell_context),
             logical_gt: LogicalGtFn::declare(inkwell_context),
             logical_ge: LogicalGeFn::declare(inkwell_context),
             logical_lt: LogicalLtFn::declare(inkwell_context),
@@ -129,6 +165,18 @@ impl<'ctx> PredefineFunctions<'ctx> {
             arithmetic_substraction: ArithmeticSubstractionFn::declare(inkwell_context),
             arithmetic_multiplication: ArithmeticMultiplicationFn::declare(inkwell_context),
             arithmetic_division: ArithmeticDivisionFn::declare(inkwell_context),
+            arithmetic_remainder: ArithmeticRemainderFn::declare(inkwell_context),
+            arithmetic_exponentiation: ArithmeticExponentiationFn::declare(inkwell_context),
+            arithmetic_negation: ArithmeticNegationFn::declare(inkwell_context),
+            arithmetic_bitwise_and: ArithmeticBitwiseAndFn::declare(inkwell_context),
+            arithmetic_bitwise_or: ArithmeticBitwiseOrFn::declare(inkwell_context),
+            arithmetic_bitwise_xor: ArithmeticBitwiseXorFn::declare(inkwell_context),
+            arithmetic_bitwise_not: ArithmeticBitwiseNotFn::declare(inkwell_context),
+            arithmetic_left_shift: ArithmeticLeftShiftFn::declare(inkwell_context),
+            arithmetic_right_shift: ArithmeticRightShiftFn::declare(inkwell_context),
+            arithmetic_unsigned_right_shift: ArithmeticUnsignedRightShiftFn::declare(
+                inkwell_context,
+            ),
             // convert
             convert_to_boolean: ConvertToBooleanFn::declare(inkwell_context),
             convert_to_number: ConvertToNumberFn::declare(inkwell_context),
@@ -201,6 +249,10 @@ impl<'ctx> PredefineFunctions<'ctx> {
         &self.get_boolean
     }
 
+    pub fn get_type_of(&self) -> &GetTypeOfFn<'ctx> {
+        &self.get_type_of
+    }
+
     pub fn function_call(&self) -> &FunctionCallFn<'ctx> {
         &self.function_call
     }
@@ -242,17 +294,39 @@ impl<'ctx> PredefineFunctions<'ctx> {
         &self.remove_property
     }
 
-    // logical functions
-    pub fn logical_not(&self) -> &LogicalNotFn<'ctx> {
-        &self.logical_not
+    // gc functions
+    pub fn gc_add_root(&self) -> &GcAddRootFn<'ctx> {
+        &self.gc_add_root
+    }
+
+    pub fn gc_remove_root(&self) -> &GcRemoveRootFn<'ctx> {
+        &self.gc_remove_root
+    }
+
+    pub fn gc_collect(&self) -> &GcCollectFn<'ctx> {
+        &self.gc_collect
+    }
+
+    // exception functions
+    pub fn throw_exception(&self) -> &ThrowExceptionFn<'ctx> {
+        &self.throw_exception
+    }
+
+    pub fn has_exception(&self) -> &HasExceptionFn<'ctx> {
+        &self.has_exception
+    }
+
+    pub fn take_exception(&self) -> &TakeExceptionFn<'ctx> {
+        &self.take_exception
     }
 
-    pub fn logical_and(&self) -> &LogicalAndFn<'ctx> {
-        &self.logical_and
+    pub fn uncaught_exception(&self) -> &UncaughtExceptionFn<'ctx> {
+        &self.uncaught_exception
     }
 
-    pub fn logical_or(&self) -> &LogicalOrFn<'ctx> {
-        &self.logical_or
+    // logical functions
+    pub fn logical_not(&self) -> &LogicalNotFn<'ctx> {
+        &self.logical_not
     }
 
     pub fn logical_eq(&self) -> &LogicalEqFn<'ctx> {
@@ -263,6 +337,14 @@ impl<'ctx> PredefineFunctions<'ctx> {
         &self.logical_ne
     }
 
+    pub fn logical_seq(&self) -> &LogicalSeqFn<'ctx> {
+        &self.logical_seq
+    }
+
+    pub fn logical_sne(&self) -> &LogicalSneFn<'ctx> {
+        &self.logical_sne
+    }
+
     pub fn logical_gt(&self) -> &LogicalGtFn<'ctx> {
         &self.logical_gt
     }
@@ -296,6 +378,46 @@ impl<'ctx> PredefineFunctions<'ctx> {
         &self.arithmetic_division
     }
 
+    pub fn arithmetic_remainder(&self) -> &ArithmeticRemainderFn<'ctx> {
+        &self.arithmetic_remainder
+    }
+
+    pub fn arithmetic_exponentiation(&self) -> &ArithmeticExponentiationFn<'ctx> {
+        &self.arithmetic_exponentiation
+    }
+
+    pub fn arithmetic_negation(&self) -> &ArithmeticNegationFn<'ctx> {
+        &self.arithmetic_negation
+    }
+
+    pub fn arithmetic_bitwise_and(&self) -> &ArithmeticBitwiseAndFn<'ctx> {
+        &self.arithmetic_bitwise_and
+    }
+
+    pub fn arithmetic_bitwise_or(&self) -> &ArithmeticBitwiseOrFn<'ctx> {
+        &self.arithmetic_bitwise_or
+    }
+
+    pub fn arithmetic_bitwise_xor(&self) -> &ArithmeticBitwiseXorFn<'ctx> {
+        &self.arithmetic_bitwise_xor
+    }
+
+    pub fn arithmetic_bitwise_not(&self) -> &ArithmeticBitwiseNotFn<'ctx> {
+        &self.arithmetic_bitwise_not
+    }
+
+    pub fn arithmetic_left_shift(&self) -> &ArithmeticLeftShiftFn<'ctx> {
+        &self.arithmetic_left_shift
+    }
+
+    pub fn arithmetic_right_shift(&self) -> &ArithmeticRightShiftFn<'ctx> {
+        &self.arithmetic_right_shift
+    }
+
+    pub fn arithmetic_unsigned_right_shift(&self) -> &ArithmeticUnsignedRightShiftFn<'ctx> {
+        &self.arithmetic_unsigned_right_shift
+    }
+
     // convert
     pub fn convert_to_boolean(&self) -> &ConvertToBooleanFn<'ctx> {
         &self.convert_to_boolean
@@ -322,3 +444,52 @@ impl<'ctx> PredefineFunctions<'ctx> {
         &self.printf
     }
 }
+
+/// Stores the variables into the stack allocated array, returns the pointer to the array
+fn build_variables_array<'ctx, T>(
+    compiler: &Compiler<'ctx, T>,
+    variables: &[Variable<'ctx>],
+) -> BasicValueEnum<'ctx> {
+    let var_type = compiler.inkwell_context.variable_type;
+
+    let array = compiler
+        .inkwell_context
+        .builder
+        .build_alloca(var_type.array_type(variables.len().try_into().unwrap()), "");
+
+    for (i, variable) in variables.iter().enumerate() {
+        unsafe {
+            let ptr = compiler.inkwell_context.builder.build_gep(
+                array,
+                &[
+                    compiler
+                        .inkwell_context
+                        .context
+                        .i32_type()
+                        .const_int(0, false),
+                    compiler
+                        .inkwell_context
+                        .context
+                        .i32_type()
+                        .const_int(i.try_into().unwrap(), false),
+                ],
+                "",
+            );
+            let ptr = compiler
+                .inkwell_context
+                .builder
+                .build_bitcast(ptr, var_type.ptr_type(AddressSpace::from(0)), "")
+                .into_pointer_value();
+            compiler
+                .inkwell_context
+                .builder
+                .build_store(ptr, variable.value);
+        }
+    }
+
+    compiler.inkwell_context.builder.build_bitcast(
+        array,
+        var_type.ptr_type(AddressSpace::from(0)),
+        "",
+    )
+}
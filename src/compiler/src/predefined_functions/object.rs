@@ -1,4 +1,4 @@
-use super::Compiler;
+use super::{build_variables_array, Compiler};
 use crate::{InkwellContext, Variable};
 use inkwell::{module::Linkage, values::FunctionValue, AddressSpace};
 
@@ -223,7 +223,7 @@ impl<'ctx> GetPropertyByBooleanFn<'ctx> {
             .into_pointer_value();
         Variable {
             value,
-            is_tmp: false,
+            is_tmp: true,
         }
     }
 }
@@ -275,7 +275,7 @@ impl<'ctx> GetPropertyByNumberFn<'ctx> {
             .into_pointer_value();
         Variable {
             value,
-            is_tmp: false,
+            is_tmp: true,
         }
     }
 }
@@ -323,7 +323,7 @@ impl<'ctx> GetPropertyByStrFn<'ctx> {
             .into_pointer_value();
         Variable {
             value,
-            is_tmp: false,
+            is_tmp: true,
         }
     }
 }
@@ -362,7 +362,7 @@ impl<'ctx> GetPropertyByVarFn<'ctx> {
             .into_pointer_value();
         Variable {
             value,
-            is_tmp: false,
+            is_tmp: true,
         }
     }
 }
@@ -437,50 +437,12 @@ impl<'ctx> FunctionCallFn<'ctx> {
         val: &Variable<'ctx>,
         args: &[Variable<'ctx>],
     ) -> Variable<'ctx> {
-        let var_type = compiler.inkwell_context.variable_type;
-
-        let array = compiler
-            .inkwell_context
-            .builder
-            .build_alloca(var_type.array_type(args.len().try_into().unwrap()), "");
         let args_len = compiler
             .inkwell_context
             .context
             .i32_type()
             .const_int(args.len().try_into().unwrap(), false);
-
-        for (i, arg) in args.iter().enumerate() {
-            unsafe {
-                let ptr = compiler.inkwell_context.builder.build_gep(
-                    array,
-                    &[
-                        compiler
-                            .inkwell_context
-                            .context
-                            .i32_type()
-                            .const_int(0, false),
-                        compiler
-                            .inkwell_context
-                            .context
-                            .i32_type()
-                            .const_int(i.try_into().unwrap(), false),
-                    ],
-                    "",
-                );
-                let ptr = compiler
-                    .inkwell_context
-                    .builder
-                    .build_bitcast(ptr, var_type.ptr_type(AddressSpace::from(0)), "")
-                    .into_pointer_value();
-                compiler.inkwell_context.builder.build_store(ptr, arg.value);
-            }
-        }
-
-        let args = compiler.inkwell_context.builder.build_bitcast(
-            array,
-            var_type.ptr_type(AddressSpace::from(0)),
-            "",
-        );
+        let args = build_variables_array(compiler, args);
 
         let value = compiler
             .inkwell_context
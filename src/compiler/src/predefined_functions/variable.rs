@@ -1,4 +1,4 @@
-use super::Compiler;
+use super::{build_variables_array, Compiler};
 use crate::{Function, InkwellContext, Variable};
 use inkwell::{
     module::Linkage,
@@ -337,13 +337,25 @@ impl<'ctx> SetFunctionFn<'ctx> {
     pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
         let var_type = inkwell_context.variable_type;
         let func_type = var_type
-            .fn_type(&[var_type.ptr_type(AddressSpace::from(0)).into()], false)
+            .fn_type(
+                &[
+                    var_type.ptr_type(AddressSpace::from(0)).into(),
+                    var_type.ptr_type(AddressSpace::from(0)).into(),
+                ],
+                false,
+            )
             .ptr_type(AddressSpace::from(0));
         let u32_type = inkwell_context.context.i32_type();
-        let function_type = inkwell_context
-            .context
-            .void_type()
-            .fn_type(&[var_type.into(), func_type.into(), u32_type.into()], false);
+        let function_type = inkwell_context.context.void_type().fn_type(
+            &[
+                var_type.into(),
+                func_type.into(),
+                u32_type.into(),
+                var_type.ptr_type(AddressSpace::from(0)).into(),
+                u32_type.into(),
+            ],
+            false,
+        );
         let func =
             inkwell_context
                 .module
@@ -356,18 +368,27 @@ impl<'ctx> SetFunctionFn<'ctx> {
         compiler: &Compiler<'ctx, T>,
         val: &Variable<'ctx>,
         func: &Function<'ctx, T>,
+        env: &[Variable<'ctx>],
     ) {
         let args_num = compiler
             .inkwell_context
             .context
             .i32_type()
             .const_int(func.arg_names.len().try_into().unwrap(), false);
+        let env_num = compiler
+            .inkwell_context
+            .context
+            .i32_type()
+            .const_int(env.len().try_into().unwrap(), false);
+        let env = build_variables_array(compiler, env);
         compiler.inkwell_context.builder.build_call(
             self.func,
             &[
                 val.value.into(),
                 func.function.as_global_value().as_pointer_value().into(),
                 args_num.into(),
+                env.into(),
+                env_num.into(),
             ],
             "",
         );
@@ -445,3 +466,37 @@ impl<'ctx> GetBooleanFn<'ctx> {
             .into_int_value()
     }
 }
+
+#[derive(Clone)]
+pub struct GetTypeOfFn<'ctx> {
+    func: FunctionValue<'ctx>,
+}
+
+impl<'ctx> GetTypeOfFn<'ctx> {
+    const NAME: &'static str = "get_type_of";
+
+    pub(super) fn declare(inkwell_context: &InkwellContext<'ctx>) -> Self {
+        let var_type = inkwell_context.variable_type;
+        let function_type = var_type.fn_type(&[var_type.into()], false);
+        let func =
+            inkwell_context
+                .module
+                .add_function(Self::NAME, function_type, Some(Linkage::External));
+        Self { func }
+    }
+
+    pub fn call<T>(&self, compiler: &Compiler<'ctx, T>, val: &Variable<'ctx>) -> Variable<'ctx> {
+        let value = compiler
+            .inkwell_context
+            .builder
+            .build_call(self.func, &[val.value.into()], "")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+        Variable {
+            value,
+            is_tmp: true,
+        }
+    }
+}
@@ -123,11 +123,11 @@ impl<'ctx> Variable<'ctx> {
     pub fn new_function<T>(
         compiler: &Compiler<'ctx, T>,
         function: &Function<'ctx, T>,
+        env: &[Self],
         is_tmp: bool,
     ) -> Self {
         let mut variable = Self::new(compiler);
-        let set_function_fn = compiler.predefined_functions().set_function();
-        set_function_fn.call(compiler, &variable, function);
+        variable.assign_function(compiler, function, env);
         variable.is_tmp = is_tmp;
         variable
     }
@@ -142,6 +142,16 @@ impl<'ctx> Variable<'ctx> {
         let set_variable_fn = compiler.predefined_functions().set_variable();
         set_variable_fn.call(compiler, self, variable);
     }
+
+    pub fn assign_function<T>(
+        &self,
+        compiler: &Compiler<'ctx, T>,
+        function: &Function<'ctx, T>,
+        env: &[Self],
+    ) {
+        let set_function_fn = compiler.predefined_functions().set_function();
+        set_function_fn.call(compiler, self, function, env);
+    }
 }
 
 impl<'ctx> Variable<'ctx> {
@@ -1,27 +1,51 @@
-use crate::{number::Number, ptr::RawPtr, variable::VariableValue};
+use crate::{
+    number::Number,
+    ptr::{RawPtr, Tracked},
+    variable::VariableValue,
+};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Array {
+#[derive(Debug, PartialEq)]
+struct Storage {
     values: Vec<RawPtr<VariableValue>>,
     properties: HashMap<String, RawPtr<VariableValue>>,
 }
 
+impl Tracked for Storage {}
+
+/// Reference to the array, all copies share the same values and properties
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+    storage: RawPtr<Storage>,
+}
+
 impl Array {
     const LENGTH_PROPERTY: &'static str = "length";
 
     pub fn new() -> Self {
         Self {
-            values: Vec::new(),
-            properties: HashMap::new(),
+            storage: RawPtr::allocate(Storage {
+                values: Vec::new(),
+                properties: HashMap::new(),
+            }),
         }
     }
 
+    /// Returns the address of the storage, which identifies the array
+    pub fn id(&self) -> *const () {
+        self.storage.get_raw().cast_const().cast()
+    }
+
+    /// Returns true if both values reference the same array
+    pub fn is_same(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         let mut res = String::new();
         res.push('[');
-        let mut values_iter = self.values.iter();
+        let mut values_iter = self.storage.values.iter();
         if let Some(value) = values_iter.next() {
             res.push_str(value.to_string().as_str());
             for property in values_iter {
@@ -32,15 +56,26 @@ impl Array {
         res
     }
 
+    /// Returns all values referenced by the array, the elements and the properties
+    pub fn references(&self) -> impl Iterator<Item = &RawPtr<VariableValue>> {
+        self.storage
+            .values
+            .iter()
+            .chain(self.storage.properties.values())
+    }
+
     fn add_value(&mut self, index: usize, value: RawPtr<VariableValue>) {
-        while index >= self.values.len() {
-            self.values.push(RawPtr::allocate(VariableValue::Undefined));
+        while index >= self.storage.values.len() {
+            self.storage
+                .values
+                .push(RawPtr::allocate(VariableValue::Undefined));
         }
-        self.values[index] = value;
+        self.storage.values[index] = value;
     }
 
     fn get_value(&mut self, index: usize) -> RawPtr<VariableValue> {
-        self.values
+        self.storage
+            .values
             .get(index)
             .map_or(RawPtr::allocate(VariableValue::Undefined), |val| val.copy())
     }
@@ -55,7 +90,7 @@ impl Array {
                 if property_name == Array::LENGTH_PROPERTY {
                     // TODO print runtime error
                 } else {
-                    self.properties.insert(property_name, property);
+                    self.storage.properties.insert(property_name, property);
                 }
             }
         }
@@ -67,9 +102,12 @@ impl Array {
             property_name => {
                 let property_name = property_name.to_string();
                 if property_name == Array::LENGTH_PROPERTY {
-                    RawPtr::allocate(VariableValue::Number(Number::Num(self.values.len() as f64)))
+                    RawPtr::allocate(VariableValue::Number(Number::Num(
+                        self.storage.values.len() as f64,
+                    )))
                 } else {
-                    self.properties
+                    self.storage
+                        .properties
                         .get(&property_name)
                         .map_or(RawPtr::allocate(VariableValue::Undefined), |val| val.copy())
                 }
@@ -90,15 +128,17 @@ mod tests {
         assert_eq!(array.to_string(), "[]");
 
         array
+            .storage
             .values
             .push(RawPtr::allocate(VariableValue::Undefined));
-        array.properties.insert(
+        array.storage.properties.insert(
             "key".to_string(),
             RawPtr::allocate(VariableValue::Undefined),
         );
         assert_eq!(array.to_string(), "[undefined]");
 
         array
+            .storage
             .values
             .push(RawPtr::allocate(VariableValue::Undefined));
         assert_eq!(array.to_string(), "[undefined, undefined]");
@@ -108,40 +148,40 @@ mod tests {
     fn add_value_test() {
         let mut array = Array::new();
 
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
 
         array.add_value(0, RawPtr::allocate(VariableValue::Undefined));
-        assert_eq!(array.values.len(), 1);
-        assert_eq!(array.values[0].deref(), &VariableValue::Undefined);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 1);
+        assert_eq!(array.storage.values[0].deref(), &VariableValue::Undefined);
+        assert_eq!(array.storage.properties.len(), 0);
 
         array.add_value(1, RawPtr::allocate(VariableValue::Undefined));
-        assert_eq!(array.values.len(), 2);
-        assert_eq!(array.values[1].deref(), &VariableValue::Undefined);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 2);
+        assert_eq!(array.storage.values[1].deref(), &VariableValue::Undefined);
+        assert_eq!(array.storage.properties.len(), 0);
 
         array.add_value(0, RawPtr::allocate(VariableValue::Null));
-        assert_eq!(array.values.len(), 2);
-        assert_eq!(array.values[0].deref(), &VariableValue::Null);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 2);
+        assert_eq!(array.storage.values[0].deref(), &VariableValue::Null);
+        assert_eq!(array.storage.properties.len(), 0);
     }
 
     #[test]
     fn get_value_test() {
         let mut array = Array::new();
 
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
 
         let val = array.get_value(0);
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
         assert_eq!(val.deref(), &VariableValue::Undefined);
 
         let val = array.get_value(3);
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
         assert_eq!(val.deref(), &VariableValue::Undefined);
     }
 
@@ -149,17 +189,18 @@ mod tests {
     fn add_property_test() {
         let mut array = Array::new();
 
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
 
         array.add_property(
             &VariableValue::Null,
             RawPtr::allocate(VariableValue::Undefined),
         );
-        assert_eq!(array.properties.len(), 1);
-        assert_eq!(array.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 1);
+        assert_eq!(array.storage.values.len(), 0);
         assert_eq!(
             array
+                .storage
                 .properties
                 .get(&VariableValue::Null.to_string())
                 .unwrap(),
@@ -170,10 +211,11 @@ mod tests {
             &VariableValue::Number(Number::Num(0.0)),
             RawPtr::allocate(VariableValue::Undefined),
         );
-        assert_eq!(array.properties.len(), 1);
-        assert_eq!(array.values.len(), 1);
+        assert_eq!(array.storage.properties.len(), 1);
+        assert_eq!(array.storage.values.len(), 1);
         assert_eq!(
             array
+                .storage
                 .properties
                 .get(&VariableValue::Null.to_string())
                 .unwrap(),
@@ -185,27 +227,27 @@ mod tests {
     fn get_property_test() {
         let mut array = Array::new();
 
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
 
         let val = array.get_property(&VariableValue::String(Array::LENGTH_PROPERTY.to_string()));
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
         assert_eq!(val.deref(), &VariableValue::Number(Number::Num(0.0)));
 
         let val = array.get_property(&VariableValue::Null);
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
         assert_eq!(val.deref(), &VariableValue::Undefined);
 
         let val = array.get_property(&VariableValue::Number(Number::Num(0.0)));
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
         assert_eq!(val.deref(), &VariableValue::Undefined);
 
         let val = array.get_property(&VariableValue::String(Array::LENGTH_PROPERTY.to_string()));
-        assert_eq!(array.values.len(), 0);
-        assert_eq!(array.properties.len(), 0);
+        assert_eq!(array.storage.values.len(), 0);
+        assert_eq!(array.storage.properties.len(), 0);
         assert_eq!(val.deref(), &VariableValue::Number(Number::Num(0.0)));
     }
 }
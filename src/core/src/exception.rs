@@ -0,0 +1,75 @@
+use crate::{object::Object, ptr::RawPtr, variable::VariableValue};
+use std::cell::RefCell;
+
+thread_local! {
+    /// Thrown value, which is not caught yet.
+    /// Compiled code checks it after each call, which could throw
+    static PENDING_EXCEPTION: RefCell<Option<RawPtr<VariableValue>>> = const { RefCell::new(None) };
+}
+
+/// Sets the pending exception, the previous one is replaced
+pub fn throw(value: RawPtr<VariableValue>) {
+    PENDING_EXCEPTION.with(|exception| *exception.borrow_mut() = Some(value));
+}
+
+/// Throws the error object, like `{ name: "TypeError", message: "..." }`
+pub fn throw_error(name: &str, message: String) {
+    let mut error = Object::new();
+    error.add_property(
+        &"name".to_string().into(),
+        RawPtr::allocate(name.to_string().into()),
+    );
+    error.add_property(
+        &"message".to_string().into(),
+        RawPtr::allocate(message.into()),
+    );
+    throw(RawPtr::allocate(VariableValue::Object(error)));
+}
+
+pub fn throw_type_error(message: String) {
+    throw_error("TypeError", message);
+}
+
+pub fn has_exception() -> bool {
+    PENDING_EXCEPTION.with(|exception| exception.borrow().is_some())
+}
+
+/// Clears the pending exception and returns it
+pub fn take() -> Option<RawPtr<VariableValue>> {
+    PENDING_EXCEPTION.with(|exception| exception.borrow_mut().take())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throw_test() {
+        assert!(!has_exception());
+        assert_eq!(take(), None);
+
+        throw(RawPtr::allocate(VariableValue::Number(5.0.into())));
+        assert!(has_exception());
+        throw(RawPtr::allocate(VariableValue::Number(6.0.into())));
+        assert!(has_exception());
+
+        assert_eq!(take().as_deref(), Some(&VariableValue::Number(6.0.into())));
+        assert!(!has_exception());
+    }
+
+    #[test]
+    fn throw_type_error_test() {
+        throw_type_error("5 is not a function".to_string());
+
+        let mut error = take().unwrap();
+        assert_eq!(
+            *error.get_property(&"name".to_string().into()),
+            VariableValue::String("TypeError".to_string())
+        );
+        assert_eq!(
+            *error.get_property(&"message".to_string().into()),
+            VariableValue::String("5 is not a function".to_string())
+        );
+        assert!(!has_exception());
+    }
+}
@@ -1,16 +1,38 @@
 use crate::{ptr::RawPtr, variable::VariableValue};
 
-pub type FuncType = extern "C" fn(*mut *mut VariableValue) -> *mut VariableValue;
+/// Compiled function, receives the arguments and the captured variables
+pub type FuncType =
+    extern "C" fn(*mut *mut VariableValue, *mut *mut VariableValue) -> *mut VariableValue;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Function {
     func: FuncType,
     args_num: u32,
+    /// variables captured by the function from the outer scopes
+    env: Vec<RawPtr<VariableValue>>,
+}
+
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        // captured variables are compared by identity, as they could reference the function itself
+        self.func as *const () == other.func as *const ()
+            && self.args_num == other.args_num
+            && self.env.len() == other.env.len()
+            && self
+                .env
+                .iter()
+                .zip(other.env.iter())
+                .all(|(a, b)| a.get_raw() == b.get_raw())
+    }
 }
 
 impl Function {
-    pub fn new(func: FuncType, args_num: u32) -> Self {
-        Self { func, args_num }
+    pub fn new(func: FuncType, args_num: u32, env: Vec<RawPtr<VariableValue>>) -> Self {
+        Self {
+            func,
+            args_num,
+            env,
+        }
     }
 
     #[allow(clippy::inherent_to_string)]
@@ -21,11 +43,21 @@ impl Function {
         )
     }
 
-    pub fn call(&self, args: &mut Vec<*mut VariableValue>) -> RawPtr<VariableValue> {
+    pub fn references(&self) -> impl Iterator<Item = &RawPtr<VariableValue>> {
+        self.env.iter()
+    }
+
+    pub fn call(&self, args: &[*mut VariableValue]) -> RawPtr<VariableValue> {
+        let mut args = args.to_vec();
+        // missing arguments are filled with the undefined values, which are released after the call
+        let mut undefined_args = Vec::new();
         while args.len() < self.args_num as usize {
-            args.push(RawPtr::allocate(VariableValue::Undefined).get_raw());
+            let arg = RawPtr::allocate(VariableValue::Undefined);
+            args.push(arg.get_raw());
+            undefined_args.push(arg);
         }
-        let res = (self.func)(args.as_mut_ptr());
-        RawPtr::from_raw(res).expect("should be always valid")
+        let mut env: Vec<_> = self.env.iter().map(RawPtr::get_raw).collect();
+        let res = (self.func)(args.as_mut_ptr(), env.as_mut_ptr());
+        RawPtr::from_raw_owned(res).expect("should be always valid")
     }
 }
@@ -0,0 +1,251 @@
+use crate::{
+    ptr::{RawPtr, Tracked},
+    variable::VariableValue,
+};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    mem::ManuallyDrop,
+};
+
+thread_local! {
+    static GC: RefCell<GarbageCollector> = RefCell::new(GarbageCollector::new());
+}
+
+/// Mark and sweep garbage collector, which frees the cyclic object graphs unreachable by the reference counting.
+/// Roots of the graph are the variables registered by the compiled code,
+/// values referenced outside of the heap graph (e.g. temporary values of the compiled code) are treated as roots as well
+#[derive(Debug)]
+struct GarbageCollector {
+    /// all allocated values
+    heap: HashSet<*mut VariableValue>,
+    /// compiled code variables
+    roots: HashSet<*mut VariableValue>,
+    /// amount of allocations since the last collection
+    allocations: usize,
+    threshold: usize,
+}
+
+impl GarbageCollector {
+    const MIN_THRESHOLD: usize = 1024;
+
+    fn new() -> Self {
+        Self {
+            heap: HashSet::new(),
+            roots: HashSet::new(),
+            allocations: 0,
+            threshold: Self::MIN_THRESHOLD,
+        }
+    }
+
+    /// Returns the borrowed value, without changing its reference counter
+    unsafe fn borrow(raw: *mut VariableValue) -> ManuallyDrop<RawPtr<VariableValue>> {
+        ManuallyDrop::new(RawPtr::from_raw_owned(raw).unwrap())
+    }
+
+    fn find_garbage(&self) -> Vec<*mut VariableValue> {
+        // count references from the heap values, all remaining references are the external ones
+        let mut internal_refs: HashMap<*mut VariableValue, usize> = HashMap::new();
+        // copies of the object hold the references of the object only once
+        let mut counted = HashSet::new();
+        for raw in &self.heap {
+            let value = unsafe { Self::borrow(*raw) };
+            if value.shared_id().is_some_and(|id| !counted.insert(id)) {
+                continue;
+            }
+            for reference in value.references() {
+                *internal_refs.entry(reference.get_raw()).or_default() += 1;
+            }
+        }
+
+        let mut stack: Vec<_> = self
+            .heap
+            .iter()
+            .filter(|raw| {
+                let value = unsafe { Self::borrow(**raw) };
+                self.roots.contains(*raw)
+                    || value.ref_count() > internal_refs.get(*raw).copied().unwrap_or_default()
+            })
+            .copied()
+            .collect();
+
+        // mark
+        let mut marked = HashSet::new();
+        while let Some(raw) = stack.pop() {
+            if marked.insert(raw) {
+                let value = unsafe { Self::borrow(raw) };
+                stack.extend(value.references().map(RawPtr::get_raw));
+            }
+        }
+
+        self.heap
+            .iter()
+            .filter(|raw| !marked.contains(*raw))
+            .copied()
+            .collect()
+    }
+}
+
+impl Tracked for VariableValue {
+    fn on_allocate(raw: *mut Self) {
+        GC.with(|gc| {
+            let mut gc = gc.borrow_mut();
+            gc.heap.insert(raw);
+            gc.allocations += 1;
+        });
+    }
+
+    fn on_deallocate(raw: *mut Self) {
+        GC.with(|gc| {
+            let mut gc = gc.borrow_mut();
+            gc.heap.remove(&raw);
+            gc.roots.remove(&raw);
+        });
+    }
+}
+
+pub fn add_root(raw: *mut VariableValue) {
+    GC.with(|gc| gc.borrow_mut().roots.insert(raw));
+}
+
+pub fn remove_root(raw: *mut VariableValue) {
+    GC.with(|gc| gc.borrow_mut().roots.remove(&raw));
+}
+
+/// Frees all unreachable values
+pub fn collect() {
+    let garbage = GC.with(|gc| {
+        let mut gc = gc.borrow_mut();
+        gc.allocations = 0;
+        gc.find_garbage()
+    });
+
+    // garbage values are kept alive until all of them are cleared,
+    // so breaking the cycles could not free the value which is still in use
+    let mut garbage: Vec<_> = garbage
+        .into_iter()
+        .map(|raw| RawPtr::from_raw(raw).unwrap())
+        .collect();
+    for value in &mut garbage {
+        **value = VariableValue::Undefined;
+    }
+    drop(garbage);
+
+    GC.with(|gc| {
+        let mut gc = gc.borrow_mut();
+        gc.threshold = gc.heap.len().max(GarbageCollector::MIN_THRESHOLD);
+    });
+}
+
+/// Runs the collection, if enough values were allocated since the last one.
+/// Should be called only if there are no borrowed values on the Rust side
+pub fn collect_if_needed() {
+    let need_collect = GC.with(|gc| {
+        let gc = gc.borrow();
+        gc.allocations >= gc.threshold
+    });
+    if need_collect {
+        collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heap_size() -> usize {
+        GC.with(|gc| gc.borrow().heap.len())
+    }
+
+    fn new_object() -> RawPtr<VariableValue> {
+        RawPtr::allocate(VariableValue::Object(crate::object::Object::new()))
+    }
+
+    #[test]
+    fn collect_test() {
+        let initial_size = heap_size();
+
+        let a = new_object();
+        let b = new_object();
+        a.clone().add_property(&"b".to_string().into(), b.clone());
+        b.clone().add_property(&"a".to_string().into(), a.clone());
+        assert_eq!(heap_size(), initial_size + 2);
+
+        // referenced values are kept
+        collect();
+        assert_eq!(heap_size(), initial_size + 2);
+        drop(b);
+        collect();
+        assert_eq!(heap_size(), initial_size + 2);
+
+        // unreachable cycle is freed
+        drop(a);
+        assert_eq!(heap_size(), initial_size + 2);
+        collect();
+        assert_eq!(heap_size(), initial_size);
+    }
+
+    #[test]
+    fn shared_object_test() {
+        let initial_size = heap_size();
+
+        // both variables reference the same object, which references them and the value
+        let a = new_object();
+        let b = RawPtr::allocate((*a).clone());
+        let c = RawPtr::allocate(VariableValue::Boolean(true));
+        a.clone().add_property(&"a".to_string().into(), a.clone());
+        a.clone().add_property(&"b".to_string().into(), b.clone());
+        b.clone().add_property(&"c".to_string().into(), c.clone());
+        assert_eq!(heap_size(), initial_size + 3);
+
+        // the value is referenced by the object once, so the remaining reference keeps it
+        drop(a);
+        drop(b);
+        collect();
+        assert_eq!(heap_size(), initial_size + 1);
+        assert_eq!(*c, VariableValue::Boolean(true));
+
+        drop(c);
+        assert_eq!(heap_size(), initial_size);
+    }
+
+    #[test]
+    fn function_env_test() {
+        extern "C" fn func(
+            _args: *mut *mut VariableValue,
+            _env: *mut *mut VariableValue,
+        ) -> *mut VariableValue {
+            RawPtr::allocate(VariableValue::Undefined).into_raw()
+        }
+
+        let initial_size = heap_size();
+
+        // function captures its own variable
+        let mut a = RawPtr::allocate(VariableValue::Undefined);
+        *a = VariableValue::Function(crate::function::Function::new(func, 0, vec![a.clone()]));
+
+        collect();
+        assert_eq!(heap_size(), initial_size + 1);
+
+        drop(a);
+        collect();
+        assert_eq!(heap_size(), initial_size);
+    }
+
+    #[test]
+    fn roots_test() {
+        let initial_size = heap_size();
+
+        let a = new_object();
+        a.clone().add_property(&"a".to_string().into(), a.clone());
+        add_root(a.get_raw());
+
+        collect();
+        assert_eq!(heap_size(), initial_size + 1);
+
+        remove_root(a.get_raw());
+        drop(a);
+        collect();
+        assert_eq!(heap_size(), initial_size);
+    }
+}
@@ -1,10 +1,30 @@
-use crate::{ptr::RawPtr, variable::VariableValue};
+use crate::{gc, ptr::RawPtr, variable::VariableValue};
 
 #[no_mangle]
 pub extern "C" fn allocate() -> *mut VariableValue {
+    // compiled code does not borrow any values on the Rust side, so it is a safe point for the collection
+    gc::collect_if_needed();
     let val = VariableValue::Undefined;
-    RawPtr::allocate(val).get_raw()
+    RawPtr::allocate(val).into_raw()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn deallocate(_this: *mut VariableValue) {}
+pub unsafe extern "C" fn deallocate(this: *mut VariableValue) {
+    // releases the reference owned by the caller, the value is freed with the last reference
+    drop(RawPtr::from_raw_owned(this));
+}
+
+#[no_mangle]
+pub extern "C" fn gc_add_root(this: *mut VariableValue) {
+    gc::add_root(this);
+}
+
+#[no_mangle]
+pub extern "C" fn gc_remove_root(this: *mut VariableValue) {
+    gc::remove_root(this);
+}
+
+#[no_mangle]
+pub extern "C" fn gc_collect() {
+    gc::collect();
+}
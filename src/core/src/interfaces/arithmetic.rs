@@ -10,7 +10,7 @@ pub extern "C" fn arithmetic_addition(
     let val2 = RawPtr::from_raw(val2).unwrap();
 
     let res = VariableValue::add(val1.deref(), val2.deref());
-    Box::into_raw(Box::new(res))
+    RawPtr::allocate(res).into_raw()
 }
 
 #[no_mangle]
@@ -22,7 +22,7 @@ pub extern "C" fn arithmetic_substraction(
     let val2 = RawPtr::from_raw(val2).unwrap();
 
     let res = VariableValue::sub(val1.deref(), val2.deref());
-    Box::into_raw(Box::new(res))
+    RawPtr::allocate(res).into_raw()
 }
 
 #[no_mangle]
@@ -34,7 +34,7 @@ pub extern "C" fn arithmetic_multiplication(
     let val2 = RawPtr::from_raw(val2).unwrap();
 
     let res = VariableValue::mul(val1.deref(), val2.deref());
-    Box::into_raw(Box::new(res))
+    RawPtr::allocate(res).into_raw()
 }
 
 #[no_mangle]
@@ -46,5 +46,115 @@ pub extern "C" fn arithmetic_division(
     let val2 = RawPtr::from_raw(val2).unwrap();
 
     let res = VariableValue::div(val1.deref(), val2.deref());
-    Box::into_raw(Box::new(res))
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_remainder(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::rem(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_exponentiation(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::pow(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_negation(val: *mut VariableValue) -> *mut VariableValue {
+    let val = RawPtr::from_raw(val).unwrap();
+
+    RawPtr::allocate(val.neg()).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_bitwise_and(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::bit_and(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_bitwise_or(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::bit_or(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_bitwise_xor(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::bit_xor(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_bitwise_not(val: *mut VariableValue) -> *mut VariableValue {
+    let val = RawPtr::from_raw(val).unwrap();
+
+    RawPtr::allocate(val.bit_not()).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_left_shift(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::shl(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_right_shift(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::shr(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn arithmetic_unsigned_right_shift(
+    val1: *mut VariableValue,
+    val2: *mut VariableValue,
+) -> *mut VariableValue {
+    let val1 = RawPtr::from_raw(val1).unwrap();
+    let val2 = RawPtr::from_raw(val2).unwrap();
+
+    let res = VariableValue::ushr(val1.deref(), val2.deref());
+    RawPtr::allocate(res).into_raw()
 }
@@ -0,0 +1,30 @@
+use crate::{exception, ptr::RawPtr, variable::VariableValue};
+
+#[no_mangle]
+pub extern "C" fn throw_exception(this: *mut VariableValue) {
+    let this = RawPtr::from_raw(this).unwrap();
+
+    exception::throw(this);
+}
+
+#[no_mangle]
+pub extern "C" fn has_exception() -> bool {
+    exception::has_exception()
+}
+
+/// Returns the pending exception and clears it, returns `undefined` if there is no exception
+#[no_mangle]
+pub extern "C" fn take_exception() -> *mut VariableValue {
+    exception::take()
+        .unwrap_or_else(|| RawPtr::allocate(VariableValue::Undefined))
+        .into_raw()
+}
+
+/// Reports the exception, which was not caught by the compiled code, and terminates the program
+#[no_mangle]
+pub extern "C" fn uncaught_exception() {
+    if let Some(exception) = exception::take() {
+        eprintln!("Uncaught {}", exception.to_string());
+    }
+    std::process::exit(1);
+}
@@ -5,51 +5,51 @@ use std::ops::Deref;
 pub unsafe extern "C" fn logical_not(val: *mut VariableValue) -> *mut VariableValue {
     let val = RawPtr::from_raw(val).unwrap();
 
-    RawPtr::allocate(val.not()).get_raw()
+    RawPtr::allocate(val.not()).into_raw()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn logical_and(
+pub unsafe extern "C" fn logical_eq(
     val1: *mut VariableValue,
     val2: *mut VariableValue,
 ) -> *mut VariableValue {
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::and(val1.deref(), val2.deref())).get_raw()
+    RawPtr::allocate(VariableValue::loose_eq(val1.deref(), val2.deref())).into_raw()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn logical_or(
+pub unsafe extern "C" fn logical_ne(
     val1: *mut VariableValue,
     val2: *mut VariableValue,
 ) -> *mut VariableValue {
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::or(val1.deref(), val2.deref())).get_raw()
+    RawPtr::allocate(VariableValue::loose_eq(val1.deref(), val2.deref()).not()).into_raw()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn logical_eq(
+pub unsafe extern "C" fn logical_seq(
     val1: *mut VariableValue,
     val2: *mut VariableValue,
 ) -> *mut VariableValue {
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::from(val1 == val2)).get_raw()
+    RawPtr::allocate(VariableValue::strict_eq(val1.deref(), val2.deref())).into_raw()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn logical_ne(
+pub unsafe extern "C" fn logical_sne(
     val1: *mut VariableValue,
     val2: *mut VariableValue,
 ) -> *mut VariableValue {
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::from(val1 != val2)).get_raw()
+    RawPtr::allocate(VariableValue::strict_eq(val1.deref(), val2.deref()).not()).into_raw()
 }
 
 #[no_mangle]
@@ -60,7 +60,7 @@ pub unsafe extern "C" fn logical_gt(
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::gt(val1.deref(), val2.deref())).get_raw()
+    RawPtr::allocate(VariableValue::gt(val1.deref(), val2.deref())).into_raw()
 }
 
 #[no_mangle]
@@ -71,7 +71,7 @@ pub unsafe extern "C" fn logical_ge(
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::ge(val1.deref(), val2.deref())).get_raw()
+    RawPtr::allocate(VariableValue::ge(val1.deref(), val2.deref())).into_raw()
 }
 
 #[no_mangle]
@@ -82,7 +82,7 @@ pub unsafe extern "C" fn logical_lt(
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::lt(val1.deref(), val2.deref())).get_raw()
+    RawPtr::allocate(VariableValue::lt(val1.deref(), val2.deref())).into_raw()
 }
 
 #[no_mangle]
@@ -93,5 +93,5 @@ pub unsafe extern "C" fn logical_le(
     let val1 = RawPtr::from_raw(val1).unwrap();
     let val2 = RawPtr::from_raw(val2).unwrap();
 
-    RawPtr::allocate(VariableValue::le(val1.deref(), val2.deref())).get_raw()
+    RawPtr::allocate(VariableValue::le(val1.deref(), val2.deref())).into_raw()
 }
@@ -1,6 +1,7 @@
 mod alloc;
 mod arithmetic;
 mod convert;
+mod exception;
 mod logical;
 mod object;
 mod test_utils;
@@ -61,7 +61,7 @@ pub unsafe extern "C" fn get_property_by_boolean(
 ) -> *mut VariableValue {
     let mut this = RawPtr::from_raw(this).unwrap();
 
-    this.get_property(&property_name.into()).get_raw()
+    this.get_property(&property_name.into()).into_raw()
 }
 
 #[no_mangle]
@@ -72,7 +72,7 @@ pub unsafe extern "C" fn get_property_by_number(
     let mut this = RawPtr::from_raw(this).unwrap();
 
     this.get_property(&VariableValue::Number(property_name.into()))
-        .get_raw()
+        .into_raw()
 }
 
 #[no_mangle]
@@ -83,7 +83,7 @@ pub unsafe extern "C" fn get_property_by_str(
     let mut this = RawPtr::from_raw(this).unwrap();
     let property_name = CStr::from_ptr(property_name).to_str().unwrap().to_string();
 
-    this.get_property(&property_name.into()).get_raw()
+    this.get_property(&property_name.into()).into_raw()
 }
 
 #[no_mangle]
@@ -94,7 +94,7 @@ pub extern "C" fn get_property_by_var(
     let mut this = RawPtr::from_raw(this).unwrap();
     let property_name = RawPtr::from_raw(property_name).unwrap();
 
-    this.get_property(property_name.deref()).get_raw()
+    this.get_property(property_name.deref()).into_raw()
 }
 
 #[no_mangle]
@@ -104,8 +104,11 @@ pub unsafe extern "C" fn function_call(
     args_num: u32,
 ) -> *mut VariableValue {
     let this = RawPtr::from_raw(this).unwrap();
-    // providing 0 capacity value because we dont want to deallocate memory for args
-    let mut args = Vec::from_raw_parts(args, args_num as usize, 0);
+    let args = if args_num == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(args, args_num as usize)
+    };
 
-    this.function_call(&mut args).get_raw()
+    this.function_call(args).into_raw()
 }
@@ -80,10 +80,24 @@ pub unsafe extern "C" fn set_empty_array(this: *mut VariableValue) {
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn set_function(this: *mut VariableValue, func: FuncType, args_num: u32) {
+pub unsafe extern "C" fn set_function(
+    this: *mut VariableValue,
+    func: FuncType,
+    args_num: u32,
+    env: *mut *mut VariableValue,
+    env_num: u32,
+) {
     let mut this = RawPtr::from_raw(this).unwrap();
-
-    *this = VariableValue::Function(Function::new(func, args_num));
+    let env = if env_num == 0 {
+        Vec::new()
+    } else {
+        std::slice::from_raw_parts(env, env_num as usize)
+            .iter()
+            .map(|val| RawPtr::from_raw(*val).unwrap())
+            .collect()
+    };
+
+    *this = VariableValue::Function(Function::new(func, args_num, env));
 }
 
 #[no_mangle]
@@ -100,3 +114,10 @@ pub extern "C" fn get_boolean(this: *mut VariableValue) -> bool {
 
     this.to_boolean()
 }
+
+#[no_mangle]
+pub extern "C" fn get_type_of(this: *mut VariableValue) -> *mut VariableValue {
+    let this = RawPtr::from_raw(this).unwrap();
+
+    RawPtr::allocate(VariableValue::from(this.type_of().to_string())).into_raw()
+}
@@ -1,5 +1,7 @@
 mod array;
+mod exception;
 mod function;
+mod gc;
 mod interfaces;
 mod number;
 mod object;
@@ -41,6 +41,40 @@ impl Number {
             Number::Num(value) => value.to_string(),
         }
     }
+
+    /// Converts the string to the number, the empty string is `0`, not numeric strings are `NaN`
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_string(string: &str) -> Self {
+        let from_radix = |digits: &str, radix| {
+            if digits.chars().all(|c| c.is_digit(radix)) {
+                u64::from_str_radix(digits, radix)
+                    .map_or(Number::NaN, |value| Number::Num(value as f64))
+            } else {
+                Number::NaN
+            }
+        };
+
+        let string = string.trim();
+        match string.get(..2) {
+            _ if string.is_empty() => Number::Num(0.0),
+            Some("0x" | "0X") => from_radix(&string[2..], 16),
+            Some("0o" | "0O") => from_radix(&string[2..], 8),
+            Some("0b" | "0B") => from_radix(&string[2..], 2),
+            _ => match string {
+                "Infinity" | "+Infinity" => Number::Infinity,
+                "-Infinity" => Number::NegInfinity,
+                // skip "inf" and "nan", which are accepted by the Rust parser
+                string
+                    if string.chars().all(|c| {
+                        c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')
+                    }) =>
+                {
+                    string.parse::<f64>().map_or(Number::NaN, Number::from)
+                }
+                _ => Number::NaN,
+            },
+        }
+    }
 }
 
 // arithmetic operations
@@ -118,6 +152,95 @@ impl Number {
             (&Number::Num(a), &Number::Num(b)) => (a / b).into(),
         }
     }
+
+    /// JS `%` operator, the result has the sign of the dividend
+    pub fn rem(a: &Number, b: &Number) -> Number {
+        match (a, b) {
+            (&Number::NaN, _) | (_, &Number::NaN) => Number::NaN,
+            (&Number::Infinity | &Number::NegInfinity, _) => Number::NaN,
+            (&Number::Num(a), &Number::Infinity | &Number::NegInfinity) => Number::Num(a),
+            (&Number::Num(a), &Number::Num(b)) => (a % b).into(),
+        }
+    }
+
+    /// JS `**` operator
+    pub fn pow(a: &Number, b: &Number) -> Number {
+        let (a, b) = (a.to_f64(), b.to_f64());
+        // unlike `f64::powf`, `1 ** Infinity` is `NaN`
+        if b.is_infinite() && a.abs() == 1.0 {
+            Number::NaN
+        } else {
+            a.powf(b).into()
+        }
+    }
+
+    /// JS unary `-` operator
+    pub fn neg(a: &Number) -> Number {
+        match a {
+            Number::NaN => Number::NaN,
+            Number::Infinity => Number::NegInfinity,
+            Number::NegInfinity => Number::Infinity,
+            Number::Num(a) => Number::Num(-a),
+        }
+    }
+}
+
+// bitwise operations
+
+impl Number {
+    /// JS ToUint32 conversion, `NaN` and infinities are `0`, other values are truncated modulo 2^32
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_uint32(&self) -> u32 {
+        match self {
+            Number::NaN | Number::Infinity | Number::NegInfinity => 0,
+            Number::Num(value) => value.trunc().rem_euclid(4_294_967_296.0) as u32,
+        }
+    }
+
+    /// JS ToInt32 conversion
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn to_int32(&self) -> i32 {
+        self.to_uint32() as i32
+    }
+
+    pub fn bit_and(a: &Number, b: &Number) -> Number {
+        Number::Num(f64::from(a.to_int32() & b.to_int32()))
+    }
+
+    pub fn bit_or(a: &Number, b: &Number) -> Number {
+        Number::Num(f64::from(a.to_int32() | b.to_int32()))
+    }
+
+    pub fn bit_xor(a: &Number, b: &Number) -> Number {
+        Number::Num(f64::from(a.to_int32() ^ b.to_int32()))
+    }
+
+    pub fn bit_not(a: &Number) -> Number {
+        Number::Num(f64::from(!a.to_int32()))
+    }
+
+    /// Only the lowest 5 bits of the shift count are used
+    pub fn shl(a: &Number, b: &Number) -> Number {
+        Number::Num(f64::from(a.to_int32().wrapping_shl(b.to_uint32() & 31)))
+    }
+
+    pub fn shr(a: &Number, b: &Number) -> Number {
+        Number::Num(f64::from(a.to_int32() >> (b.to_uint32() & 31)))
+    }
+
+    /// Zero-filling right shift, the result is unsigned
+    pub fn ushr(a: &Number, b: &Number) -> Number {
+        Number::Num(f64::from(a.to_uint32() >> (b.to_uint32() & 31)))
+    }
+
+    fn to_f64(&self) -> f64 {
+        match self {
+            Number::NaN => f64::NAN,
+            Number::Infinity => f64::INFINITY,
+            Number::NegInfinity => f64::NEG_INFINITY,
+            Number::Num(value) => *value,
+        }
+    }
 }
 
 // logical operations
@@ -174,6 +297,16 @@ impl Number {
             _ => true,
         }
     }
+
+    /// Numeric equality, `NaN` is not equal to anything, including itself
+    pub fn equals(a: &Number, b: &Number) -> bool {
+        match (a, b) {
+            (&Number::NaN, _) => false,
+            (_, &Number::NaN) => false,
+            (&Number::Num(a), &Number::Num(b)) => a == b,
+            (a, b) => a == b,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -422,6 +555,137 @@ mod tests {
         );
     }
 
+    #[proptest]
+    fn rem_test(a: f64, #[filter(#b != 0.0)] b: f64) {
+        assert_eq!(
+            Number::rem(&Number::Num(a), &Number::Num(b)),
+            (a % b).into()
+        );
+
+        assert_eq!(Number::rem(&Number::Num(a), &Number::Num(0.0)), Number::NaN);
+        assert_eq!(Number::rem(&Number::NaN, &Number::Num(b)), Number::NaN);
+        assert_eq!(Number::rem(&Number::Num(a), &Number::NaN), Number::NaN);
+        assert_eq!(Number::rem(&Number::Infinity, &Number::Num(b)), Number::NaN);
+        assert_eq!(
+            Number::rem(&Number::NegInfinity, &Number::Num(b)),
+            Number::NaN
+        );
+        assert_eq!(
+            Number::rem(&Number::Num(a), &Number::Infinity),
+            Number::Num(a)
+        );
+        assert_eq!(
+            Number::rem(&Number::Num(a), &Number::NegInfinity),
+            Number::Num(a)
+        );
+    }
+
+    #[proptest]
+    fn pow_test(a: f64, b: f64) {
+        assert_eq!(
+            Number::pow(&Number::Num(a), &Number::Num(b)),
+            a.powf(b).into()
+        );
+
+        assert_eq!(
+            Number::pow(&Number::NaN, &Number::Num(0.0)),
+            Number::Num(1.0)
+        );
+        assert_eq!(Number::pow(&Number::NaN, &Number::Num(1.0)), Number::NaN);
+        assert_eq!(
+            Number::pow(&Number::Num(1.0), &Number::Infinity),
+            Number::NaN
+        );
+        assert_eq!(
+            Number::pow(&Number::Num(-1.0), &Number::NegInfinity),
+            Number::NaN
+        );
+        assert_eq!(
+            Number::pow(&Number::Num(2.0), &Number::Infinity),
+            Number::Infinity
+        );
+        assert_eq!(
+            Number::pow(&Number::Num(2.0), &Number::Num(10.0)),
+            Number::Num(1024.0)
+        );
+    }
+
+    #[proptest]
+    fn neg_test(a: f64) {
+        assert_eq!(Number::neg(&Number::Num(a)), (-a).into());
+        assert_eq!(Number::neg(&Number::NaN), Number::NaN);
+        assert_eq!(Number::neg(&Number::Infinity), Number::NegInfinity);
+        assert_eq!(Number::neg(&Number::NegInfinity), Number::Infinity);
+    }
+
+    #[proptest]
+    fn to_int32_test(a: i32, b: u32) {
+        assert_eq!(Number::Num(f64::from(a)).to_int32(), a);
+        assert_eq!(Number::Num(f64::from(b)).to_uint32(), b);
+        assert_eq!(Number::Num(f64::from(b) + 4_294_967_296.0).to_uint32(), b);
+        assert_eq!(Number::Num(f64::from(a) - 4_294_967_296.0).to_int32(), a);
+
+        assert_eq!(Number::Num(-1.0).to_uint32(), u32::MAX);
+        assert_eq!(Number::Num(4_294_967_295.0).to_int32(), -1);
+        assert_eq!(Number::Num(1.9).to_int32(), 1);
+        assert_eq!(Number::Num(-1.9).to_int32(), -1);
+        assert_eq!(Number::NaN.to_int32(), 0);
+        assert_eq!(Number::Infinity.to_int32(), 0);
+        assert_eq!(Number::NegInfinity.to_uint32(), 0);
+    }
+
+    #[proptest]
+    fn bitwise_test(a: i32, b: i32) {
+        let (num_a, num_b) = (Number::Num(f64::from(a)), Number::Num(f64::from(b)));
+        assert_eq!(
+            Number::bit_and(&num_a, &num_b),
+            Number::Num(f64::from(a & b))
+        );
+        assert_eq!(
+            Number::bit_or(&num_a, &num_b),
+            Number::Num(f64::from(a | b))
+        );
+        assert_eq!(
+            Number::bit_xor(&num_a, &num_b),
+            Number::Num(f64::from(a ^ b))
+        );
+        assert_eq!(Number::bit_not(&num_a), Number::Num(f64::from(!a)));
+
+        assert_eq!(
+            Number::bit_or(&Number::NaN, &num_b),
+            Number::Num(f64::from(b))
+        );
+        assert_eq!(Number::bit_and(&Number::Infinity, &num_b), Number::Num(0.0));
+        assert_eq!(Number::bit_not(&Number::NaN), Number::Num(-1.0));
+    }
+
+    #[proptest]
+    fn shift_test(a: i32, #[strategy(0_u32..32)] b: u32) {
+        let (num_a, num_b) = (Number::Num(f64::from(a)), Number::Num(f64::from(b)));
+        assert_eq!(Number::shl(&num_a, &num_b), Number::Num(f64::from(a << b)));
+        assert_eq!(Number::shr(&num_a, &num_b), Number::Num(f64::from(a >> b)));
+        #[allow(clippy::cast_sign_loss)]
+        let unsigned_a = a as u32;
+        assert_eq!(
+            Number::ushr(&num_a, &num_b),
+            Number::Num(f64::from(unsigned_a >> b))
+        );
+
+        // only the lowest 5 bits of the shift count are used
+        assert_eq!(
+            Number::shl(&num_a, &Number::Num(f64::from(b + 32))),
+            Number::shl(&num_a, &num_b)
+        );
+        assert_eq!(
+            Number::ushr(&Number::Num(-1.0), &Number::Num(0.0)),
+            Number::Num(4_294_967_295.0)
+        );
+        assert_eq!(
+            Number::shr(&Number::Num(-16.0), &Number::Num(2.0)),
+            Number::Num(-4.0)
+        );
+    }
+
     #[proptest]
     fn gt_test(a: f64, b: f64) {
         assert!(!Number::gt(&Number::NaN, &Number::NaN));
@@ -513,4 +777,35 @@ mod tests {
 
         assert_eq!(Number::le(&Number::Num(a), &Number::Num(b)), a <= b,);
     }
+
+    #[proptest]
+    fn from_string_test(number: f64) {
+        assert_eq!(Number::from_string(&number.to_string()), number.into());
+
+        assert_eq!(Number::from_string(""), Number::Num(0.0));
+        assert_eq!(Number::from_string(" 12.5 "), Number::Num(12.5));
+        assert_eq!(Number::from_string("1e3"), Number::Num(1000.0));
+        assert_eq!(Number::from_string("-Infinity"), Number::NegInfinity);
+        assert_eq!(Number::from_string("0x1F"), Number::Num(31.0));
+        assert_eq!(Number::from_string("0b101"), Number::Num(5.0));
+        assert_eq!(Number::from_string("0x"), Number::NaN);
+        assert_eq!(Number::from_string("0x+1"), Number::NaN);
+        assert_eq!(Number::from_string("inf"), Number::NaN);
+        assert_eq!(Number::from_string("12abc"), Number::NaN);
+    }
+
+    #[proptest]
+    fn equals_test(a: f64, b: f64) {
+        assert!(!Number::equals(&Number::NaN, &Number::NaN));
+        assert!(!Number::equals(&Number::NaN, &Number::Num(b)));
+        assert!(!Number::equals(&Number::Num(a), &Number::NaN));
+
+        assert!(Number::equals(&Number::Infinity, &Number::Infinity));
+        assert!(!Number::equals(&Number::Infinity, &Number::NegInfinity));
+        assert!(!Number::equals(&Number::Infinity, &Number::Num(b)));
+        assert!(Number::equals(&Number::NegInfinity, &Number::NegInfinity));
+        assert!(!Number::equals(&Number::Num(a), &Number::NegInfinity));
+
+        assert_eq!(Number::equals(&Number::Num(a), &Number::Num(b)), a == b);
+    }
 }
@@ -1,18 +1,36 @@
-use crate::{ptr::RawPtr, variable::VariableValue};
+use crate::{
+    ptr::{RawPtr, Tracked},
+    variable::VariableValue,
+};
 use std::collections::HashMap;
 
+type Properties = HashMap<String, RawPtr<VariableValue>>;
+
+impl Tracked for Properties {}
+
+/// Reference to the object, all copies share the same properties
 #[derive(Debug, Clone, PartialEq)]
 pub struct Object {
-    properties: HashMap<String, RawPtr<VariableValue>>,
+    properties: RawPtr<Properties>,
 }
 
 impl Object {
     pub fn new() -> Self {
         Self {
-            properties: HashMap::new(),
+            properties: RawPtr::allocate(HashMap::new()),
         }
     }
 
+    /// Returns the address of the properties, which identifies the object
+    pub fn id(&self) -> *const () {
+        self.properties.get_raw().cast_const().cast()
+    }
+
+    /// Returns true if both values reference the same object
+    pub fn is_same(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         let mut res = String::new();
@@ -28,6 +46,11 @@ impl Object {
         res
     }
 
+    /// Returns all values referenced by the object
+    pub fn references(&self) -> impl Iterator<Item = &RawPtr<VariableValue>> {
+        self.properties.values()
+    }
+
     pub fn add_property(&mut self, property_name: &VariableValue, property: RawPtr<VariableValue>) {
         self.properties.insert(property_name.to_string(), property);
     }
@@ -1,55 +1,111 @@
 use std::ops::{Deref, DerefMut};
 
+/// Heap allocated value with the reference counter.
+/// `value` must be the first field, so a pointer to the `RcBox<T>` is a valid pointer to the `T`
+#[repr(C)]
+struct RcBox<T: Tracked> {
+    value: T,
+    counter: usize,
+}
+
+/// Allocation hooks of the `RawPtr` values, used to keep track of the allocations by the garbage collector
+pub trait Tracked: Sized {
+    fn on_allocate(_raw: *mut Self) {}
+    fn on_deallocate(_raw: *mut Self) {}
+}
+
+/// Reference counted pointer, which could be passed through the FFI boundary as a raw `*mut T`.
+/// Each `RawPtr` instance owns one reference, the value is deallocated when the last one is dropped
 #[derive(Debug)]
-pub struct RawPtr<T> {
-    raw: *mut T,
+pub struct RawPtr<T: Tracked> {
+    raw: *mut RcBox<T>,
 }
 
-impl<T> RawPtr<T> {
+impl<T: Tracked> RawPtr<T> {
     pub fn allocate(val: T) -> Self {
-        let ptr = Box::into_raw(Box::new(val));
-        Self::from_raw(ptr).unwrap()
+        let ptr = Box::into_raw(Box::new(RcBox {
+            value: val,
+            counter: 1,
+        }));
+        T::on_allocate(ptr.cast());
+        Self { raw: ptr }
     }
 
+    /// Creates a new reference to the already allocated value, increments the reference counter
     pub fn from_raw(raw: *mut T) -> Option<Self> {
+        let res = Self::from_raw_owned(raw)?;
+        unsafe {
+            (*res.raw).counter += 1;
+        }
+        Some(res)
+    }
+
+    /// Takes the ownership of the one reference, which was previously released by the `into_raw`
+    pub fn from_raw_owned(raw: *mut T) -> Option<Self> {
         if raw.is_null() {
             None
         } else {
-            Some(Self { raw })
+            Some(Self { raw: raw.cast() })
         }
     }
 
     pub fn copy(&self) -> Self {
-        Self::from_raw(self.raw).expect("should be always valid")
+        self.clone()
     }
 
     pub fn get_raw(&self) -> *mut T {
-        self.raw
+        self.raw.cast()
+    }
+
+    /// Releases the reference without decrementing the reference counter
+    pub fn into_raw(self) -> *mut T {
+        let raw = self.get_raw();
+        std::mem::forget(self);
+        raw
+    }
+
+    pub fn ref_count(&self) -> usize {
+        unsafe { (*self.raw).counter }
     }
 }
 
-impl<T> Clone for RawPtr<T> {
+impl<T: Tracked> Clone for RawPtr<T> {
     fn clone(&self) -> Self {
+        unsafe {
+            (*self.raw).counter += 1;
+        }
         Self { raw: self.raw }
     }
 }
 
-impl<T: PartialEq> PartialEq for RawPtr<T> {
+impl<T: Tracked> Drop for RawPtr<T> {
+    fn drop(&mut self) {
+        unsafe {
+            (*self.raw).counter -= 1;
+            if (*self.raw).counter == 0 {
+                T::on_deallocate(self.raw.cast());
+                drop(Box::from_raw(self.raw));
+            }
+        }
+    }
+}
+
+impl<T: Tracked + PartialEq> PartialEq for RawPtr<T> {
     fn eq(&self, other: &Self) -> bool {
-        unsafe { (*self.raw) == (*other.raw) }
+        self.deref() == other.deref()
     }
 }
 
-impl<T> Deref for RawPtr<T> {
+impl<T: Tracked> Deref for RawPtr<T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
-        unsafe { &*self.raw }
+        unsafe { &(*self.raw).value }
     }
 }
 
-impl<T> DerefMut for RawPtr<T> {
+impl<T: Tracked> DerefMut for RawPtr<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut *self.raw }
+        unsafe { &mut (*self.raw).value }
     }
 }
 
@@ -57,6 +113,8 @@ impl<T> DerefMut for RawPtr<T> {
 mod tests {
     use super::*;
 
+    impl Tracked for i32 {}
+
     #[test]
     fn raw_ptr_eq_test() {
         assert_eq!(RawPtr::allocate(5), RawPtr::allocate(5));
@@ -73,4 +131,25 @@ mod tests {
             assert_eq!(*ptr.get_raw(), 5);
         }
     }
+
+    #[test]
+    fn raw_ptr_ref_count_test() {
+        let ptr = RawPtr::allocate(5);
+        assert_eq!(ptr.ref_count(), 1);
+
+        let ptr2 = ptr.copy();
+        assert_eq!(ptr.ref_count(), 2);
+
+        let ptr3 = RawPtr::from_raw(ptr.get_raw()).unwrap();
+        assert_eq!(ptr.ref_count(), 3);
+
+        drop(ptr2);
+        drop(ptr3);
+        assert_eq!(ptr.ref_count(), 1);
+
+        let raw = ptr.into_raw();
+        let ptr = RawPtr::from_raw_owned(raw).unwrap();
+        assert_eq!(ptr.ref_count(), 1);
+        assert_eq!(ptr.deref(), &5);
+    }
 }
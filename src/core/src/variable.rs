@@ -1,4 +1,6 @@
-use crate::{array::Array, function::Function, number::Number, object::Object, ptr::RawPtr};
+use crate::{
+    array::Array, exception, function::Function, number::Number, object::Object, ptr::RawPtr,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum VariableValue {
@@ -38,8 +40,8 @@ impl VariableValue {
             Self::Number(number) => number.to_boolean(),
             Self::Boolean(boolean) => *boolean,
             Self::String(string) => !string.is_empty(),
-            Self::Object(_) => false,
-            Self::Array(_) => false,
+            Self::Object(_) => true,
+            Self::Array(_) => true,
             Self::Function(_) => true,
         }
     }
@@ -51,13 +53,32 @@ impl VariableValue {
             Self::Number(number) => number.clone(),
             Self::Boolean(true) => Number::Num(1.0),
             Self::Boolean(false) => Number::Num(0.0),
-            Self::String(_) => Number::NaN,
+            Self::String(string) => Number::from_string(string),
             Self::Object(_) => Number::NaN,
             Self::Array(_) => Number::NaN,
             Self::Function(_) => Number::NaN,
         }
     }
 
+    /// Returns all values referenced by the value, only objects, arrays and functions could reference other values
+    pub fn references(&self) -> Box<dyn Iterator<Item = &RawPtr<VariableValue>> + '_> {
+        match self {
+            Self::Object(object) => Box::new(object.references()),
+            Self::Array(array) => Box::new(array.references()),
+            Self::Function(function) => Box::new(function.references()),
+            _ => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Returns the identity of the object or array, all copies of which share the same references
+    pub fn shared_id(&self) -> Option<*const ()> {
+        match self {
+            Self::Object(object) => Some(object.id()),
+            Self::Array(array) => Some(array.id()),
+            _ => None,
+        }
+    }
+
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         match self {
@@ -72,15 +93,40 @@ impl VariableValue {
             Self::Function(function) => function.to_string(),
         }
     }
+
+    /// JS `typeof` operator result
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Undefined => "undefined",
+            Self::Null => "object",
+            Self::Number(_) => "number",
+            Self::Boolean(_) => "boolean",
+            Self::String(_) => "string",
+            Self::Object(_) => "object",
+            Self::Array(_) => "object",
+            Self::Function(_) => "function",
+        }
+    }
 }
 
 impl VariableValue {
     pub fn add_property(&mut self, property_name: &VariableValue, property: RawPtr<VariableValue>) {
-        // TODO print runtime error
         match self {
             Self::Object(object) => object.add_property(property_name, property),
             Self::Array(array) => array.add_property(property_name, property),
-            _ => {}
+            // properties of the functions are not supported
+            Self::Function(_) => {}
+            Self::Undefined | Self::Null => exception::throw_type_error(format!(
+                "Cannot set properties of {} (setting '{}')",
+                self.to_string(),
+                property_name.to_string()
+            )),
+            _ => exception::throw_type_error(format!(
+                "Cannot create property '{}' on {} '{}'",
+                property_name.to_string(),
+                self.type_of(),
+                self.to_string()
+            )),
         }
     }
 
@@ -92,10 +138,13 @@ impl VariableValue {
         }
     }
 
-    pub fn function_call(&self, args: &mut Vec<*mut VariableValue>) -> RawPtr<VariableValue> {
+    pub fn function_call(&self, args: &[*mut VariableValue]) -> RawPtr<VariableValue> {
         if let Self::Function(function) = self {
-            function.call(args)
+            // the function could overwrite its own variable during the call,
+            // so the captured variables are kept alive by the copy
+            function.clone().call(args)
         } else {
+            exception::throw_type_error(format!("{} is not a function", self.to_string()));
             RawPtr::allocate(VariableValue::Undefined)
         }
     }
@@ -124,6 +173,50 @@ impl VariableValue {
     pub fn div(a: &VariableValue, b: &VariableValue) -> VariableValue {
         Number::div(&a.to_number(), &b.to_number()).into()
     }
+
+    pub fn rem(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::rem(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn pow(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::pow(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn neg(&self) -> VariableValue {
+        Number::neg(&self.to_number()).into()
+    }
+}
+
+// bitwise operations
+
+impl VariableValue {
+    pub fn bit_and(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::bit_and(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn bit_or(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::bit_or(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn bit_xor(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::bit_xor(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn bit_not(&self) -> VariableValue {
+        Number::bit_not(&self.to_number()).into()
+    }
+
+    pub fn shl(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::shl(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn shr(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::shr(&a.to_number(), &b.to_number()).into()
+    }
+
+    pub fn ushr(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        Number::ushr(&a.to_number(), &b.to_number()).into()
+    }
 }
 
 // logical operations
@@ -133,19 +226,35 @@ impl VariableValue {
         (!self.to_boolean()).into()
     }
 
-    pub fn and(a: &VariableValue, b: &VariableValue) -> VariableValue {
-        if a.to_boolean() {
-            b.clone()
-        } else {
-            a.clone()
+    /// Strict equality, objects, arrays and functions are equal only to themselves
+    pub fn strict_eq(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        match (a, b) {
+            (Self::Number(a), Self::Number(b)) => Number::equals(a, b).into(),
+            (Self::Object(a), Self::Object(b)) => a.is_same(b).into(),
+            (Self::Array(a), Self::Array(b)) => a.is_same(b).into(),
+            (a, b) => (a == b).into(),
         }
     }
 
-    pub fn or(a: &VariableValue, b: &VariableValue) -> VariableValue {
-        if a.to_boolean() {
-            a.clone()
-        } else {
-            b.clone()
+    /// Abstract equality, like strict equality, but the values of the different types are converted before the comparison
+    pub fn loose_eq(a: &VariableValue, b: &VariableValue) -> VariableValue {
+        match (a, b) {
+            (Self::Undefined | Self::Null, Self::Undefined | Self::Null) => true.into(),
+            (Self::Undefined | Self::Null, _) | (_, Self::Undefined | Self::Null) => false.into(),
+            (Self::Number(a), Self::String(b)) => Number::equals(a, &Number::from_string(b)).into(),
+            (Self::String(a), Self::Number(b)) => Number::equals(&Number::from_string(a), b).into(),
+            (Self::Boolean(_), b) => Self::loose_eq(&a.to_number().into(), b),
+            (a, Self::Boolean(_)) => Self::loose_eq(a, &b.to_number().into()),
+            // objects are converted to the primitive values
+            (
+                Self::Object(_) | Self::Array(_) | Self::Function(_),
+                Self::Number(_) | Self::String(_),
+            ) => Self::loose_eq(&a.to_string().into(), b),
+            (
+                Self::Number(_) | Self::String(_),
+                Self::Object(_) | Self::Array(_) | Self::Function(_),
+            ) => Self::loose_eq(a, &b.to_string().into()),
+            (a, b) => Self::strict_eq(a, b),
         }
     }
 
@@ -204,6 +313,8 @@ mod tests {
             VariableValue::String(string.clone()).to_boolean(),
             !string.is_empty()
         );
+        assert!(VariableValue::Object(Object::new()).to_boolean());
+        assert!(VariableValue::Array(Array::new()).to_boolean());
     }
 
     #[proptest]
@@ -213,7 +324,18 @@ mod tests {
         assert_eq!(VariableValue::Number(number.clone()).to_number(), number);
         assert_eq!(VariableValue::Boolean(true).to_number(), Number::Num(1.0));
         assert_eq!(VariableValue::Boolean(false).to_number(), Number::Num(0.0));
-        assert_eq!(VariableValue::String(string).to_number(), Number::NaN);
+        assert_eq!(
+            VariableValue::String(string.clone()).to_number(),
+            Number::from_string(&string)
+        );
+        assert_eq!(
+            VariableValue::String(" 5 ".to_string()).to_number(),
+            Number::Num(5.0)
+        );
+        assert_eq!(
+            VariableValue::String("abc".to_string()).to_number(),
+            Number::NaN
+        );
     }
 
     #[proptest]
@@ -287,29 +409,146 @@ mod tests {
             ),
             Number::div(&number1, &number2).into()
         );
+        assert_eq!(
+            VariableValue::rem(
+                &VariableValue::Number(number1.clone()),
+                &VariableValue::Number(number2.clone())
+            ),
+            Number::rem(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::pow(
+                &VariableValue::Number(number1.clone()),
+                &VariableValue::Number(number2.clone())
+            ),
+            Number::pow(&number1, &number2).into()
+        );
+        // strings are converted to the numbers
+        assert_eq!(
+            VariableValue::rem(
+                &VariableValue::String(string1.clone()),
+                &VariableValue::Number(number2.clone())
+            ),
+            Number::rem(&Number::from_string(&string1), &number2).into()
+        );
+        assert_eq!(
+            VariableValue::pow(
+                &VariableValue::Number(number1.clone()),
+                &VariableValue::String(string2.clone())
+            ),
+            Number::pow(&number1, &Number::from_string(&string2)).into()
+        );
+        assert_eq!(
+            VariableValue::rem(
+                &VariableValue::String("8".to_string()),
+                &VariableValue::Number(Number::Num(3.0))
+            ),
+            VariableValue::Number(Number::Num(2.0))
+        );
+        assert_eq!(
+            VariableValue::Number(number1.clone()).neg(),
+            Number::neg(&number1).into()
+        );
+        assert_eq!(
+            VariableValue::Boolean(true).neg(),
+            VariableValue::Number(Number::Num(-1.0))
+        );
+        assert_eq!(
+            VariableValue::String(string1.clone()).neg(),
+            Number::neg(&Number::from_string(&string1)).into()
+        );
+        assert_eq!(
+            VariableValue::String("5".to_string()).neg(),
+            VariableValue::Number(Number::Num(-5.0))
+        );
     }
 
     #[proptest]
-    fn not_test(boolean: bool) {
-        assert_eq!(VariableValue::Boolean(boolean).not(), (!boolean).into());
-    }
+    fn bitwise_test(number1: Number, number2: Number, string1: String, string2: String) {
+        let (a, b) = (
+            VariableValue::Number(number1.clone()),
+            VariableValue::Number(number2.clone()),
+        );
+        assert_eq!(
+            VariableValue::bit_and(&a, &b),
+            Number::bit_and(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::bit_or(&a, &b),
+            Number::bit_or(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::bit_xor(&a, &b),
+            Number::bit_xor(&number1, &number2).into()
+        );
+        assert_eq!(a.bit_not(), Number::bit_not(&number1).into());
+        assert_eq!(
+            VariableValue::shl(&a, &b),
+            Number::shl(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::shr(&a, &b),
+            Number::shr(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::ushr(&a, &b),
+            Number::ushr(&number1, &number2).into()
+        );
 
-    #[proptest]
-    fn and_test(a: bool, b: bool) {
         assert_eq!(
-            VariableValue::and(&VariableValue::Boolean(a), &VariableValue::Boolean(b)),
-            (a && b).into()
+            VariableValue::bit_or(&VariableValue::Boolean(true), &VariableValue::Null),
+            VariableValue::Number(Number::Num(1.0))
+        );
+        assert_eq!(
+            VariableValue::Undefined.bit_not(),
+            VariableValue::Number(Number::Num(-1.0))
         );
-    }
 
-    #[proptest]
-    fn or_test(a: bool, b: bool) {
+        // strings are converted to the numbers before the integers
+        let (a, b) = (
+            VariableValue::String(string1.clone()),
+            VariableValue::String(string2.clone()),
+        );
+        let (number1, number2) = (Number::from_string(&string1), Number::from_string(&string2));
+        assert_eq!(
+            VariableValue::bit_and(&a, &b),
+            Number::bit_and(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::bit_or(&a, &b),
+            Number::bit_or(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::bit_xor(&a, &b),
+            Number::bit_xor(&number1, &number2).into()
+        );
+        assert_eq!(a.bit_not(), Number::bit_not(&number1).into());
+        assert_eq!(
+            VariableValue::shl(&a, &b),
+            Number::shl(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::shr(&a, &b),
+            Number::shr(&number1, &number2).into()
+        );
         assert_eq!(
-            VariableValue::or(&VariableValue::Boolean(a), &VariableValue::Boolean(b)),
-            (a || b).into()
+            VariableValue::ushr(&a, &b),
+            Number::ushr(&number1, &number2).into()
+        );
+        assert_eq!(
+            VariableValue::shl(
+                &VariableValue::String("1".to_string()),
+                &VariableValue::Number(Number::Num(2.0))
+            ),
+            VariableValue::Number(Number::Num(4.0))
         );
     }
 
+    #[proptest]
+    fn not_test(boolean: bool) {
+        assert_eq!(VariableValue::Boolean(boolean).not(), (!boolean).into());
+    }
+
     #[proptest]
     fn gt_test(a1: String, b1: String, a2: Number, b2: Number) {
         assert_eq!(
@@ -361,4 +600,174 @@ mod tests {
             VariableValue::le(&a2.into(), &b2.into()),
         );
     }
+
+    #[test]
+    fn type_of_test() {
+        assert_eq!(VariableValue::Undefined.type_of(), "undefined");
+        assert_eq!(VariableValue::Null.type_of(), "object");
+        assert_eq!(VariableValue::Number(Number::NaN).type_of(), "number");
+        assert_eq!(VariableValue::Boolean(false).type_of(), "boolean");
+        assert_eq!(VariableValue::String(String::new()).type_of(), "string");
+        assert_eq!(VariableValue::Object(Object::new()).type_of(), "object");
+        assert_eq!(VariableValue::Array(Array::new()).type_of(), "object");
+    }
+
+    #[test]
+    fn add_property_test() {
+        let mut object = VariableValue::Object(Object::new());
+        object.add_property(
+            &"a".to_string().into(),
+            RawPtr::allocate(VariableValue::Boolean(true)),
+        );
+        assert_eq!(
+            *object.get_property(&"a".to_string().into()),
+            VariableValue::Boolean(true)
+        );
+        assert!(exception::take().is_none());
+
+        VariableValue::Undefined.add_property(
+            &"a".to_string().into(),
+            RawPtr::allocate(VariableValue::Boolean(true)),
+        );
+        let mut error = exception::take().unwrap();
+        assert_eq!(
+            *error.get_property(&"message".to_string().into()),
+            VariableValue::String("Cannot set properties of undefined (setting 'a')".to_string())
+        );
+
+        VariableValue::Number(5.0.into()).add_property(
+            &"a".to_string().into(),
+            RawPtr::allocate(VariableValue::Boolean(true)),
+        );
+        let mut error = exception::take().unwrap();
+        assert_eq!(
+            *error.get_property(&"name".to_string().into()),
+            VariableValue::String("TypeError".to_string())
+        );
+        assert_eq!(
+            *error.get_property(&"message".to_string().into()),
+            VariableValue::String("Cannot create property 'a' on number '5'".to_string())
+        );
+    }
+
+    #[test]
+    fn function_call_test() {
+        let res = VariableValue::Number(5.0.into()).function_call(&[]);
+        assert_eq!(*res, VariableValue::Undefined);
+
+        let mut error = exception::take().unwrap();
+        assert_eq!(
+            *error.get_property(&"name".to_string().into()),
+            VariableValue::String("TypeError".to_string())
+        );
+        assert_eq!(
+            *error.get_property(&"message".to_string().into()),
+            VariableValue::String("5 is not a function".to_string())
+        );
+    }
+
+    #[test]
+    fn strict_eq_test() {
+        let number = VariableValue::Number(5.0.into());
+        let string = VariableValue::String("5".to_string());
+        let nan = VariableValue::Number(Number::NaN);
+        assert_eq!(
+            VariableValue::strict_eq(&number, &number.clone()),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::strict_eq(&number, &string),
+            VariableValue::Boolean(false)
+        );
+        assert_eq!(
+            VariableValue::strict_eq(&nan, &nan),
+            VariableValue::Boolean(false)
+        );
+        assert_eq!(
+            VariableValue::strict_eq(&VariableValue::Null, &VariableValue::Undefined),
+            VariableValue::Boolean(false)
+        );
+
+        // objects are compared by the identity
+        let object1 = VariableValue::Object(Object::new());
+        let object2 = VariableValue::Object(Object::new());
+        assert_eq!(
+            VariableValue::strict_eq(&object1, &object1.clone()),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::strict_eq(&object1, &object2),
+            VariableValue::Boolean(false)
+        );
+        // the copy references the same properties
+        let mut copy = object1.clone();
+        copy.add_property(
+            &"a".to_string().into(),
+            RawPtr::allocate(VariableValue::Boolean(true)),
+        );
+        assert_eq!(
+            *object1.clone().get_property(&"a".to_string().into()),
+            VariableValue::Boolean(true)
+        );
+        let array1 = VariableValue::Array(Array::new());
+        let array2 = VariableValue::Array(Array::new());
+        assert_eq!(
+            VariableValue::strict_eq(&array1, &array1.clone()),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::strict_eq(&array1, &array2),
+            VariableValue::Boolean(false)
+        );
+    }
+
+    #[proptest]
+    fn loose_eq_test(a: f64) {
+        assert_eq!(
+            VariableValue::loose_eq(&VariableValue::Number(a.into()), &a.to_string().into()),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(&VariableValue::Null, &VariableValue::Undefined),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(&VariableValue::Null, &VariableValue::Number(0.0.into())),
+            VariableValue::Boolean(false)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(&VariableValue::Boolean(true), &"1".to_string().into()),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(
+                &VariableValue::Boolean(false),
+                &VariableValue::String(String::new())
+            ),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(
+                &VariableValue::Number(Number::NaN),
+                &VariableValue::Number(Number::NaN)
+            ),
+            VariableValue::Boolean(false)
+        );
+
+        // objects are compared by the identity
+        let object1 = VariableValue::Object(Object::new());
+        let object2 = VariableValue::Object(Object::new());
+        assert_eq!(
+            VariableValue::loose_eq(&object1, &object1.clone()),
+            VariableValue::Boolean(true)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(&object1, &object2),
+            VariableValue::Boolean(false)
+        );
+        assert_eq!(
+            VariableValue::loose_eq(&object1, &object1.to_string().into()),
+            VariableValue::Boolean(true)
+        );
+    }
 }
@@ -4,53 +4,96 @@ use std::io::Read;
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 pub enum Error {
     #[error("Read error: {0}, position: {1}")]
-    ReadError(String, Position),
+    Read(String, Position),
+    #[error("Invalid UTF-8 sequence, position: {0}")]
+    InvalidUtf8(Position),
     #[error("End of file")]
     Eof,
 }
 
+impl Error {
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::Read(_, position) | Self::InvalidUtf8(position) => Some(position),
+            Self::Eof => None,
+        }
+    }
+}
+
 pub struct CharReader<R: Read> {
-    buf: [u8; 1],
+    /// the longest UTF-8 encoded symbol takes 4 bytes
+    buf: [u8; 4],
     reader: R,
 
+    /// position of the last read symbol
     position: Position,
-    saved: Option<char>,
+    /// position of the symbol before the last read one, restored when the symbol is saved
+    prev_position: Position,
+    /// position of the next symbol in the source
+    next_position: Position,
+    saved: Option<(char, Position)>,
 }
 
 impl<R: Read> CharReader<R> {
     pub fn new(reader: R) -> Self {
         Self {
-            buf: [0],
+            buf: [0; 4],
             reader,
-            position: Position::new(1, 1),
+            position: Position::new(1, 0),
+            prev_position: Position::new(1, 0),
+            next_position: Position::new(1, 1),
             saved: None,
         }
     }
 
     pub fn get_char(&mut self) -> Result<char, Error> {
-        match self.saved {
-            Some(char) => {
-                self.saved = None;
-                Ok(char)
-            }
-            None => match self.reader.read(&mut self.buf) {
-                Ok(0) => Err(Error::Eof),
-                Ok(_) => {
-                    self.position.inc_line();
-                    if self.buf[0] as char == '\n' {
-                        self.position.inc_column();
-                    }
-                    Ok(self.buf[0].into())
-                }
-                Err(e) => Err(Error::ReadError(e.to_string(), self.position.clone())),
-            },
+        if let Some((char, position)) = self.saved.take() {
+            self.prev_position = std::mem::replace(&mut self.position, position);
+            return Ok(char);
         }
+
+        if self.read_byte(0)? == 0 {
+            return Err(Error::Eof);
+        }
+        // the sequence length is encoded in the leading byte, invalid leading bytes fail the decoding
+        let len = match self.buf[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1,
+        };
+        let mut read = 1;
+        while read < len && self.read_byte(read)? != 0 {
+            read += 1;
+        }
+
+        let char = std::str::from_utf8(&self.buf[..read])
+            .ok()
+            .and_then(|str| str.chars().next())
+            .ok_or_else(|| Error::InvalidUtf8(self.next_position.clone()))?;
+        self.prev_position = std::mem::replace(&mut self.position, self.next_position.clone());
+        if char == '\n' {
+            self.next_position.next_line();
+        } else {
+            self.next_position.next_column();
+        }
+        Ok(char)
+    }
+
+    /// Reads the next byte into the `buf[index]`, returns the amount of the read bytes
+    fn read_byte(&mut self, index: usize) -> Result<usize, Error> {
+        self.reader
+            .read(&mut self.buf[index..=index])
+            .map_err(|e| Error::Read(e.to_string(), self.next_position.clone()))
     }
 
+    /// Returns the symbol back to the reader, the position moves back to the previous symbol
     pub fn save(&mut self, char: char) {
-        self.saved = Some(char);
+        let position = std::mem::replace(&mut self.position, self.prev_position.clone());
+        self.saved = Some((char, position));
     }
 
+    /// Returns the position of the last read symbol
     pub fn get_position(&self) -> &Position {
         &self.position
     }
@@ -86,19 +129,60 @@ mod tests {
         let mut reader = CharReader::new("a \n b".as_bytes());
 
         assert_eq!(reader.get_char(), Ok('a'));
-        assert_eq!(reader.get_position(), &Position::new(2, 1));
+        assert_eq!(reader.get_position(), &Position::new(1, 1));
 
         assert_eq!(reader.get_char(), Ok(' '));
-        assert_eq!(reader.get_position(), &Position::new(3, 1));
+        assert_eq!(reader.get_position(), &Position::new(1, 2));
 
         assert_eq!(reader.get_char(), Ok('\n'));
-        assert_eq!(reader.get_position(), &Position::new(1, 2));
+        assert_eq!(reader.get_position(), &Position::new(1, 3));
 
         assert_eq!(reader.get_char(), Ok(' '));
+        assert_eq!(reader.get_position(), &Position::new(2, 1));
+
+        assert_eq!(reader.get_char(), Ok('b'));
         assert_eq!(reader.get_position(), &Position::new(2, 2));
 
+        // saved symbol is read again at the same position
+        reader.save('b');
+        assert_eq!(reader.get_position(), &Position::new(2, 1));
         assert_eq!(reader.get_char(), Ok('b'));
-        assert_eq!(reader.get_position(), &Position::new(3, 2));
+        assert_eq!(reader.get_position(), &Position::new(2, 2));
+    }
+
+    #[test]
+    fn reader_utf8_test() {
+        let mut reader = CharReader::new("añ€😀\n".as_bytes());
+
+        assert_eq!(reader.get_char(), Ok('a'));
+        assert_eq!(reader.get_char(), Ok('ñ'));
+        assert_eq!(reader.get_char(), Ok('€'));
+        assert_eq!(reader.get_char(), Ok('😀'));
+        assert_eq!(reader.get_position(), &Position::new(1, 4));
+        assert_eq!(reader.get_char(), Ok('\n'));
+        assert_eq!(reader.get_char(), Err(Error::Eof));
+
+        // invalid leading byte
+        let mut reader = CharReader::new([b'a', 0xFF].as_slice());
+        assert_eq!(reader.get_char(), Ok('a'));
+        assert_eq!(
+            reader.get_char(),
+            Err(Error::InvalidUtf8(Position::new(1, 2)))
+        );
+
+        // invalid continuation byte
+        let mut reader = CharReader::new([0xE2, 0x82, b'a'].as_slice());
+        assert_eq!(
+            reader.get_char(),
+            Err(Error::InvalidUtf8(Position::new(1, 1)))
+        );
+
+        // truncated sequence
+        let mut reader = CharReader::new([0xF0, 0x9F].as_slice());
+        assert_eq!(
+            reader.get_char(),
+            Err(Error::InvalidUtf8(Position::new(1, 1)))
+        );
     }
 
     #[test]
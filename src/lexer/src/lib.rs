@@ -7,9 +7,9 @@
 )]
 
 use char_reader::CharReader;
-pub use position::Position;
-use std::io::Read;
-pub use tokens::{Arithmetic, Keyword, Literal, Logical, Separator, Token};
+pub use position::{Position, Span};
+use std::{collections::VecDeque, io::Read};
+pub use tokens::{Arithmetic, Keyword, Literal, Logical, Separator, Template, Token};
 
 mod char_reader;
 mod position;
@@ -23,16 +23,45 @@ pub enum Error {
     ReaderError(char_reader::Error),
     #[error("Unexpected symbol: {0}, position: {1}")]
     UnexpectedSymbol(char, Position),
-    #[error("Unexpected token: {0}")]
-    UnexpectedToken(Token),
+    #[error("Unexpected token: {0}, position: {1}")]
+    UnexpectedToken(Token, Span),
+    #[error("Invalid escape sequence, position: {0}")]
+    InvalidEscapeSequence(Position),
+    #[error("Invalid number: {0}, position: {1}")]
+    InvalidNumber(String, Position),
+}
+
+impl Error {
+    /// Returns the location of the error in the source, if it is known
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::RpnError(_) => None,
+            Self::ReaderError(e) => e.position().cloned().map(Span::from),
+            Self::UnexpectedSymbol(_, position)
+            | Self::InvalidEscapeSequence(position)
+            | Self::InvalidNumber(_, position) => Some(position.clone().into()),
+            Self::UnexpectedToken(_, span) => Some(span.clone()),
+        }
+    }
 }
 
 fn is_skip(char: char) -> bool {
-    char.is_ascii_whitespace() || char.eq(&';')
+    // including the byte order mark
+    char.is_whitespace() || char == '\u{feff}'
+}
+
+fn is_id_start(char: char) -> bool {
+    unicode_ident::is_xid_start(char) || char == '$' || char == '_'
+}
+
+fn is_id_continue(char: char) -> bool {
+    // zero width non-joiner and joiner are allowed inside the identifier
+    unicode_ident::is_xid_continue(char) || char == '$' || char == '\u{200c}' || char == '\u{200d}'
 }
 
 fn can_stop(char: char) -> bool {
     is_skip(char)
+        || char.eq(&';')
         || char.eq(&'=')
         || char.eq(&'(')
         || char.eq(&')')
@@ -43,6 +72,7 @@ fn can_stop(char: char) -> bool {
         || char.eq(&',')
         || char.eq(&'.')
         || char.eq(&':')
+        || char.eq(&'?')
         || char.eq(&'&')
         || char.eq(&'|')
         || char.eq(&'!')
@@ -52,23 +82,79 @@ fn can_stop(char: char) -> bool {
         || char.eq(&'/')
         || char.eq(&'>')
         || char.eq(&'<')
+        || char.eq(&'%')
+        || char.eq(&'^')
+        || char.eq(&'~')
+}
+
+/// Removes the numeric separators "_", which are allowed only between the digits of the provided `radix`.
+/// Returns `None` if there are any other symbols
+fn remove_separators(digits: &str, radix: u32) -> Option<String> {
+    if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return None;
+    }
+    if !digits
+        .chars()
+        .all(|char| char == '_' || char.is_digit(radix))
+    {
+        return None;
+    }
+    Some(digits.replace('_', ""))
+}
+
+/// Parses the decimal number, e.g. "1_000.5e-3", ".5" or "5.", returns `None` if it is malformed
+fn parse_decimal(number: &str) -> Option<f64> {
+    let (mantissa, exponent) = match number.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (number, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    if integer.is_empty() && fraction.unwrap_or_default().is_empty() {
+        return None;
+    }
+
+    let mut res = remove_separators(integer, 10)?;
+    if let Some(fraction) = fraction {
+        res.push('.');
+        res.push_str(&remove_separators(fraction, 10)?);
+    }
+    if let Some(exponent) = exponent {
+        let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        if digits.is_empty() {
+            return None;
+        }
+        res.push('e');
+        if exponent.starts_with('-') {
+            res.push('-');
+        }
+        res.push_str(&remove_separators(digits, 10)?);
+    }
+    res.parse().ok()
 }
 
 pub struct TokenReader<R: Read> {
     char_reader: CharReader<R>,
-    // used as FIFO collection
-    saved_tokens: Vec<Vec<Token>>,
-    next_to_read_tokens: Vec<Vec<Token>>,
-    saved_flag: u8,
+    /// tokens read ahead by `peek_n`, they are returned first by `next_token`
+    peeked_tokens: VecDeque<(Token, Span)>,
+    /// span of the last token returned by `next_token`
+    span: Span,
+    /// open curly braces count inside each template substitution, which is currently read
+    template_braces: Vec<u32>,
+    /// syntax errors, which the parser has recovered from
+    errors: Vec<Error>,
 }
 
 impl<R: Read> TokenReader<R> {
     pub fn new(reader: R) -> Self {
         Self {
             char_reader: CharReader::new(reader),
-            saved_tokens: Vec::new(),
-            next_to_read_tokens: Vec::new(),
-            saved_flag: 0,
+            peeked_tokens: VecDeque::new(),
+            span: Span::default(),
+            template_braces: Vec::new(),
+            errors: Vec::new(),
         }
     }
 }
@@ -191,9 +277,9 @@ impl<R: Read> TokenReader<R> {
         Ok(res)
     }
 
-    // try read identifier: [a-zA-Z][a-zA-Z0-9_]*
+    // try read identifier: ID_Start or "$", "_" symbol, followed by ID_Continue or "$" symbols
     fn try_read_identifier(&mut self, mut char: char) -> Result<TokenResult<()>, Error> {
-        if char.is_ascii_alphabetic() || char == '_' {
+        if is_id_start(char) {
             let mut ident = char.to_string();
             loop {
                 char = match self.char_reader.get_char() {
@@ -201,7 +287,7 @@ impl<R: Read> TokenReader<R> {
                     Err(char_reader::Error::Eof) => break,
                     Err(e) => return Err(Error::ReaderError(e)),
                 };
-                if !char.is_ascii_alphanumeric() && char != '_' {
+                if !is_id_continue(char) {
                     if !can_stop(char) {
                         return Err(Error::UnexpectedSymbol(
                             char,
@@ -215,96 +301,139 @@ impl<R: Read> TokenReader<R> {
                 ident.push(char);
             }
 
-            if ident == "var" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::Var)));
-            }
-
-            if ident == "let" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::Let)));
-            }
-
-            if ident == "function" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::Function)));
-            }
-
-            if ident == "return" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::Return)));
-            }
-
-            if ident == "if" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::If)));
-            }
-
-            if ident == "else" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::Else)));
-            }
-
-            if ident == "while" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::While)));
-            }
-
-            if ident == "do" {
-                return Ok(TokenResult::Token(Token::Keyword(Keyword::Do)));
-            }
-
-            if ident == "true" {
-                return Ok(TokenResult::Token(Token::Literal(Literal::Boolean(true))));
-            }
-
-            if ident == "false" {
-                return Ok(TokenResult::Token(Token::Literal(Literal::Boolean(false))));
-            }
-
-            if ident == "undefined" {
-                return Ok(TokenResult::Token(Token::Literal(Literal::Undefined)));
-            }
-
-            if ident == "null" {
-                return Ok(TokenResult::Token(Token::Literal(Literal::Null)));
-            }
-
-            if ident == "NaN" {
-                return Ok(TokenResult::Token(Token::Literal(Literal::NaN)));
-            }
-
-            if ident == "Infinity" {
-                return Ok(TokenResult::Token(Token::Literal(Literal::Infinity)));
-            }
-
-            return Ok(TokenResult::Token(Token::Ident(ident)));
+            return Ok(TokenResult::Token(Self::ident_or_keyword(ident)));
         }
         Ok(TokenResult::Result(()))
     }
 
-    // try read number: [0-9.]+
-    fn try_read_number(&mut self, mut char: char) -> Result<TokenResult<()>, Error> {
-        if char.is_ascii_digit() {
-            let mut number = char.to_string();
-            loop {
-                char = match self.char_reader.get_char() {
-                    Ok(char) => char,
-                    Err(char_reader::Error::Eof) => break,
-                    Err(e) => return Err(Error::ReaderError(e)),
-                };
-                if !char.is_ascii_digit() && char != '.' {
-                    if !can_stop(char) {
-                        return Err(Error::UnexpectedSymbol(
-                            char,
-                            self.char_reader.get_position().clone(),
-                        ));
-                    }
+    /// Keywords and literals are reserved, other words are identifiers
+    fn ident_or_keyword(ident: String) -> Token {
+        match ident.as_str() {
+            "var" => Token::Keyword(Keyword::Var),
+            "let" => Token::Keyword(Keyword::Let),
+            "const" => Token::Keyword(Keyword::Const),
+            "function" => Token::Keyword(Keyword::Function),
+            "return" => Token::Keyword(Keyword::Return),
+            "if" => Token::Keyword(Keyword::If),
+            "else" => Token::Keyword(Keyword::Else),
+            "while" => Token::Keyword(Keyword::While),
+            "do" => Token::Keyword(Keyword::Do),
+            "for" => Token::Keyword(Keyword::For),
+            "break" => Token::Keyword(Keyword::Break),
+            "continue" => Token::Keyword(Keyword::Continue),
+            "throw" => Token::Keyword(Keyword::Throw),
+            "try" => Token::Keyword(Keyword::Try),
+            "catch" => Token::Keyword(Keyword::Catch),
+            "finally" => Token::Keyword(Keyword::Finally),
+            "switch" => Token::Keyword(Keyword::Switch),
+            "case" => Token::Keyword(Keyword::Case),
+            "default" => Token::Keyword(Keyword::Default),
+            "typeof" => Token::Keyword(Keyword::Typeof),
+            "void" => Token::Keyword(Keyword::Void),
+            "true" => Token::Literal(Literal::Boolean(true)),
+            "false" => Token::Literal(Literal::Boolean(false)),
+            "undefined" => Token::Literal(Literal::Undefined),
+            "null" => Token::Literal(Literal::Null),
+            "NaN" => Token::Literal(Literal::NaN),
+            "Infinity" => Token::Literal(Literal::Infinity),
+            _ => Token::Ident(ident),
+        }
+    }
+
+    // try read number: decimal "1_000.5e-3" or ".5", hex "0xFF", octal "0o17" or binary "0b101"
+    fn try_read_number(&mut self, char: char) -> Result<TokenResult<()>, Error> {
+        let position = self.char_reader.get_position().clone();
+        let number = match char {
+            '0' => match self.char_reader.get_char() {
+                Ok('x' | 'X') => self.read_radix_number(16, position)?,
+                Ok('o' | 'O') => self.read_radix_number(8, position)?,
+                Ok('b' | 'B') => self.read_radix_number(2, position)?,
+                Ok(char) => {
                     self.char_reader.save(char);
-                    break;
+                    self.read_decimal_number('0', position)?
+                }
+                Err(char_reader::Error::Eof) => 0_f64,
+                Err(e) => return Err(Error::ReaderError(e)),
+            },
+            '1'..='9' => self.read_decimal_number(char, position)?,
+            // leading dot, otherwise it is the separator
+            '.' => match self.char_reader.get_char() {
+                Ok(next) if next.is_ascii_digit() => {
+                    self.char_reader.save(next);
+                    self.read_decimal_number('.', position)?
                 }
+                Ok(next) => {
+                    self.char_reader.save(next);
+                    return Ok(TokenResult::Result(()));
+                }
+                Err(char_reader::Error::Eof) => return Ok(TokenResult::Result(())),
+                Err(e) => return Err(Error::ReaderError(e)),
+            },
+            _ => return Ok(TokenResult::Result(())),
+        };
+        Ok(TokenResult::Token(Token::Literal(Literal::Number(number))))
+    }
 
-                number.push(char);
+    // read the rest of the number symbols, which satisfy `is_number_symbol`, the first symbols are already read
+    fn read_number_symbols<F>(
+        &mut self,
+        mut number: String,
+        is_number_symbol: F,
+    ) -> Result<String, Error>
+    where
+        F: Fn(&str, char) -> bool,
+    {
+        loop {
+            let char = match self.char_reader.get_char() {
+                Ok(char) => char,
+                Err(char_reader::Error::Eof) => break,
+                Err(e) => return Err(Error::ReaderError(e)),
+            };
+            if !is_number_symbol(&number, char) {
+                if !can_stop(char) {
+                    return Err(Error::UnexpectedSymbol(
+                        char,
+                        self.char_reader.get_position().clone(),
+                    ));
+                }
+                self.char_reader.save(char);
+                break;
             }
-
-            return Ok(TokenResult::Token(Token::Literal(Literal::Number(
-                number.parse().expect("string should be f64 number"),
-            ))));
+            number.push(char);
         }
-        Ok(TokenResult::Result(()))
+        Ok(number)
+    }
+
+    fn read_decimal_number(&mut self, first: char, position: Position) -> Result<f64, Error> {
+        let number = self.read_number_symbols(first.to_string(), |number, char| {
+            char.is_ascii_digit()
+                || matches!(char, '_' | '.' | 'e' | 'E')
+                // exponent sign
+                || (matches!(char, '+' | '-') && number.ends_with(['e', 'E']))
+        })?;
+        parse_decimal(&number).ok_or(Error::InvalidNumber(number, position))
+    }
+
+    // read the number with the prefix "0x", "0o" or "0b", the prefix is already read
+    fn read_radix_number(&mut self, radix: u32, position: Position) -> Result<f64, Error> {
+        let digits = self.read_number_symbols(String::new(), |_, char| {
+            char.is_ascii_alphanumeric() || char == '_'
+        })?;
+        remove_separators(&digits, radix)
+            .filter(|digits| !digits.is_empty())
+            .map(|digits| {
+                digits.chars().fold(0_f64, |res, digit| {
+                    res * f64::from(radix) + f64::from(digit.to_digit(radix).unwrap_or_default())
+                })
+            })
+            .ok_or_else(|| {
+                let prefix = match radix {
+                    16 => "0x",
+                    8 => "0o",
+                    _ => "0b",
+                };
+                Error::InvalidNumber(format!("{prefix}{digits}"), position)
+            })
     }
 
     // try read assign operator
@@ -316,18 +445,55 @@ impl<R: Read> TokenReader<R> {
         }
     }
 
+    // read "==" or "===", "!=" or "!==", the first two symbols are already read
+    fn read_equality(&mut self, loose: Logical, strict: Logical) -> Result<TokenResult<()>, Error> {
+        match self.char_reader.get_char() {
+            Ok('=') => Ok(TokenResult::Token(Token::Logical(strict))),
+            Ok(char) => {
+                self.char_reader.save(char);
+                Ok(TokenResult::Token(Token::Logical(loose)))
+            }
+            Err(char_reader::Error::Eof) => Ok(TokenResult::Token(Token::Logical(loose))),
+            Err(e) => Err(Error::ReaderError(e)),
+        }
+    }
+
+    // read "&&=", "&&" or "&", the first symbol is already read
+    fn read_logical_or_bitwise(
+        &mut self,
+        first: char,
+        assign: Logical,
+        logical: Logical,
+        bitwise: Arithmetic,
+    ) -> Result<TokenResult<()>, Error> {
+        match self.char_reader.get_char() {
+            Ok(char) if char == first => {
+                self.read_variant(&[('=', Token::Logical(assign))], Token::Logical(logical))
+            }
+            Ok(char) => {
+                self.char_reader.save(char);
+                Ok(TokenResult::Token(Token::Arithmetic(bitwise)))
+            }
+            Err(char_reader::Error::Eof) => Ok(TokenResult::Token(Token::Arithmetic(bitwise))),
+            Err(e) => Err(Error::ReaderError(e)),
+        }
+    }
+
     // try read logical
     fn try_read_logical(&mut self, mut char: char) -> Result<TokenResult<()>, Error> {
         if char == '=' {
-            char = match self.char_reader.get_char() {
-                Ok(char) => char,
+            match self.char_reader.get_char() {
+                Ok('=') => return self.read_equality(Logical::Eq, Logical::SEq),
+                // arrow function, e.g. =>
+                Ok('>') => return Ok(TokenResult::Token(Token::Separator(Separator::Arrow))),
+                // assign operator
+                Ok(char) => {
+                    self.char_reader.save(char);
+                    return Ok(TokenResult::Result(()));
+                }
                 Err(char_reader::Error::Eof) => return Ok(TokenResult::Result(())),
                 Err(e) => return Err(Error::ReaderError(e)),
-            };
-            if char == '=' {
-                return Ok(TokenResult::Token(Token::Logical(Logical::Eq)));
             }
-            self.char_reader.save(char);
         }
 
         if char == '!' {
@@ -339,39 +505,63 @@ impl<R: Read> TokenReader<R> {
                 Err(e) => return Err(Error::ReaderError(e)),
             };
             if char == '=' {
-                return Ok(TokenResult::Token(Token::Logical(Logical::Ne)));
+                return self.read_equality(Logical::Ne, Logical::SNe);
             }
 
             self.char_reader.save(char);
             return Ok(TokenResult::Token(Token::Logical(Logical::Not)));
         }
         if char == '&' {
-            let postion = self.char_reader.get_position().clone();
-            char = match self.char_reader.get_char() {
-                Ok(char) => char,
-                Err(char_reader::Error::Eof) => return Err(Error::UnexpectedSymbol('&', postion)),
-                Err(e) => return Err(Error::ReaderError(e)),
-            };
-            if char == '&' {
-                return Ok(TokenResult::Token(Token::Logical(Logical::And)));
-            }
-            return Err(Error::UnexpectedSymbol('&', postion));
+            return self.read_logical_or_bitwise(
+                '&',
+                Logical::AndAssign,
+                Logical::And,
+                Arithmetic::BitAnd,
+            );
         }
         if char == '|' {
-            let postion = self.char_reader.get_position().clone();
-            char = match self.char_reader.get_char() {
-                Ok(char) => char,
-                Err(char_reader::Error::Eof) => return Err(Error::UnexpectedSymbol('|', postion)),
-                Err(e) => return Err(Error::ReaderError(e)),
+            return self.read_logical_or_bitwise(
+                '|',
+                Logical::OrAssign,
+                Logical::Or,
+                Arithmetic::BitOr,
+            );
+        }
+        if char == '?' {
+            return match self.char_reader.get_char() {
+                // only "??=" is supported, not the "??" operator
+                Ok('?') => {
+                    let position = self.char_reader.get_position().clone();
+                    match self.char_reader.get_char() {
+                        Ok('=') => Ok(TokenResult::Token(Token::Logical(Logical::NullishAssign))),
+                        Ok(_) | Err(char_reader::Error::Eof) => {
+                            Err(Error::UnexpectedSymbol('?', position))
+                        }
+                        Err(e) => Err(Error::ReaderError(e)),
+                    }
+                }
+                Ok(char) => {
+                    self.char_reader.save(char);
+                    Ok(TokenResult::Token(Token::Separator(
+                        Separator::QuestionMark,
+                    )))
+                }
+                Err(char_reader::Error::Eof) => Ok(TokenResult::Token(Token::Separator(
+                    Separator::QuestionMark,
+                ))),
+                Err(e) => Err(Error::ReaderError(e)),
             };
-            if char == '|' {
-                return Ok(TokenResult::Token(Token::Logical(Logical::Or)));
-            }
-            return Err(Error::UnexpectedSymbol('|', postion));
         }
         if char == '>' {
             match self.char_reader.get_char() {
                 Ok('=') => return Ok(TokenResult::Token(Token::Logical(Logical::Ge))),
+                // shift operators, e.g. >> or >>>
+                Ok('>') => {
+                    return self.read_variant(
+                        &[('>', Token::Arithmetic(Arithmetic::UShr))],
+                        Token::Arithmetic(Arithmetic::Shr),
+                    )
+                }
                 Ok(char) => {
                     self.char_reader.save(char);
                     return Ok(TokenResult::Token(Token::Logical(Logical::Gt)));
@@ -385,6 +575,7 @@ impl<R: Read> TokenReader<R> {
         if char == '<' {
             match self.char_reader.get_char() {
                 Ok('=') => return Ok(TokenResult::Token(Token::Logical(Logical::Le))),
+                Ok('<') => return Ok(TokenResult::Token(Token::Arithmetic(Arithmetic::Shl))),
                 Ok(char) => {
                     self.char_reader.save(char);
                     return Ok(TokenResult::Token(Token::Logical(Logical::Lt)));
@@ -399,17 +590,57 @@ impl<R: Read> TokenReader<R> {
     }
 
     // try read arithmetic
-    fn try_read_arithmetic(char: char) -> TokenResult<()> {
-        if char == '+' {
-            TokenResult::Token(Token::Arithmetic(Arithmetic::Add))
-        } else if char == '-' {
-            TokenResult::Token(Token::Arithmetic(Arithmetic::Sub))
-        } else if char == '*' {
-            TokenResult::Token(Token::Arithmetic(Arithmetic::Mul))
-        } else if char == '/' {
-            TokenResult::Token(Token::Arithmetic(Arithmetic::Div))
-        } else {
-            TokenResult::Result(())
+    fn try_read_arithmetic(&mut self, char: char) -> Result<TokenResult<()>, Error> {
+        match char {
+            '+' => self.read_variant(
+                &[
+                    ('+', Token::Arithmetic(Arithmetic::Inc)),
+                    ('=', Token::Arithmetic(Arithmetic::AddAssign)),
+                ],
+                Token::Arithmetic(Arithmetic::Add),
+            ),
+            '-' => self.read_variant(
+                &[
+                    ('-', Token::Arithmetic(Arithmetic::Dec)),
+                    ('=', Token::Arithmetic(Arithmetic::SubAssign)),
+                ],
+                Token::Arithmetic(Arithmetic::Sub),
+            ),
+            '*' => self.read_variant(
+                &[
+                    ('*', Token::Arithmetic(Arithmetic::Exp)),
+                    ('=', Token::Arithmetic(Arithmetic::MulAssign)),
+                ],
+                Token::Arithmetic(Arithmetic::Mul),
+            ),
+            '/' => self.read_variant(
+                &[('=', Token::Arithmetic(Arithmetic::DivAssign))],
+                Token::Arithmetic(Arithmetic::Div),
+            ),
+            '%' => Ok(TokenResult::Token(Token::Arithmetic(Arithmetic::Mod))),
+            '^' => Ok(TokenResult::Token(Token::Arithmetic(Arithmetic::BitXor))),
+            '~' => Ok(TokenResult::Token(Token::Arithmetic(Arithmetic::BitNot))),
+            _ => Ok(TokenResult::Result(())),
+        }
+    }
+
+    // read the token paired with the next symbol in `variants`, otherwise `single`
+    fn read_variant(
+        &mut self,
+        variants: &[(char, Token)],
+        single: Token,
+    ) -> Result<TokenResult<()>, Error> {
+        match self.char_reader.get_char() {
+            Ok(char) => {
+                if let Some((_, token)) = variants.iter().find(|(second, _)| *second == char) {
+                    Ok(TokenResult::Token(token.clone()))
+                } else {
+                    self.char_reader.save(char);
+                    Ok(TokenResult::Token(single))
+                }
+            }
+            Err(char_reader::Error::Eof) => Ok(TokenResult::Token(single)),
+            Err(e) => Err(Error::ReaderError(e)),
         }
     }
 
@@ -425,13 +656,92 @@ impl<R: Read> TokenReader<R> {
             ',' => TokenResult::Token(Token::Separator(Separator::Comma)),
             '.' => TokenResult::Token(Token::Separator(Separator::Dot)),
             ':' => TokenResult::Token(Token::Separator(Separator::Colon)),
+            ';' => TokenResult::Token(Token::Separator(Separator::SemiColon)),
             _ => TokenResult::Result(()),
         }
     }
 
-    // try read string: "<any symbol>"
+    // try read template literal part: `<any symbol>${ or }<any symbol>`
+    fn try_read_template(&mut self, char: char) -> Result<TokenResult<()>, Error> {
+        match char {
+            '`' => self.read_template_part(true),
+            '{' => {
+                if let Some(braces) = self.template_braces.last_mut() {
+                    *braces += 1;
+                }
+                Ok(TokenResult::Result(()))
+            }
+            // the substitution ends with the unpaired closing curly brace
+            '}' => match self.template_braces.last_mut() {
+                Some(0) => {
+                    self.template_braces.pop();
+                    self.read_template_part(false)
+                }
+                Some(braces) => {
+                    *braces -= 1;
+                    Ok(TokenResult::Result(()))
+                }
+                None => Ok(TokenResult::Result(())),
+            },
+            _ => Ok(TokenResult::Result(())),
+        }
+    }
+
+    // read the template part until "`" or "${", the opening "`" or "}" is already read
+    fn read_template_part(&mut self, is_head: bool) -> Result<TokenResult<()>, Error> {
+        let position = self.char_reader.get_position().clone();
+        let mut string = String::new();
+        loop {
+            let char = match self.char_reader.get_char() {
+                Ok(char) => char,
+                Err(char_reader::Error::Eof) => {
+                    return Err(Error::UnexpectedSymbol(
+                        if is_head { '`' } else { '}' },
+                        position,
+                    ))
+                }
+                Err(e) => return Err(Error::ReaderError(e)),
+            };
+            match char {
+                '`' => {
+                    let template = if is_head {
+                        Template::NoSubstitution(string)
+                    } else {
+                        Template::Tail(string)
+                    };
+                    return Ok(TokenResult::Token(Token::Template(template)));
+                }
+                '$' => match self.char_reader.get_char() {
+                    Ok('{') => {
+                        self.template_braces.push(0);
+                        let template = if is_head {
+                            Template::Head(string)
+                        } else {
+                            Template::Middle(string)
+                        };
+                        return Ok(TokenResult::Token(Token::Template(template)));
+                    }
+                    Ok(char) => {
+                        string.push('$');
+                        self.char_reader.save(char);
+                    }
+                    Err(char_reader::Error::Eof) => string.push('$'),
+                    Err(e) => return Err(Error::ReaderError(e)),
+                },
+                '\\' => {
+                    if let Some(char) = self.read_escape()? {
+                        string.push(char);
+                    }
+                }
+                char => string.push(char),
+            }
+        }
+    }
+
+    // try read string: "<any symbol>" or '<any symbol>'
     fn try_read_string(&mut self, mut char: char) -> Result<TokenResult<()>, Error> {
-        if char == '"' {
+        if char == '"' || char == '\'' {
+            let quote = char;
             let mut string = String::new();
             loop {
                 char = match self.char_reader.get_char() {
@@ -439,7 +749,7 @@ impl<R: Read> TokenReader<R> {
                     Err(char_reader::Error::Eof) => break,
                     Err(e) => return Err(Error::ReaderError(e)),
                 };
-                if char == '"' {
+                if char == quote {
                     char = match self.char_reader.get_char() {
                         Ok(char) => char,
                         Err(char_reader::Error::Eof) => break,
@@ -456,92 +766,209 @@ impl<R: Read> TokenReader<R> {
                     break;
                 }
 
-                string.push(char);
+                if char == '\\' {
+                    if let Some(char) = self.read_escape()? {
+                        string.push(char);
+                    }
+                } else {
+                    string.push(char);
+                }
             }
 
             return Ok(TokenResult::Token(Token::Literal(Literal::String(string))));
         }
         Ok(TokenResult::Result(()))
     }
-}
 
-impl<R: Read> TokenReader<R> {
-    pub fn start_saving(&mut self) -> u8 {
-        self.saved_flag += 1;
-        self.saved_tokens.push(Vec::new());
-        self.saved_flag
-    }
-
-    pub fn stop_saving(&mut self) {
-        if self.saved_flag != 0 {
-            self.saved_flag -= 1;
-            let saved = self.saved_tokens.pop().unwrap();
-            if !saved.is_empty() {
-                self.next_to_read_tokens.push(saved);
+    // read escape sequence, the backslash is already read.
+    // Returns `None` for the line continuation, which does not produce any symbol
+    fn read_escape(&mut self) -> Result<Option<char>, Error> {
+        let position = self.char_reader.get_position().clone();
+        let char = match self.char_reader.get_char() {
+            Ok(char) => char,
+            Err(char_reader::Error::Eof) => return Err(Error::InvalidEscapeSequence(position)),
+            Err(e) => return Err(Error::ReaderError(e)),
+        };
+        let char = match char {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'v' => '\u{b}',
+            '0' => '\0',
+            'x' => {
+                let code = self.read_hex_digits(2, &position)?;
+                char::from_u32(code).ok_or(Error::InvalidEscapeSequence(position))?
             }
+            'u' => {
+                let code = self.read_unicode_code(&position)?;
+                // symbols outside of the basic plane are written as the surrogate pair, e.g. \uD83D\uDE00
+                if (0xD800..0xDC00).contains(&code) {
+                    let low = match (self.char_reader.get_char(), self.char_reader.get_char()) {
+                        (Ok('\\'), Ok('u')) => self.read_unicode_code(&position)?,
+                        (Err(e), _) | (_, Err(e)) if e != char_reader::Error::Eof => {
+                            return Err(Error::ReaderError(e))
+                        }
+                        _ => return Err(Error::InvalidEscapeSequence(position)),
+                    };
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(Error::InvalidEscapeSequence(position));
+                    }
+                    let code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                    char::from_u32(code).ok_or(Error::InvalidEscapeSequence(position))?
+                } else {
+                    char::from_u32(code).ok_or(Error::InvalidEscapeSequence(position))?
+                }
+            }
+            // line continuation
+            '\n' => return Ok(None),
+            // any other symbol is escaped as is, e.g. \", \' or \\
+            char => char,
+        };
+        Ok(Some(char))
+    }
+
+    // read "XXXX" or "{X...}" unicode code point after \u
+    fn read_unicode_code(&mut self, position: &Position) -> Result<u32, Error> {
+        match self.char_reader.get_char() {
+            Ok('{') => {
+                let mut code: u32 = 0;
+                let mut digits = 0;
+                loop {
+                    match self.char_reader.get_char() {
+                        Ok('}') if digits != 0 => break,
+                        Ok(char) => match char.to_digit(16) {
+                            Some(digit) if code <= 0x0010_FFFF => {
+                                code = code * 16 + digit;
+                                digits += 1;
+                            }
+                            _ => return Err(Error::InvalidEscapeSequence(position.clone())),
+                        },
+                        Err(char_reader::Error::Eof) => {
+                            return Err(Error::InvalidEscapeSequence(position.clone()))
+                        }
+                        Err(e) => return Err(Error::ReaderError(e)),
+                    }
+                }
+                if code > 0x0010_FFFF {
+                    return Err(Error::InvalidEscapeSequence(position.clone()));
+                }
+                Ok(code)
+            }
+            Ok(char) => {
+                self.char_reader.save(char);
+                self.read_hex_digits(4, position)
+            }
+            Err(char_reader::Error::Eof) => Err(Error::InvalidEscapeSequence(position.clone())),
+            Err(e) => Err(Error::ReaderError(e)),
         }
     }
 
-    pub fn reset_saving(&mut self) {
-        if self.saved_flag != 0 {
-            self.saved_flag -= 1;
-            let saved = self.saved_tokens.pop().unwrap();
-            if self.saved_flag != 0 && !saved.is_empty() {
-                self.saved_tokens.last_mut().unwrap().extend(saved);
+    // read exactly `count` hex digits
+    fn read_hex_digits(&mut self, count: u32, position: &Position) -> Result<u32, Error> {
+        let mut code = 0;
+        for _ in 0..count {
+            let digit = match self.char_reader.get_char() {
+                Ok(char) => char.to_digit(16),
+                Err(char_reader::Error::Eof) => None,
+                Err(e) => return Err(Error::ReaderError(e)),
+            };
+            match digit {
+                Some(digit) => code = code * 16 + digit,
+                None => return Err(Error::InvalidEscapeSequence(position.clone())),
             }
         }
+        Ok(code)
+    }
+}
+
+impl<R: Read> TokenReader<R> {
+    /// Records the syntax error, which the parser has recovered from
+    pub fn add_error(&mut self, error: Error) {
+        self.errors.push(error);
+    }
+
+    /// Returns all recorded errors in the order they were added
+    pub fn take_errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.errors)
     }
 
     pub fn next_token(&mut self) -> Result<Token, Error> {
-        let token = if let Some(next_to_read) = self.next_to_read_tokens.last_mut() {
-            if next_to_read.is_empty() {
-                self.next_to_read_tokens.pop();
-                self.read_token()?
-            } else {
-                // remove first element
-                let token = next_to_read.remove(0);
-                if next_to_read.is_empty() {
-                    self.next_to_read_tokens.pop();
-                }
-                token
-            }
-        } else {
-            self.read_token()?
+        let (token, span) = match self.peeked_tokens.pop_front() {
+            Some(token) => token,
+            None => self.read_spanned_token()?,
         };
-        if self.saved_flag > 0 {
-            self.saved_tokens
-                .last_mut()
-                .expect("saved tokens should not be empty")
-                .push(token.clone());
-        }
+        self.span = span;
         Ok(token)
     }
 
+    /// Returns the next token without consuming it, the same as `peek_n(0)`
+    pub fn peek(&mut self) -> Result<&Token, Error> {
+        self.peek_n(0)
+    }
+
+    /// Returns the token `n` positions ahead of the last consumed one without consuming it,
+    /// `peek_n(0)` is the token returned by the next `next_token` call.
+    /// The lexer error is returned only once, the invalid symbols are skipped
+    pub fn peek_n(&mut self, n: usize) -> Result<&Token, Error> {
+        while self.peeked_tokens.len() <= n {
+            let token = self.read_spanned_token()?;
+            self.peeked_tokens.push_back(token);
+        }
+        Ok(&self.peeked_tokens[n].0)
+    }
+
+    /// Returns the span of the last token returned by `next_token`
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    #[cfg(test)]
     fn read_token(&mut self) -> Result<Token, Error> {
-        match self.char_reader.get_char() {
-            Ok(char) => self.try_skip(char)?.token_or_continue(|char| {
-                self.try_read_identifier(char)?.token_or_continue(|()| {
-                    self.try_read_number(char)?.token_or_continue(|()| {
-                        self.try_read_logical(char)?.token_or_continue(|()| {
-                            Self::try_read_arithmetic(char).token_or_continue(|()| {
-                                Self::try_read_assign_operator(char).token_or_continue(|()| {
-                                    Self::try_read_separator(char).token_or_continue(|()| {
-                                        self.try_read_string(char)?.token_or_continue(|()| {
-                                            Err(Error::UnexpectedSymbol(
-                                                char,
-                                                self.char_reader.get_position().clone(),
-                                            ))
-                                        })
+        self.read_spanned_token().map(|(token, _)| token)
+    }
+
+    fn read_spanned_token(&mut self) -> Result<(Token, Span), Error> {
+        let char = match self.char_reader.get_char() {
+            Ok(char) => char,
+            Err(char_reader::Error::Eof) => return Ok((Token::Eof, self.position_span())),
+            Err(e) => return Err(Error::ReaderError(e)),
+        };
+        let char = match self.try_skip(char)? {
+            TokenResult::Result(char) => char,
+            TokenResult::Token(token) => return Ok((token, self.position_span())),
+        };
+        let start = self.char_reader.get_position().clone();
+        let token = self.try_read_identifier(char)?.token_or_continue(|()| {
+            self.try_read_number(char)?.token_or_continue(|()| {
+                self.try_read_logical(char)?.token_or_continue(|()| {
+                    self.try_read_arithmetic(char)?.token_or_continue(|()| {
+                        Self::try_read_assign_operator(char).token_or_continue(|()| {
+                            self.try_read_template(char)?.token_or_continue(|()| {
+                                Self::try_read_separator(char).token_or_continue(|()| {
+                                    self.try_read_string(char)?.token_or_continue(|()| {
+                                        Err(Error::UnexpectedSymbol(
+                                            char,
+                                            self.char_reader.get_position().clone(),
+                                        ))
                                     })
                                 })
                             })
                         })
                     })
                 })
-            }),
-            Err(char_reader::Error::Eof) => Ok(Token::Eof),
-            Err(e) => Err(Error::ReaderError(e)),
-        }
+            })
+        })?;
+        Ok((
+            token,
+            Span::new(start, self.char_reader.get_position().clone()),
+        ))
+    }
+
+    /// Span of the last read symbol position, e.g. for the end of file
+    fn position_span(&self) -> Span {
+        self.char_reader.get_position().clone().into()
     }
 }
 
@@ -550,115 +977,52 @@ mod tests {
     use super::*;
 
     #[test]
-    fn token_reader_saving_test1() {
+    fn token_reader_peek_test() {
         let mut reader = TokenReader::new("name1 name2 name3 name4".as_bytes());
 
+        assert_eq!(reader.peek(), Ok(&Token::Ident("name1".to_string())));
+        assert_eq!(reader.peek(), Ok(&Token::Ident("name1".to_string())));
         assert_eq!(reader.next_token(), Ok(Token::Ident("name1".to_string())));
 
-        reader.start_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
-        reader.stop_saving();
+        assert_eq!(reader.peek_n(2), Ok(&Token::Ident("name4".to_string())));
+        assert_eq!(reader.peek_n(0), Ok(&Token::Ident("name2".to_string())));
+        assert_eq!(reader.peek_n(1), Ok(&Token::Ident("name3".to_string())));
+        assert_eq!(reader.peek_n(3), Ok(&Token::Eof));
 
         assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
+        assert_eq!(reader.peek(), Ok(&Token::Ident("name3".to_string())));
         assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
         assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
+        assert_eq!(reader.peek(), Ok(&Token::Eof));
         assert_eq!(reader.next_token(), Ok(Token::Eof));
     }
 
     #[test]
-    fn token_reader_saving_test2() {
-        let mut reader = TokenReader::new("name1 name2 name3 name4".as_bytes());
+    fn token_reader_peek_error_test() {
+        let mut reader = TokenReader::new("name1 # name2".as_bytes());
 
         assert_eq!(reader.next_token(), Ok(Token::Ident("name1".to_string())));
-
-        reader.start_saving();
-
+        assert_eq!(
+            reader.peek_n(1),
+            Err(Error::UnexpectedSymbol('#', Position::new(1, 7)))
+        );
+        assert_eq!(reader.peek(), Ok(&Token::Ident("name2".to_string())));
         assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-
-        reader.start_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
-        reader.stop_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
-        assert_eq!(reader.next_token(), Ok(Token::Eof));
-
-        reader.stop_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
         assert_eq!(reader.next_token(), Ok(Token::Eof));
     }
 
     #[test]
-    fn token_reader_saving_test3() {
-        let mut reader = TokenReader::new("name1 name2 name3 name4".as_bytes());
+    fn token_reader_errors_test() {
+        let mut reader = TokenReader::new("name1 name2".as_bytes());
+        let error = || Error::UnexpectedToken(Token::Eof, Span::default());
 
+        reader.add_error(error());
         assert_eq!(reader.next_token(), Ok(Token::Ident("name1".to_string())));
+        reader.add_error(error());
 
-        reader.start_saving();
-
+        assert_eq!(reader.take_errors(), vec![error(), error()]);
+        assert_eq!(reader.take_errors(), Vec::new());
         assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
-        reader.stop_saving();
-
-        reader.start_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
-        reader.stop_saving();
-
-        reader.start_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-
-        reader.stop_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Eof));
-    }
-
-    #[test]
-    fn token_reader_saving_test4() {
-        let mut reader = TokenReader::new("name1 name2 name3 name4 name5".as_bytes());
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name1".to_string())));
-
-        reader.start_saving();
-        reader.start_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-
-        reader.reset_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name5".to_string())));
-
-        reader.stop_saving();
-
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name2".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name3".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name4".to_string())));
-        assert_eq!(reader.next_token(), Ok(Token::Ident("name5".to_string())));
-        assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
 
     #[test]
@@ -676,4 +1040,69 @@ mod tests {
         let mut reader = TokenReader::new("// name1 \n /* name2 name3 */".as_bytes());
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
+
+    #[test]
+    fn token_reader_span_test() {
+        let mut reader = TokenReader::new("let ñame = \"str\";\n/* a */ x >>= 10".as_bytes());
+
+        let spans = [
+            (Token::Keyword(Keyword::Let), (1, 1), (1, 3)),
+            (Token::Ident("ñame".to_string()), (1, 5), (1, 8)),
+            (Token::Assign, (1, 10), (1, 10)),
+            (
+                Token::Literal(Literal::String("str".to_string())),
+                (1, 12),
+                (1, 16),
+            ),
+            (Token::Separator(Separator::SemiColon), (1, 17), (1, 17)),
+            (Token::Ident("x".to_string()), (2, 9), (2, 9)),
+            (Token::Arithmetic(Arithmetic::Shr), (2, 11), (2, 12)),
+            (Token::Assign, (2, 13), (2, 13)),
+            (Token::Literal(Literal::Number(10_f64)), (2, 15), (2, 16)),
+            (Token::Eof, (2, 16), (2, 16)),
+        ];
+        for (token, start, end) in spans {
+            assert_eq!(reader.next_token(), Ok(token));
+            assert_eq!(
+                reader.span(),
+                Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+            );
+        }
+
+        // peeked tokens keep their spans, peeking does not change the current span
+        let mut reader = TokenReader::new("name1 name2".as_bytes());
+        assert_eq!(reader.peek_n(1), Ok(&Token::Ident("name2".to_string())));
+        assert_eq!(reader.span(), Span::default());
+        assert_eq!(reader.next_token(), Ok(Token::Ident("name1".to_string())));
+        assert_eq!(
+            reader.span(),
+            Span::new(Position::new(1, 1), Position::new(1, 5))
+        );
+    }
+
+    #[test]
+    fn error_span_test() {
+        let mut reader = TokenReader::new("a\n  @".as_bytes());
+        assert_eq!(reader.next_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.next_token().unwrap_err().span(),
+            Some(Position::new(2, 3).into())
+        );
+
+        let mut reader = TokenReader::new([b'a', b' ', 0xFF].as_slice());
+        assert_eq!(reader.next_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.next_token().unwrap_err().span(),
+            Some(Position::new(1, 3).into())
+        );
+
+        assert_eq!(
+            Error::UnexpectedToken(
+                Token::Eof,
+                Span::new(Position::new(1, 1), Position::new(1, 2))
+            )
+            .span(),
+            Some(Span::new(Position::new(1, 1), Position::new(1, 2)))
+        );
+    }
 }
@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Position of the symbol in the source, both line and column start from 1
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Position {
-    line: u64,
-    column: u64,
+    pub line: u64,
+    pub column: u64,
 }
 
 impl Display for Position {
@@ -17,12 +18,39 @@ impl Position {
         Self { line, column }
     }
 
-    pub fn inc_line(&mut self) {
+    pub fn next_line(&mut self) {
         self.line += 1;
+        self.column = 1;
     }
 
-    pub fn inc_column(&mut self) {
-        self.line = 1;
+    pub fn next_column(&mut self) {
         self.column += 1;
     }
 }
+
+/// Source span from the first to the last symbol, both ends are inclusive.
+/// The default span is used for the nodes, which are not produced from the source
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{0} - {1}", self.start, self.end)
+    }
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Span of the single symbol
+impl From<Position> for Span {
+    fn from(position: Position) -> Self {
+        Self::new(position.clone(), position)
+    }
+}
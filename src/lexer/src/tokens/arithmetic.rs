@@ -10,6 +10,36 @@ pub enum Arithmetic {
     Div,
     /// arithmetic multiplication, e.g. *
     Mul,
+    /// arithmetic remainder, e.g. %
+    Mod,
+    /// arithmetic exponentiation, e.g. **
+    Exp,
+    /// bitwise and, e.g. &
+    BitAnd,
+    /// bitwise or, e.g. |
+    BitOr,
+    /// bitwise xor, e.g. ^
+    BitXor,
+    /// bitwise not, e.g. ~
+    BitNot,
+    /// left shift, e.g. <<
+    Shl,
+    /// sign-propagating right shift, e.g. >>
+    Shr,
+    /// zero-fill right shift, e.g. >>>
+    UShr,
+    /// increment, e.g. ++
+    Inc,
+    /// decrement, e.g. --
+    Dec,
+    /// addition assignment, e.g. +=
+    AddAssign,
+    /// substraction assignment, e.g. -=
+    SubAssign,
+    /// multiplication assignment, e.g. *=
+    MulAssign,
+    /// division assignment, e.g. /=
+    DivAssign,
 }
 
 impl Display for Arithmetic {
@@ -19,6 +49,21 @@ impl Display for Arithmetic {
             Self::Sub => write!(f, "Arithmetic SUB operator"),
             Self::Div => write!(f, "Arithmetic DIV operator"),
             Self::Mul => write!(f, "Arithmetic MUL operator"),
+            Self::Mod => write!(f, "Arithmetic MOD operator"),
+            Self::Exp => write!(f, "Arithmetic EXP operator"),
+            Self::BitAnd => write!(f, "Bitwise AND operator"),
+            Self::BitOr => write!(f, "Bitwise OR operator"),
+            Self::BitXor => write!(f, "Bitwise XOR operator"),
+            Self::BitNot => write!(f, "Bitwise NOT operator"),
+            Self::Shl => write!(f, "Bitwise SHL operator"),
+            Self::Shr => write!(f, "Bitwise SHR operator"),
+            Self::UShr => write!(f, "Bitwise USHR operator"),
+            Self::Inc => write!(f, "Arithmetic INC operator"),
+            Self::Dec => write!(f, "Arithmetic DEC operator"),
+            Self::AddAssign => write!(f, "Arithmetic ADD_ASSIGN operator"),
+            Self::SubAssign => write!(f, "Arithmetic SUB_ASSIGN operator"),
+            Self::MulAssign => write!(f, "Arithmetic MUL_ASSIGN operator"),
+            Self::DivAssign => write!(f, "Arithmetic DIV_ASSIGN operator"),
         }
     }
 }
@@ -26,7 +71,7 @@ impl Display for Arithmetic {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Token, TokenReader};
+    use crate::{Logical, Token, TokenReader};
 
     #[test]
     fn arithmetic_add_test() {
@@ -115,4 +160,401 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
+
+    #[test]
+    fn arithmetic_mod_test() {
+        let mut reader = TokenReader::new("%".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Mod)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a % b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Mod)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a%b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Mod)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_exp_test() {
+        let mut reader = TokenReader::new("**".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Exp)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a ** b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Exp)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a**b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Exp)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_bit_and_test() {
+        let mut reader = TokenReader::new("&".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitAnd))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a & b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitAnd))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a&b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitAnd))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_bit_or_test() {
+        let mut reader = TokenReader::new("|".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitOr))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a | b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitOr))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a|b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitOr))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_bit_xor_test() {
+        let mut reader = TokenReader::new("^".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitXor))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a ^ b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitXor))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a^b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitXor))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_shl_test() {
+        let mut reader = TokenReader::new("<<".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shl)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a << b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shl)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a<<b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shl)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_shr_test() {
+        let mut reader = TokenReader::new(">>".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shr)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a >> b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shr)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a>>b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shr)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_ushr_test() {
+        let mut reader = TokenReader::new(">>>".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::UShr)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a >>> b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::UShr)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a>>>b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::UShr)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_bit_not_test() {
+        let mut reader = TokenReader::new("~".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitNot))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("~~a".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitNot))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::BitNot))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_operators_sequence_test() {
+        let mut reader = TokenReader::new("a>>>=b >> =c&&d**-e".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::UShr)));
+        assert_eq!(reader.read_token(), Ok(Token::Assign));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Shr)));
+        assert_eq!(reader.read_token(), Ok(Token::Assign));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("c".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::And)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("d".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Exp)));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Sub)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("e".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_add_assign_test() {
+        let mut reader = TokenReader::new("+=".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::AddAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a += b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::AddAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a+=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::AddAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_sub_assign_test() {
+        let mut reader = TokenReader::new("-=".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::SubAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a -= b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::SubAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a-=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::SubAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_mul_assign_test() {
+        let mut reader = TokenReader::new("*=".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::MulAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a *= b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::MulAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a*=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::MulAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_div_assign_test() {
+        let mut reader = TokenReader::new("/=".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::DivAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a /= b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::DivAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a/=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Arithmetic(Arithmetic::DivAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arithmetic_inc_dec_test() {
+        let mut reader = TokenReader::new("++a--".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Inc)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Dec)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // the longest operator is read first, e.g. "(a++) + b"
+        let mut reader = TokenReader::new("a+++b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Inc)));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Add)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a - -b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Sub)));
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Sub)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
 }
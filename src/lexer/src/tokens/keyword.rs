@@ -6,6 +6,8 @@ pub enum Keyword {
     Var,
     /// "let"
     Let,
+    /// "const"
+    Const,
     /// "function"
     Function,
     /// "return",
@@ -18,6 +20,30 @@ pub enum Keyword {
     While,
     /// "do"
     Do,
+    /// "for"
+    For,
+    /// "break"
+    Break,
+    /// "continue"
+    Continue,
+    /// "throw"
+    Throw,
+    /// "try"
+    Try,
+    /// "catch"
+    Catch,
+    /// "finally"
+    Finally,
+    /// "switch"
+    Switch,
+    /// "case"
+    Case,
+    /// "default"
+    Default,
+    /// "typeof"
+    Typeof,
+    /// "void"
+    Void,
 }
 
 impl Display for Keyword {
@@ -25,12 +51,25 @@ impl Display for Keyword {
         match self {
             Self::Var => write!(f, "Keyword var token"),
             Self::Let => write!(f, "Keyword let token"),
+            Self::Const => write!(f, "Keyword const token"),
             Self::Function => write!(f, "Keyword function token"),
             Self::Return => write!(f, "Keyword return token"),
             Self::If => write!(f, "Keyword if token"),
             Self::Else => write!(f, "Keyword else token"),
             Self::While => write!(f, "Keyword while token"),
             Self::Do => write!(f, "Keyword do token"),
+            Self::For => write!(f, "Keyword for token"),
+            Self::Break => write!(f, "Keyword break token"),
+            Self::Continue => write!(f, "Keyword continue token"),
+            Self::Throw => write!(f, "Keyword throw token"),
+            Self::Try => write!(f, "Keyword try token"),
+            Self::Catch => write!(f, "Keyword catch token"),
+            Self::Finally => write!(f, "Keyword finally token"),
+            Self::Switch => write!(f, "Keyword switch token"),
+            Self::Case => write!(f, "Keyword case token"),
+            Self::Default => write!(f, "Keyword default token"),
+            Self::Typeof => write!(f, "Keyword typeof token"),
+            Self::Void => write!(f, "Keyword void token"),
         }
     }
 }
@@ -56,6 +95,14 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
 
+    #[test]
+    fn keyword_const_test() {
+        let mut reader = TokenReader::new("const".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Const)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
     #[test]
     fn keyword_function_test() {
         let mut reader = TokenReader::new("function".as_bytes());
@@ -103,4 +150,100 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Do)));
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
+
+    #[test]
+    fn keyword_for_test() {
+        let mut reader = TokenReader::new("for".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::For)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_break_test() {
+        let mut reader = TokenReader::new("break".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Break)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_continue_test() {
+        let mut reader = TokenReader::new("continue".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Continue)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_throw_test() {
+        let mut reader = TokenReader::new("throw".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Throw)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_try_test() {
+        let mut reader = TokenReader::new("try".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Try)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_catch_test() {
+        let mut reader = TokenReader::new("catch".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Catch)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_finally_test() {
+        let mut reader = TokenReader::new("finally".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Finally)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_switch_test() {
+        let mut reader = TokenReader::new("switch".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Switch)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_case_test() {
+        let mut reader = TokenReader::new("case".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Case)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_default_test() {
+        let mut reader = TokenReader::new("default".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Default)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_typeof_test() {
+        let mut reader = TokenReader::new("typeof".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Typeof)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn keyword_void_test() {
+        let mut reader = TokenReader::new("void".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Keyword(Keyword::Void)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
 }
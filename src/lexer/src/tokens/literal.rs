@@ -35,7 +35,7 @@ impl Display for Literal {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Error, Position, Token, TokenReader};
+    use crate::{Arithmetic, Error, Position, Separator, Token, TokenReader};
 
     #[test]
     fn literal_boolean_test() {
@@ -75,10 +75,99 @@ mod tests {
         let mut reader = TokenReader::new("1f2.145".as_bytes());
         assert_eq!(
             reader.read_token(),
-            Err(Error::UnexpectedSymbol('f', Position::new(3, 1)))
+            Err(Error::UnexpectedSymbol('f', Position::new(1, 2)))
         );
     }
 
+    #[test]
+    fn literal_number_test_2() {
+        let mut reader = TokenReader::new(
+            "0 .5 5. 1_000_000 1e3 1.5E+2 2e-3 0xFF 0XfF 0o17 0b101 0b1_0 007".as_bytes(),
+        );
+        for number in [
+            0_f64,
+            0.5,
+            5_f64,
+            1_000_000_f64,
+            1000_f64,
+            150_f64,
+            0.002,
+            255_f64,
+            255_f64,
+            15_f64,
+            5_f64,
+            2_f64,
+            7_f64,
+        ] {
+            assert_eq!(
+                reader.read_token(),
+                Ok(Token::Literal(Literal::Number(number)))
+            );
+        }
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // dot is the separator, if it is not followed by the digit
+        let mut reader = TokenReader::new("a.b[0].c".as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Dot)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::OpenSquareBracket))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::Number(0_f64)))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::CloseSquareBracket))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Dot)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("c".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // exponent sign is not the arithmetic operator
+        let mut reader = TokenReader::new("1e-1-1".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::Number(0.1)))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Arithmetic(Arithmetic::Sub)));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::Number(1_f64)))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn literal_invalid_number_test() {
+        for (input, number) in [
+            ("1.2.3", "1.2.3"),
+            ("1__0", "1__0"),
+            ("1_", "1_"),
+            ("1_.5", "1_.5"),
+            ("1e", "1e"),
+            ("1e+", "1e+"),
+            ("1e2.5", "1e2.5"),
+            ("0x", "0x"),
+            ("0xFG", "0xFG"),
+            ("0o8", "0o8"),
+            ("0b12", "0b12"),
+            ("0b_1", "0b_1"),
+        ] {
+            let mut reader = TokenReader::new(input.as_bytes());
+            assert_eq!(
+                reader.read_token(),
+                Err(Error::InvalidNumber(
+                    number.to_string(),
+                    Position::new(1, 1)
+                ))
+            );
+        }
+    }
+
     #[test]
     fn literal_string_test() {
         let mut reader = TokenReader::new(r#""Hello World__414f$$@#!@$$!%%!""#.as_bytes());
@@ -92,6 +181,97 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
 
+    #[test]
+    fn literal_single_quoted_string_test() {
+        let mut reader = TokenReader::new(r#"'Hello "World"' "it's""#.as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String(
+                r#"Hello "World""#.to_string()
+            )))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String("it's".to_string())))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn literal_string_escape_test() {
+        let mut reader = TokenReader::new(
+            r#""a\"b" 'a\'b' "\n\t\r\\\0" "\x41BC\u{44}\u{1F600}\uD83D\uDE00""#.as_bytes(),
+        );
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String("a\"b".to_string())))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String("a'b".to_string())))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String("\n\t\r\\\0".to_string())))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String(
+                "ABCD\u{1F600}\u{1F600}".to_string()
+            )))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // line continuation
+        let mut reader = TokenReader::new("\"a\\\nb\"".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String("ab".to_string())))
+        );
+    }
+
+    #[test]
+    fn literal_string_invalid_escape_test() {
+        let mut reader = TokenReader::new(r#""\x4g""#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::InvalidEscapeSequence(Position::new(1, 2)))
+        );
+
+        let mut reader = TokenReader::new(r#""ab\u12""#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::InvalidEscapeSequence(Position::new(1, 4)))
+        );
+
+        let mut reader = TokenReader::new(r#""\u{110000}""#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::InvalidEscapeSequence(Position::new(1, 2)))
+        );
+
+        let mut reader = TokenReader::new(r#""\u{}""#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::InvalidEscapeSequence(Position::new(1, 2)))
+        );
+
+        // lone surrogate
+        let mut reader = TokenReader::new(r#""\uD83D""#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::InvalidEscapeSequence(Position::new(1, 2)))
+        );
+
+        let mut reader = TokenReader::new(r#""\"#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::InvalidEscapeSequence(Position::new(1, 2)))
+        );
+    }
+
     #[test]
     fn literal_undefined_test() {
         let mut reader = TokenReader::new("undefined".as_bytes());
@@ -12,6 +12,10 @@ pub enum Logical {
     Eq,
     /// logical inequality, e.g. !=
     Ne,
+    /// logical strict equality, e.g. ===
+    SEq,
+    /// logical strict inequality, e.g. !==
+    SNe,
     /// logical greater than, e.g. >
     Gt,
     /// logical greater than or equal, e.g. >=
@@ -20,6 +24,12 @@ pub enum Logical {
     Lt,
     /// logical less than or equal, e.g. <=
     Le,
+    /// logical and assignment, e.g. &&=
+    AndAssign,
+    /// logical or assignment, e.g. ||=
+    OrAssign,
+    /// nullish coalescing assignment, e.g. ??=
+    NullishAssign,
 }
 
 impl Display for Logical {
@@ -30,10 +40,15 @@ impl Display for Logical {
             Self::Or => write!(f, "Logical OR operator"),
             Self::Eq => write!(f, "Logical EQ operator"),
             Self::Ne => write!(f, "Logical NE operator"),
+            Self::SEq => write!(f, "Logical SEQ operator"),
+            Self::SNe => write!(f, "Logical SNE operator"),
             Self::Gt => write!(f, "Logical GT operator"),
             Self::Ge => write!(f, "Logical GE operator"),
             Self::Lt => write!(f, "Logical LT operator"),
             Self::Le => write!(f, "Logical LE operator"),
+            Self::AndAssign => write!(f, "Logical AND_ASSIGN operator"),
+            Self::OrAssign => write!(f, "Logical OR_ASSIGN operator"),
+            Self::NullishAssign => write!(f, "Logical NULLISH_ASSIGN operator"),
         }
     }
 }
@@ -41,7 +56,7 @@ impl Display for Logical {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Token, TokenReader};
+    use crate::{Error, Position, Token, TokenReader};
 
     #[test]
     fn logical_negate_test() {
@@ -145,6 +160,50 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
 
+    #[test]
+    fn logical_seq_test() {
+        let mut reader = TokenReader::new("===".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::SEq)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a === b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::SEq)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a===b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::SEq)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn logical_sne_test() {
+        let mut reader = TokenReader::new("!==".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::SNe)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a !== b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::SNe)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a!==b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::SNe)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
     #[test]
     fn logical_gt_test() {
         let mut reader = TokenReader::new(">".as_bytes());
@@ -232,4 +291,91 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
+
+    #[test]
+    fn logical_and_assign_test() {
+        let mut reader = TokenReader::new("&&=".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::AndAssign)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a &&= b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::AndAssign)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a&&=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::AndAssign)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn logical_or_assign_test() {
+        let mut reader = TokenReader::new("||=".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::OrAssign)));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a ||= b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::OrAssign)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a||=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::OrAssign)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn logical_nullish_assign_test() {
+        let mut reader = TokenReader::new("??=".as_bytes());
+
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Logical(Logical::NullishAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a ??= b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Logical(Logical::NullishAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("a??=b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Logical(Logical::NullishAssign))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn logical_nullish_test() {
+        // "??" operator is not supported
+        let mut reader = TokenReader::new("a ?? b".as_bytes());
+
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::UnexpectedSymbol('?', Position::new(1, 4)))
+        );
+    }
 }
@@ -4,12 +4,14 @@ pub use literal::Literal;
 pub use logical::Logical;
 pub use separator::Separator;
 use std::fmt::Display;
+pub use template::Template;
 
 mod arithmetic;
 mod keyword;
 mod literal;
 mod logical;
 mod separator;
+mod template;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Token {
@@ -27,6 +29,8 @@ pub enum Token {
     Literal(Literal),
     /// separator token,
     Separator(Separator),
+    /// template literal part token
+    Template(Template),
     /// end of file token
     Eof,
 }
@@ -41,6 +45,7 @@ impl Display for Token {
             Self::Ident(val) => write!(f, "Ident token, val: {val}"),
             Self::Literal(val) => val.fmt(f),
             Self::Separator(val) => val.fmt(f),
+            Self::Template(val) => val.fmt(f),
             Self::Eof => write!(f, "Eof token"),
         }
     }
@@ -68,10 +73,30 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Ident("_name_1".to_string())));
         assert_eq!(reader.read_token(), Ok(Token::Eof));
 
-        let mut reader = TokenReader::new("name^2name".as_bytes());
+        let mut reader = TokenReader::new("name@2name".as_bytes());
         assert_eq!(
             reader.read_token(),
-            Err(Error::UnexpectedSymbol('^', Position::new(6, 1)))
+            Err(Error::UnexpectedSymbol('@', Position::new(1, 5)))
+        );
+    }
+
+    #[test]
+    fn token_unicode_ident_test() {
+        let mut reader = TokenReader::new("$name ñame 变量 x\u{200d}y".as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("$name".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("ñame".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("变量".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Ident("x\u{200d}y".to_string()))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("\u{feff}name\u{a0}€".as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("name".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::UnexpectedSymbol('€', Position::new(1, 7)))
         );
     }
 
@@ -91,11 +116,11 @@ mod tests {
 
     #[test]
     fn token_unexpected_symbol_test() {
-        let mut reader = TokenReader::new("^".as_bytes());
+        let mut reader = TokenReader::new("@".as_bytes());
 
         assert_eq!(
             reader.read_token(),
-            Err(Error::UnexpectedSymbol('^', Position::new(2, 1)))
+            Err(Error::UnexpectedSymbol('@', Position::new(1, 1)))
         );
         assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
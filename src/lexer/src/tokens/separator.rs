@@ -22,6 +22,10 @@ pub enum Separator {
     Colon,
     /// ";"
     SemiColon,
+    /// "=>"
+    Arrow,
+    /// "?"
+    QuestionMark,
 }
 
 impl Display for Separator {
@@ -37,6 +41,8 @@ impl Display for Separator {
             Self::Dot => write!(f, r#"Separator token, ".""#),
             Self::Colon => write!(f, r#"Separator token, ":""#),
             Self::SemiColon => write!(f, r#"Separator token, ";""#),
+            Self::Arrow => write!(f, r#"Separator token, "=>""#),
+            Self::QuestionMark => write!(f, r#"Separator token, "?""#),
         }
     }
 }
@@ -44,7 +50,7 @@ impl Display for Separator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Token, TokenReader};
+    use crate::{Literal, Logical, Token, TokenReader};
 
     #[test]
     fn separator_test_1() {
@@ -153,5 +159,84 @@ mod tests {
         assert_eq!(reader.read_token(), Ok(Token::Ident("name".to_string())));
         assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Colon)));
         assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new(r#"name;"#.as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("name".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::SemiColon))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn arrow_test() {
+        let mut reader = TokenReader::new("a => b".as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Arrow)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("()=>{}".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::OpenBrace))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::CloseBrace))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Arrow)));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::OpenCurlyBrace))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::CloseCurlyBrace))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // assign is not consumed by the arrow lookahead
+        let mut reader = TokenReader::new("a =!b".as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Assign));
+        assert_eq!(reader.read_token(), Ok(Token::Logical(Logical::Not)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+    }
+
+    #[test]
+    fn question_mark_test() {
+        let mut reader = TokenReader::new("a?b:c".as_bytes());
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::QuestionMark))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Colon)));
+        assert_eq!(reader.read_token(), Ok(Token::Ident("c".to_string())));
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new(r#"true ? "a" : 1"#.as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::Boolean(true)))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::QuestionMark))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::String("a".to_string())))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Separator(Separator::Colon)));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Literal(Literal::Number(1_f64)))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
     }
 }
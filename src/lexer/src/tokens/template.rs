@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+/// Template literal part, the substitutions between the parts are read as the regular tokens
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Template {
+    /// template without substitutions, the whole literal between the backticks
+    NoSubstitution(String),
+    /// template beginning, from the opening backtick to the first "${"
+    Head(String),
+    /// template part between two substitutions, from "}" to "${"
+    Middle(String),
+    /// template ending, from "}" to the closing backtick
+    Tail(String),
+}
+
+impl Display for Template {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSubstitution(val) => write!(f, "Template token, val: {val}"),
+            Self::Head(val) => write!(f, "Template head token, val: {val}"),
+            Self::Middle(val) => write!(f, "Template middle token, val: {val}"),
+            Self::Tail(val) => write!(f, "Template tail token, val: {val}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Position, Separator, Token, TokenReader};
+
+    #[test]
+    fn template_test() {
+        let mut reader = TokenReader::new("`Hello World`".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::NoSubstitution(
+                "Hello World".to_string()
+            )))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("`Hello\n $World \\` \\${}`".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::NoSubstitution(
+                "Hello\n $World ` ${}".to_string()
+            )))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        let mut reader = TokenReader::new("`Hello".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Err(Error::UnexpectedSymbol('`', Position::new(1, 1)))
+        );
+    }
+
+    #[test]
+    fn template_substitution_test() {
+        let mut reader = TokenReader::new("`a${b}c${ {} }d`".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Head("a".to_string())))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("b".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Middle("c".to_string())))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::OpenCurlyBrace))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::CloseCurlyBrace))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Tail("d".to_string())))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // nested template
+        let mut reader = TokenReader::new("`${`${a}`}`".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Head(String::new())))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Head(String::new())))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Ident("a".to_string())));
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Tail(String::new())))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::Tail(String::new())))
+        );
+        assert_eq!(reader.read_token(), Ok(Token::Eof));
+
+        // closing curly brace outside of the template
+        let mut reader = TokenReader::new("}`a`".as_bytes());
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Separator(Separator::CloseCurlyBrace))
+        );
+        assert_eq!(
+            reader.read_token(),
+            Ok(Token::Template(Template::NoSubstitution("a".to_string())))
+        );
+    }
+}
@@ -7,6 +7,7 @@
 )]
 
 use collections::set::Set;
+use lexer::Span;
 use std::{
     fmt::{Debug, Display},
     hash::Hash,
@@ -16,6 +17,46 @@ use std::{
 pub enum Error<T> {
     #[error("Undefined variable identifier {0}")]
     UndefinedVariable(T),
+    #[error("Undefined label {0}")]
+    UndefinedLabel(T),
+    #[error("Break or continue statement outside of the loop, position: {0}")]
+    OutsideOfLoop(Span),
+    #[error("Assignment to constant variable {0}")]
+    ConstantAssigment(T),
+    #[error("Missing initializer in const declaration {0}")]
+    MissingInitializer(T),
+    #[error("Cannot access {0} before initialization")]
+    UninitializedVariable(T),
+    #[error("Invalid left-hand side in assignment, position: {0}")]
+    InvalidAssigmentTarget(Span),
+}
+
+#[derive(Debug)]
+struct FunctionScope<T> {
+    /// variables count before the function arguments
+    outer_variables_len: usize,
+    /// variables count at the beginning of the function body
+    variables_len: usize,
+    loops_len: usize,
+    tries_len: usize,
+    /// variables declared outside of the function, which are used inside it
+    captured: Vec<(T, u32)>,
+}
+
+#[derive(Debug)]
+struct TryScope {
+    variables_len: usize,
+    loops_len: usize,
+    /// jumps out of the block with the finally block pass through it
+    has_finally: bool,
+}
+
+#[derive(Debug)]
+struct LoopScope<T> {
+    label: Option<T>,
+    variables_len: usize,
+    /// switch statement could be exited by the `break`, but not by the `continue`
+    is_switch: bool,
 }
 
 /// Precompiler - validate the exisitng AST tree, prepare data for the compiler
@@ -25,8 +66,23 @@ where
     T1: Clone + Hash + PartialEq + Eq + Display,
 {
     variables: Set<T1>,
+    /// predefined functions are accessible from everywhere, so they are never captured
+    predefined_len: usize,
+    /// functions, which are currently precompiled
+    function_scopes: Vec<FunctionScope<T1>>,
+    /// loops, which are currently precompiled
+    loop_scopes: Vec<LoopScope<T1>>,
+    /// try blocks, which are currently precompiled
+    try_scopes: Vec<TryScope>,
+    /// "let" and "const" variables, which are declared in the currently precompiled blocks
+    /// but are not initialized yet, with their indexes and positions in the variables stack
+    uninitialized: Vec<(T1, u32, usize)>,
 
     function_declarations: Vec<T2>,
+    /// amount of anonymous functions, used to generate their unique names
+    anonymous_functions_len: u32,
+    /// undefined variables, which are collected to be reported all at once
+    errors: Vec<Error<T1>>,
 }
 
 impl<T1, T2> Precompiler<T1, T2>
@@ -37,9 +93,17 @@ where
     where
         Iter: Iterator<Item = T1>,
     {
+        let variables: Set<T1> = predefined_functions.collect();
         Self {
-            variables: predefined_functions.collect(),
+            predefined_len: variables.len(),
+            variables,
+            function_scopes: Vec::new(),
+            loop_scopes: Vec::new(),
+            try_scopes: Vec::new(),
+            uninitialized: Vec::new(),
             function_declarations: Vec::new(),
+            anonymous_functions_len: 0,
+            errors: Vec::new(),
         }
     }
 
@@ -47,10 +111,101 @@ where
         self.variables.insert(variable)
     }
 
+    pub fn insert_const_variable(&mut self, variable: T1) -> u32 {
+        self.variables.insert_immutable(variable)
+    }
+
+    /// Declares the block scoped variable at the beginning of its block, so the functions of the block could capture it,
+    /// and returns its index. Accessing it before `initialize_variable` is called is an error,
+    /// unless it is shadowed or accessed inside of the function declared after it
+    pub fn declare_uninitialized_variable(&mut self, variable: T1, is_const: bool) -> u32 {
+        let position = self.variables.len();
+        let index = if is_const {
+            self.variables.insert_immutable(variable.clone())
+        } else {
+            self.variables.insert(variable.clone())
+        };
+        self.uninitialized.push((variable, index, position));
+        index
+    }
+
+    /// Marks the block scoped variable as initialized, returns its index if it was declared by `declare_uninitialized_variable`
+    pub fn initialize_variable(&mut self, variable: &T1) -> Option<u32> {
+        let pos = self
+            .uninitialized
+            .iter()
+            .rposition(|(name, ..)| name == variable)?;
+        Some(self.uninitialized.remove(pos).1)
+    }
+
+    /// Returns the variable index. Variable declared outside of the currently precompiled functions
+    /// is marked as captured by each of them
     pub fn get_variable(&mut self, variable: T1) -> Result<u32, Error<T1>> {
-        self.variables
-            .get(&variable)
-            .ok_or(Error::UndefinedVariable(variable))
+        if let Some((_, index, position)) = self
+            .uninitialized
+            .iter()
+            .rev()
+            .find(|(name, ..)| name == &variable)
+        {
+            let is_shadowed = self.variables.get(&variable) != Some(*index);
+            // the function declared after the variable could be called only after its initialization
+            let is_deferred = self
+                .function_scopes
+                .last()
+                .is_some_and(|scope| scope.outer_variables_len > *position);
+            if !is_shadowed && !is_deferred {
+                return Err(Error::UninitializedVariable(variable));
+            }
+        }
+
+        let Some(index) = self.variables.get(&variable) else {
+            return Err(Error::UndefinedVariable(variable));
+        };
+
+        if self
+            .variables
+            .contains_last_added(&variable, self.variables.len() - self.predefined_len)
+        {
+            for scope in self.function_scopes.iter_mut().rev() {
+                if self.variables.contains_last_added(
+                    &variable,
+                    self.variables.len() - scope.outer_variables_len,
+                ) {
+                    break;
+                }
+                let captured = (variable.clone(), index);
+                if !scope.captured.contains(&captured) {
+                    scope.captured.push(captured);
+                }
+            }
+        }
+        Ok(index)
+    }
+
+    /// Records the undefined variable error, so the precompilation could continue and all of them are reported at once.
+    /// The placeholder index 0 is returned for it, other errors are returned as is
+    pub fn collect_undefined_variable(&mut self, error: Error<T1>) -> Result<u32, Error<T1>> {
+        match error {
+            Error::UndefinedVariable(_) => {
+                self.errors.push(error);
+                Ok(0)
+            }
+            error => Err(error),
+        }
+    }
+
+    /// Returns all collected errors in the order they were found
+    pub fn take_errors(&mut self) -> Vec<Error<T1>> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Returns the index of the variable, which is the target of the assignment
+    pub fn get_mutable_variable(&mut self, variable: T1) -> Result<u32, Error<T1>> {
+        let index = self.get_variable(variable.clone())?;
+        if self.variables.is_mutable(&variable) == Some(false) {
+            return Err(Error::ConstantAssigment(variable));
+        }
+        Ok(index)
     }
 
     pub fn remove_last_added_variables(&mut self, size: usize) -> Vec<(T1, u32)> {
@@ -61,10 +216,198 @@ where
         self.variables.len()
     }
 
+    /// Enters the function body, declares the function arguments and returns their indexes.
+    /// Arguments are owned by the caller, so they are not the part of the function scope
+    pub fn enter_function_scope(&mut self, args: Vec<T1>) -> Vec<u32> {
+        let outer_variables_len = self.variables.len();
+        let args = args
+            .into_iter()
+            .map(|arg| self.variables.insert(arg))
+            .collect();
+        self.function_scopes.push(FunctionScope {
+            outer_variables_len,
+            variables_len: self.variables.len(),
+            loops_len: self.loop_scopes.len(),
+            tries_len: self.try_scopes.len(),
+            captured: Vec::new(),
+        });
+        args
+    }
+
+    /// Exits the function body, removes the function arguments
+    /// and returns all variables captured by the function
+    pub fn exit_function_scope(&mut self) -> Vec<(T1, u32)> {
+        match self.function_scopes.pop() {
+            Some(scope) => {
+                self.variables
+                    .remove_last_added(self.variables.len() - scope.outer_variables_len);
+                scope.captured
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns all variables declared inside the current function body, in the reverse order
+    pub fn get_function_scope_variables(&self) -> Vec<(T1, u32)> {
+        match self.function_scopes.last() {
+            Some(scope) => self
+                .variables
+                .get_last_added(self.variables.len() - scope.variables_len),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn enter_loop_scope(&mut self, label: Option<T1>) {
+        self.loop_scopes.push(LoopScope {
+            label,
+            variables_len: self.variables.len(),
+            is_switch: false,
+        });
+    }
+
+    pub fn exit_loop_scope(&mut self) {
+        self.loop_scopes.pop();
+    }
+
+    pub fn enter_switch_scope(&mut self) {
+        self.loop_scopes.push(LoopScope {
+            label: None,
+            variables_len: self.variables.len(),
+            is_switch: true,
+        });
+    }
+
+    pub fn exit_switch_scope(&mut self) {
+        self.loop_scopes.pop();
+    }
+
+    /// Returns the depth of the target loop, counted from the innermost one.
+    /// Loops outside of the current function are not visible, `span` of the statement is used for the error
+    pub fn get_loop_depth(&self, label: Option<T1>, span: Span) -> Result<usize, Error<T1>> {
+        let loops = self.get_function_loops();
+        match label {
+            Some(label) => loops
+                .iter()
+                .rev()
+                .position(|scope| scope.label.as_ref() == Some(&label))
+                .ok_or(Error::UndefinedLabel(label)),
+            None if loops.is_empty() => Err(Error::OutsideOfLoop(span)),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the depth of the loop targeted by the `continue`, counted from the innermost one.
+    /// Unlike the `break`, the `continue` skips the switch statements
+    pub fn get_continue_depth(&self, label: Option<T1>, span: Span) -> Result<usize, Error<T1>> {
+        match label {
+            Some(label) => self.get_loop_depth(Some(label), span),
+            None => self
+                .get_function_loops()
+                .iter()
+                .rev()
+                .position(|scope| !scope.is_switch)
+                .ok_or(Error::OutsideOfLoop(span)),
+        }
+    }
+
+    /// Returns all variables declared inside the loop with the provided depth, in the reverse order,
+    /// split by the finally blocks crossed by the jump out of the loop, see `split_by_finally_blocks`
+    pub fn get_loop_scope_variables(&self, depth: usize) -> Vec<Vec<(T1, u32)>> {
+        let loops_len = self.loop_scopes.len() - 1 - depth;
+        self.split_by_finally_blocks(self.loop_scopes[loops_len].variables_len, |scope| {
+            scope.loops_len > loops_len
+        })
+    }
+
+    /// Returns all variables declared inside the current function body, in the reverse order,
+    /// split by the finally blocks crossed by the `return`, see `split_by_finally_blocks`
+    pub fn get_return_scope_variables(&self) -> Vec<Vec<(T1, u32)>> {
+        let variables_len = self
+            .function_scopes
+            .last()
+            .map_or(self.variables.len(), |scope| scope.variables_len);
+        self.split_by_finally_blocks(variables_len, |_| true)
+    }
+
+    /// Splits the variables declared after the `variables_len` by the finally blocks of the `crossed` try blocks.
+    /// The first part is deallocated before the jump into the innermost finally block,
+    /// each next part after the corresponding finally block, so the finally blocks could use them
+    fn split_by_finally_blocks(
+        &self,
+        variables_len: usize,
+        crossed: impl Fn(&TryScope) -> bool,
+    ) -> Vec<Vec<(T1, u32)>> {
+        let mut variables = self
+            .variables
+            .get_last_added(self.variables.len() - variables_len)
+            .into_iter();
+        let mut len = self.variables.len();
+        let mut parts = Vec::new();
+        for scope in self
+            .get_function_tries()
+            .iter()
+            .rev()
+            .filter(|scope| scope.has_finally)
+            .take_while(|scope| crossed(scope))
+        {
+            parts.push(variables.by_ref().take(len - scope.variables_len).collect());
+            len = scope.variables_len;
+        }
+        parts.push(variables.collect());
+        parts
+    }
+
+    fn get_function_loops(&self) -> &[LoopScope<T1>] {
+        let loops_len = self
+            .function_scopes
+            .last()
+            .map_or(0, |scope| scope.loops_len);
+        &self.loop_scopes[loops_len..]
+    }
+
+    /// Enters the try block or the catch block of the try statement, `has_finally` is set if the statement has the finally block
+    pub fn enter_try_scope(&mut self, has_finally: bool) {
+        self.try_scopes.push(TryScope {
+            variables_len: self.variables.len(),
+            loops_len: self.loop_scopes.len(),
+            has_finally,
+        });
+    }
+
+    pub fn exit_try_scope(&mut self) {
+        self.try_scopes.pop();
+    }
+
+    /// Returns all variables, which should be deallocated when an exception is thrown, in the reverse order.
+    /// Those are the variables declared inside the innermost try block of the current function,
+    /// otherwise all variables of the function body
+    pub fn get_exception_scope_variables(&self) -> Vec<(T1, u32)> {
+        match self.get_function_tries().last() {
+            Some(scope) => self
+                .variables
+                .get_last_added(self.variables.len() - scope.variables_len),
+            None => self.get_function_scope_variables(),
+        }
+    }
+
+    fn get_function_tries(&self) -> &[TryScope] {
+        let tries_len = self
+            .function_scopes
+            .last()
+            .map_or(0, |scope| scope.tries_len);
+        &self.try_scopes[tries_len..]
+    }
+
     pub fn insert_function_declaration(&mut self, function_declaration: T2) {
         self.function_declarations.push(function_declaration);
     }
 
+    /// Returns the unique index for the new anonymous function
+    pub fn get_anonymous_function_index(&mut self) -> u32 {
+        self.anonymous_functions_len += 1;
+        self.anonymous_functions_len - 1
+    }
+
     pub fn get_function_declarations(self) -> Vec<T2> {
         self.function_declarations
     }
@@ -101,4 +444,404 @@ mod tests {
         );
         assert_eq!(precompiler.variables_len(), 0);
     }
+
+    #[test]
+    fn precompiler_collect_errors_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(
+            precompiler.collect_undefined_variable(Error::UndefinedVariable("var1".to_string())),
+            Ok(0)
+        );
+        assert_eq!(
+            precompiler.collect_undefined_variable(Error::ConstantAssigment("var2".to_string())),
+            Err(Error::ConstantAssigment("var2".to_string()))
+        );
+        assert_eq!(
+            precompiler.collect_undefined_variable(Error::UndefinedVariable("var3".to_string())),
+            Ok(0)
+        );
+
+        assert_eq!(
+            precompiler.take_errors(),
+            vec![
+                Error::UndefinedVariable("var1".to_string()),
+                Error::UndefinedVariable("var3".to_string())
+            ]
+        );
+        assert_eq!(precompiler.take_errors(), vec![]);
+    }
+
+    #[test]
+    fn precompiler_const_variables_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        assert_eq!(precompiler.insert_const_variable("var2".to_string()), 0);
+
+        assert_eq!(precompiler.get_mutable_variable("var1".to_string()), Ok(0));
+        assert_eq!(precompiler.get_variable("var2".to_string()), Ok(0));
+        assert_eq!(
+            precompiler.get_mutable_variable("var2".to_string()),
+            Err(Error::ConstantAssigment("var2".to_string()))
+        );
+        assert_eq!(
+            precompiler.get_mutable_variable("var3".to_string()),
+            Err(Error::UndefinedVariable("var3".to_string()))
+        );
+
+        // constant is shadowed by the inner variable
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 1);
+        assert_eq!(precompiler.get_mutable_variable("var2".to_string()), Ok(1));
+        precompiler.remove_last_added_variables(1);
+        assert_eq!(
+            precompiler.get_mutable_variable("var2".to_string()),
+            Err(Error::ConstantAssigment("var2".to_string()))
+        );
+    }
+
+    #[test]
+    fn precompiler_uninitialized_variables_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        assert_eq!(
+            precompiler.declare_uninitialized_variable("var1".to_string(), false),
+            1
+        );
+        assert_eq!(
+            precompiler.declare_uninitialized_variable("var2".to_string(), true),
+            0
+        );
+        assert_eq!(
+            precompiler.get_variable("var1".to_string()),
+            Err(Error::UninitializedVariable("var1".to_string()))
+        );
+        assert_eq!(
+            precompiler.get_variable("var2".to_string()),
+            Err(Error::UninitializedVariable("var2".to_string()))
+        );
+
+        // uninitialized variable is shadowed by the function argument
+        precompiler.enter_function_scope(vec!["var1".to_string()]);
+        assert_eq!(precompiler.get_variable("var1".to_string()), Ok(2));
+        precompiler.exit_function_scope();
+
+        assert_eq!(
+            precompiler.initialize_variable(&"var1".to_string()),
+            Some(1)
+        );
+        assert_eq!(precompiler.get_variable("var1".to_string()), Ok(1));
+        assert_eq!(
+            precompiler.get_variable("var2".to_string()),
+            Err(Error::UninitializedVariable("var2".to_string()))
+        );
+        assert_eq!(
+            precompiler.initialize_variable(&"var2".to_string()),
+            Some(0)
+        );
+        assert_eq!(precompiler.get_variable("var2".to_string()), Ok(0));
+        assert_eq!(precompiler.initialize_variable(&"var2".to_string()), None);
+    }
+
+    #[test]
+    fn precompiler_uninitialized_variables_function_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        // "function f() { return var1; } let var1 = 1;", the function is called after the initialization
+        assert_eq!(
+            precompiler.declare_uninitialized_variable("var1".to_string(), false),
+            0
+        );
+        precompiler.enter_function_scope(vec![]);
+        assert_eq!(precompiler.get_variable("var1".to_string()), Ok(0));
+        assert_eq!(
+            precompiler.exit_function_scope(),
+            vec![("var1".to_string(), 0)]
+        );
+        assert_eq!(
+            precompiler.get_variable("var1".to_string()),
+            Err(Error::UninitializedVariable("var1".to_string()))
+        );
+
+        // the variable declared inside of the function is checked
+        precompiler.enter_function_scope(vec![]);
+        assert_eq!(
+            precompiler.declare_uninitialized_variable("var2".to_string(), false),
+            0
+        );
+        assert_eq!(
+            precompiler.get_variable("var2".to_string()),
+            Err(Error::UninitializedVariable("var2".to_string()))
+        );
+        precompiler.exit_function_scope();
+    }
+
+    #[test]
+    fn precompiler_function_scope_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(precompiler.get_function_scope_variables(), vec![]);
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+
+        assert_eq!(
+            precompiler.enter_function_scope(vec!["arg1".to_string()]),
+            vec![0]
+        );
+        assert_eq!(precompiler.get_function_scope_variables(), vec![]);
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 1);
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 0);
+        assert_eq!(
+            precompiler.get_function_scope_variables(),
+            vec![("var2".to_string(), 0), ("var1".to_string(), 1)]
+        );
+
+        assert_eq!(
+            precompiler.enter_function_scope(Vec::new()),
+            Vec::<u32>::new()
+        );
+        assert_eq!(precompiler.insert_variable("var3".to_string()), 0);
+        assert_eq!(
+            precompiler.get_function_scope_variables(),
+            vec![("var3".to_string(), 0)]
+        );
+        precompiler.remove_last_added_variables(1);
+        assert_eq!(precompiler.exit_function_scope(), vec![]);
+
+        assert_eq!(
+            precompiler.get_function_scope_variables(),
+            vec![("var2".to_string(), 0), ("var1".to_string(), 1)]
+        );
+        precompiler.remove_last_added_variables(2);
+        assert_eq!(precompiler.exit_function_scope(), vec![]);
+
+        assert_eq!(precompiler.get_function_scope_variables(), vec![]);
+        assert_eq!(precompiler.variables_len(), 1);
+    }
+
+    #[test]
+    fn precompiler_captured_variables_test() {
+        let mut precompiler =
+            Precompiler::<String, String>::new(vec!["predefined".to_string()].into_iter());
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 0);
+
+        precompiler.enter_function_scope(vec!["var2".to_string()]);
+        assert_eq!(precompiler.insert_variable("var3".to_string()), 0);
+
+        precompiler.enter_function_scope(Vec::new());
+        assert_eq!(precompiler.get_variable("predefined".to_string()), Ok(0));
+        assert_eq!(precompiler.get_variable("var1".to_string()), Ok(0));
+        assert_eq!(precompiler.get_variable("var1".to_string()), Ok(0));
+        assert_eq!(precompiler.get_variable("var2".to_string()), Ok(1));
+        assert_eq!(precompiler.get_variable("var3".to_string()), Ok(0));
+        assert_eq!(
+            precompiler.exit_function_scope(),
+            vec![
+                ("var1".to_string(), 0),
+                ("var2".to_string(), 1),
+                ("var3".to_string(), 0)
+            ]
+        );
+
+        // variables captured by the inner function are captured by the outer one as well
+        precompiler.remove_last_added_variables(1);
+        assert_eq!(
+            precompiler.exit_function_scope(),
+            vec![("var1".to_string(), 0)]
+        );
+        assert_eq!(precompiler.variables_len(), 3);
+    }
+
+    #[test]
+    fn precompiler_anonymous_function_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(precompiler.get_anonymous_function_index(), 0);
+        assert_eq!(precompiler.get_anonymous_function_index(), 1);
+        assert_eq!(precompiler.get_anonymous_function_index(), 2);
+    }
+
+    #[test]
+    fn precompiler_loop_scope_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(
+            precompiler.get_loop_depth(None, Span::default()),
+            Err(Error::OutsideOfLoop(Span::default()))
+        );
+
+        precompiler.enter_loop_scope(Some("outer".to_string()));
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        precompiler.enter_loop_scope(None);
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 0);
+
+        assert_eq!(precompiler.get_loop_depth(None, Span::default()), Ok(0));
+        assert_eq!(
+            precompiler.get_loop_scope_variables(0),
+            vec![vec![("var2".to_string(), 0)]]
+        );
+        assert_eq!(
+            precompiler.get_loop_depth(Some("outer".to_string()), Span::default()),
+            Ok(1)
+        );
+        assert_eq!(
+            precompiler.get_loop_scope_variables(1),
+            vec![vec![("var2".to_string(), 0), ("var1".to_string(), 0)]]
+        );
+        assert_eq!(
+            precompiler.get_loop_depth(Some("label".to_string()), Span::default()),
+            Err(Error::UndefinedLabel("label".to_string()))
+        );
+
+        // loops are not visible inside the function
+        precompiler.enter_function_scope(Vec::new());
+        assert_eq!(
+            precompiler.get_loop_depth(None, Span::default()),
+            Err(Error::OutsideOfLoop(Span::default()))
+        );
+        assert_eq!(
+            precompiler.get_loop_depth(Some("outer".to_string()), Span::default()),
+            Err(Error::UndefinedLabel("outer".to_string()))
+        );
+        precompiler.exit_function_scope();
+
+        precompiler.remove_last_added_variables(1);
+        precompiler.exit_loop_scope();
+        assert_eq!(precompiler.get_loop_depth(None, Span::default()), Ok(0));
+        assert_eq!(
+            precompiler.get_loop_scope_variables(0),
+            vec![vec![("var1".to_string(), 0)]]
+        );
+        precompiler.remove_last_added_variables(1);
+        precompiler.exit_loop_scope();
+        assert_eq!(
+            precompiler.get_loop_depth(None, Span::default()),
+            Err(Error::OutsideOfLoop(Span::default()))
+        );
+    }
+
+    #[test]
+    fn precompiler_try_scope_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        assert_eq!(precompiler.get_exception_scope_variables(), vec![]);
+
+        precompiler.enter_try_scope(false);
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 0);
+        assert_eq!(
+            precompiler.get_exception_scope_variables(),
+            vec![("var2".to_string(), 0)]
+        );
+
+        // try blocks are not visible inside the function
+        precompiler.enter_function_scope(vec!["arg1".to_string()]);
+        assert_eq!(precompiler.insert_variable("var3".to_string()), 0);
+        assert_eq!(
+            precompiler.get_exception_scope_variables(),
+            vec![("var3".to_string(), 0)]
+        );
+        precompiler.enter_try_scope(false);
+        assert_eq!(precompiler.get_exception_scope_variables(), vec![]);
+        precompiler.exit_try_scope();
+        precompiler.remove_last_added_variables(1);
+        precompiler.exit_function_scope();
+
+        assert_eq!(
+            precompiler.get_exception_scope_variables(),
+            vec![("var2".to_string(), 0)]
+        );
+        precompiler.remove_last_added_variables(1);
+        precompiler.exit_try_scope();
+        assert_eq!(precompiler.get_exception_scope_variables(), vec![]);
+    }
+
+    #[test]
+    fn precompiler_finally_scope_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        precompiler.enter_function_scope(Vec::new());
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        precompiler.enter_try_scope(true);
+        precompiler.enter_loop_scope(None);
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 0);
+        precompiler.enter_try_scope(true);
+        assert_eq!(precompiler.insert_variable("var3".to_string()), 0);
+        // the try block without the finally block does not split the variables
+        precompiler.enter_try_scope(false);
+        assert_eq!(precompiler.insert_variable("var4".to_string()), 0);
+
+        assert_eq!(
+            precompiler.get_loop_scope_variables(0),
+            vec![
+                vec![("var4".to_string(), 0), ("var3".to_string(), 0)],
+                vec![("var2".to_string(), 0)],
+            ]
+        );
+        assert_eq!(
+            precompiler.get_return_scope_variables(),
+            vec![
+                vec![("var4".to_string(), 0), ("var3".to_string(), 0)],
+                vec![("var2".to_string(), 0)],
+                vec![("var1".to_string(), 0)],
+            ]
+        );
+
+        precompiler.remove_last_added_variables(2);
+        precompiler.exit_try_scope();
+        precompiler.exit_try_scope();
+        assert_eq!(
+            precompiler.get_loop_scope_variables(0),
+            vec![vec![("var2".to_string(), 0)]]
+        );
+        assert_eq!(
+            precompiler.get_return_scope_variables(),
+            vec![vec![("var2".to_string(), 0)], vec![("var1".to_string(), 0)]]
+        );
+
+        // try blocks are not visible inside the function
+        precompiler.enter_function_scope(Vec::new());
+        assert_eq!(precompiler.get_return_scope_variables(), vec![vec![]]);
+        precompiler.exit_function_scope();
+
+        precompiler.remove_last_added_variables(1);
+        precompiler.exit_loop_scope();
+        precompiler.exit_try_scope();
+        precompiler.remove_last_added_variables(1);
+        precompiler.exit_function_scope();
+    }
+
+    #[test]
+    fn precompiler_switch_scope_test() {
+        let mut precompiler = Precompiler::<String, String>::new(std::iter::empty());
+
+        precompiler.enter_switch_scope();
+        assert_eq!(precompiler.get_loop_depth(None, Span::default()), Ok(0));
+        assert_eq!(
+            precompiler.get_continue_depth(None, Span::default()),
+            Err(Error::OutsideOfLoop(Span::default()))
+        );
+        precompiler.exit_switch_scope();
+
+        precompiler.enter_loop_scope(Some("label".to_string()));
+        assert_eq!(precompiler.insert_variable("var1".to_string()), 0);
+        precompiler.enter_switch_scope();
+        assert_eq!(precompiler.insert_variable("var2".to_string()), 0);
+
+        assert_eq!(precompiler.get_loop_depth(None, Span::default()), Ok(0));
+        assert_eq!(precompiler.get_continue_depth(None, Span::default()), Ok(1));
+        assert_eq!(
+            precompiler.get_continue_depth(Some("label".to_string()), Span::default()),
+            Ok(1)
+        );
+        assert_eq!(
+            precompiler.get_loop_scope_variables(1),
+            vec![vec![("var2".to_string(), 0), ("var1".to_string(), 0)]]
+        );
+
+        precompiler.remove_last_added_variables(2);
+        precompiler.exit_switch_scope();
+        precompiler.exit_loop_scope();
+    }
 }
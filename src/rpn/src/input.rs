@@ -1,5 +1,10 @@
 pub trait Priority {
     fn priority(&self) -> u8;
+
+    /// Right associative operations with the same priority are grouped from the right, e.g. `a ** b ** c`
+    fn is_right_associative(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -58,24 +58,21 @@ impl<V, UnaryOpType, BinaryOpType: Priority> RPN<V, UnaryOpType, BinaryOpType> {
                         self.result.push(Value::Operation(Operation::PostfixOp(op)));
                     }
                     Operation::BinaryOp(op1) => {
-                        let last = self.stack.pop();
-                        if let Some(last) = last {
+                        // move the operations, which should be evaluated before, to the result
+                        while let Some(last) = self.stack.pop() {
                             match last {
                                 InputExpression::Value(Value::Operation(Operation::BinaryOp(
                                     op2,
-                                ))) => match op2.priority().cmp(&op1.priority()) {
-                                    Ordering::Equal | Ordering::Greater => {
-                                        self.result
-                                            .push(Value::Operation(Operation::BinaryOp(op2)));
-                                    }
-                                    Ordering::Less => self.stack.push(InputExpression::Value(
-                                        Value::Operation(Operation::BinaryOp(op2)),
-                                    )),
-                                },
+                                ))) if Self::is_evaluated_before(&op2, &op1) => {
+                                    self.result.push(Value::Operation(Operation::BinaryOp(op2)));
+                                }
                                 InputExpression::Value(Value::Operation(Operation::PrefixOp(
                                     op2,
                                 ))) => self.result.push(Value::Operation(Operation::PrefixOp(op2))),
-                                last => self.stack.push(last),
+                                last => {
+                                    self.stack.push(last);
+                                    break;
+                                }
                             }
                         }
                         self.stack.push(InputExpression::Value(Value::Operation(
@@ -102,6 +99,15 @@ impl<V, UnaryOpType, BinaryOpType: Priority> RPN<V, UnaryOpType, BinaryOpType> {
         Ok(())
     }
 
+    /// Returns true if the operation from the stack should be evaluated before the new operation
+    fn is_evaluated_before(stack_op: &BinaryOpType, new_op: &BinaryOpType) -> bool {
+        match stack_op.priority().cmp(&new_op.priority()) {
+            Ordering::Greater => true,
+            Ordering::Equal => !new_op.is_right_associative(),
+            Ordering::Less => false,
+        }
+    }
+
     pub fn finish(mut self) -> Result<Self, Error> {
         while let Some(expr) = self.stack.pop() {
             match expr {
@@ -159,6 +165,8 @@ mod tests {
         Sub,
         // *
         Mul,
+        // **
+        Pow,
     }
 
     #[derive(Debug, PartialEq)]
@@ -174,8 +182,13 @@ mod tests {
             match self {
                 Self::Sum | Self::Sub => 0,
                 Self::Mul => 1,
+                Self::Pow => 2,
             }
         }
+
+        fn is_right_associative(&self) -> bool {
+            matches!(self, Self::Pow)
+        }
     }
 
     #[test]
@@ -233,6 +246,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn priority_test() {
+        // 1 - 2 * 3 + 4
+        let mut rpn = RPN::new();
+        for expr in [
+            InputExpression::<i32, UnOp, BinOp>::Value(Value::Value(1)),
+            InputExpression::Value(Value::Operation(Operation::BinaryOp(BinOp::Sub))),
+            InputExpression::Value(Value::Value(2)),
+            InputExpression::Value(Value::Operation(Operation::BinaryOp(BinOp::Mul))),
+            InputExpression::Value(Value::Value(3)),
+            InputExpression::Value(Value::Operation(Operation::BinaryOp(BinOp::Sum))),
+            InputExpression::Value(Value::Value(4)),
+        ] {
+            rpn.build(expr).unwrap();
+        }
+
+        rpn = rpn.finish().unwrap();
+        // 1 2 3 × - 4 +
+        assert_eq!(
+            rpn.result,
+            vec![
+                Value::<i32, UnOp, BinOp>::Value(1),
+                Value::<i32, UnOp, BinOp>::Value(2),
+                Value::<i32, UnOp, BinOp>::Value(3),
+                Value::<i32, UnOp, BinOp>::Operation(Operation::BinaryOp(BinOp::Mul)),
+                Value::<i32, UnOp, BinOp>::Operation(Operation::BinaryOp(BinOp::Sub)),
+                Value::<i32, UnOp, BinOp>::Value(4),
+                Value::<i32, UnOp, BinOp>::Operation(Operation::BinaryOp(BinOp::Sum)),
+            ]
+        );
+    }
+
+    #[test]
+    fn right_associative_test() {
+        // 1 ** 2 ** 3 * 4
+        let mut rpn = RPN::new();
+        for expr in [
+            InputExpression::<i32, UnOp, BinOp>::Value(Value::Value(1)),
+            InputExpression::Value(Value::Operation(Operation::BinaryOp(BinOp::Pow))),
+            InputExpression::Value(Value::Value(2)),
+            InputExpression::Value(Value::Operation(Operation::BinaryOp(BinOp::Pow))),
+            InputExpression::Value(Value::Value(3)),
+            InputExpression::Value(Value::Operation(Operation::BinaryOp(BinOp::Mul))),
+            InputExpression::Value(Value::Value(4)),
+        ] {
+            rpn.build(expr).unwrap();
+        }
+
+        rpn = rpn.finish().unwrap();
+        // 1 2 3 ** ** 4 ×
+        assert_eq!(
+            rpn.result,
+            vec![
+                Value::<i32, UnOp, BinOp>::Value(1),
+                Value::<i32, UnOp, BinOp>::Value(2),
+                Value::<i32, UnOp, BinOp>::Value(3),
+                Value::<i32, UnOp, BinOp>::Operation(Operation::BinaryOp(BinOp::Pow)),
+                Value::<i32, UnOp, BinOp>::Operation(Operation::BinaryOp(BinOp::Pow)),
+                Value::<i32, UnOp, BinOp>::Value(4),
+                Value::<i32, UnOp, BinOp>::Operation(Operation::BinaryOp(BinOp::Mul)),
+            ]
+        );
+    }
+
     #[test]
     fn evaluate_test() {
         let mut rpn = RPN::new();
@@ -0,0 +1,41 @@
+// modulo has the sign of the dividend
+assert_eq(7 % 3, 1);
+assert_eq(-7 % 3, -1);
+assert_eq(7.5 % 2, 1.5);
+assert_eq(1 % 0, NaN);
+assert_eq("8" % 3, 2);
+assert_eq(8 % "3", 2);
+
+// exponentiation is right associative
+assert_eq(2 ** 10, 1024);
+assert_eq(2 ** 3 ** 2, 512);
+assert_eq(2 * 3 ** 2, 18);
+assert_eq(1 ** Infinity, NaN);
+assert_eq("2" ** "3", 8);
+
+// operands are converted to 32-bit integers
+assert_eq(5 & 3, 1);
+assert_eq(5 | 3, 7);
+assert_eq(5 ^ 3, 6);
+assert_eq(~5, -6);
+assert_eq(~~3.7, 3);
+assert_eq(4294967297 | 0, 1);
+assert_eq(2147483648 | 0, -2147483648);
+assert_eq(NaN | 0, 0);
+assert_eq(1 | 2 & 3, 3);
+// strings are converted to the numbers first
+assert_eq("5" & 3, 1);
+assert_eq("5" | "3", 7);
+assert_eq(~"5", -6);
+assert_eq("abc" | 0, 0);
+
+// only the lowest 5 bits of the shift count are used
+assert_eq(1 << 3, 8);
+assert_eq(1 << 33, 2);
+assert_eq(-16 >> 2, -4);
+assert_eq(-1 >>> 0, 4294967295);
+assert_eq(-16 >>> 28, 15);
+assert_eq(1 << 2 + 1, 8);
+assert_eq("1" << 2, 4);
+assert_eq("-16" >> "2", -4);
+assert_eq("-1" >>> 0, 4294967295);
@@ -0,0 +1,54 @@
+function makeCounter() {
+  var count = 0;
+  function increment() {
+    count = count + 1;
+    return count;
+  }
+  return increment;
+}
+
+var counter = makeCounter();
+assert_eq(counter(), 1);
+assert_eq(counter(), 2);
+assert_eq(counter(), 3);
+
+// each call creates a new environment
+var counter2 = makeCounter();
+assert_eq(counter2(), 1);
+assert_eq(counter(), 4);
+
+// variables are captured by reference
+var offset = 10;
+function addOffset(x) {
+  return x + offset;
+}
+function apply(callback, value) {
+  return callback(value);
+}
+assert_eq(apply(addOffset, 5), 15);
+offset = 20;
+assert_eq(apply(addOffset, 5), 25);
+
+// recursion
+function factorial(n) {
+  if (n < 2) {
+    return 1;
+  }
+  return n * factorial(n - 1);
+}
+assert_eq(factorial(5), 120);
+
+// nested functions
+function outer() {
+  var a = 1;
+  function middle() {
+    function inner() {
+      a = a + 1;
+      return a;
+    }
+    return inner();
+  }
+  middle();
+  return middle();
+}
+assert_eq(outer(), 3);
@@ -0,0 +1,46 @@
+var a = true ? 1 : 2;
+assert_eq(a, 1);
+
+var b = 0 ? "yes" : "no";
+assert_eq(b, "no");
+
+// lower priority than the binary operators
+var c = 1 + 1 == 2 ? 10 : 20;
+assert_eq(c, 10);
+
+var d = false || 0 ? 1 : 2 + 3;
+assert_eq(d, 5);
+
+// right associativity
+function sign(value) {
+  return value > 0 ? "positive" : value < 0 ? "negative" : "zero";
+}
+assert_eq(sign(5), "positive");
+assert_eq(sign(0 - 5), "negative");
+assert_eq(sign(0), "zero");
+
+var e = true ? false ? 1 : 2 : 3;
+assert_eq(e, 2);
+
+// inside the braces
+var f = (c == 10 ? 1 : 2) + 100;
+assert_eq(f, 101);
+
+// only the chosen branch is evaluated
+var calls = 0;
+function count(value) {
+  calls = calls + 1;
+  return value;
+}
+var g = true ? count(1) : count(2);
+assert_eq(g, 1);
+assert_eq(calls, 1);
+var h = false ? count(1) : count(2);
+assert_eq(h, 2);
+assert_eq(calls, 2);
+
+// objects are shared
+var obj = { value: 1 };
+var same = true ? obj : null;
+same.value = 2;
+assert_eq(obj.value, 2);
@@ -0,0 +1,31 @@
+const a = 5;
+assert_eq(a, 5);
+assert_eq(a + 1, 6);
+
+// the object itself could be changed
+const obj = { value: 1 };
+obj.value = 2;
+obj.value += 3;
+assert_eq(obj.value, 5);
+const arr = [1, 2];
+arr[0]++;
+assert_eq(arr[0], 2);
+
+// inner declaration shadows the constant
+function shadow(a) {
+  a = a + 1;
+  return a;
+}
+assert_eq(shadow(1), 2);
+assert_eq(a, 5);
+
+{
+  var b = a;
+  b = b * 2;
+  assert_eq(b, 10);
+}
+
+for (const i = 0; i < 1; ) {
+  assert_eq(i, 0);
+  break;
+}
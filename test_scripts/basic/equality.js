@@ -0,0 +1,64 @@
+// abstract equality converts the operands
+assert(1 == "1");
+assert("1.5" == 1.5);
+assert(0 == "");
+assert(true == 1);
+assert(false == "0");
+assert(null == undefined);
+assert(undefined == null);
+assert(!(null == 0));
+assert(!(undefined == false));
+assert(!(NaN == NaN));
+assert("abc" != 1);
+
+// strict equality does not convert the operands
+assert(1 === 1);
+assert("a" === "a");
+assert(!(1 === "1"));
+assert(1 !== "1");
+assert(!(null === undefined));
+assert(null !== undefined);
+assert(!(true === 1));
+assert(!(NaN === NaN));
+assert(NaN !== NaN);
+
+// objects, arrays and functions are equal only to themselves
+var obj1 = { value: 1 };
+var obj2 = { value: 1 };
+var obj3 = obj1;
+assert(obj1 == obj3);
+assert(obj1 === obj3);
+assert(obj1 != obj2);
+assert(obj1 !== obj2);
+
+// the same object is changed through any of its references
+obj3.value = 2;
+assert_eq(obj1.value, 2);
+obj1.other = 3;
+assert_eq(obj3.other, 3);
+assert(obj1 === obj3);
+
+var arr1 = [1, 2];
+var arr2 = [1, 2];
+var arr3 = arr1;
+assert(arr1 == arr3);
+assert(arr1 === arr3);
+assert(arr1 != arr2);
+assert(arr1 !== arr2);
+
+arr3[0] = 5;
+assert_eq(arr1[0], 5);
+arr1[2] = 3;
+assert_eq(arr3.length, 3);
+assert(arr1 === arr3);
+
+function foo() {
+  return 1;
+}
+var bar = foo;
+assert(foo == bar);
+assert(foo === bar);
+
+// priority is the same as of the abstract equality
+assert(1 + 1 === 2);
+assert((1 === 1) == true);
@@ -0,0 +1,174 @@
+// throw and catch
+var caught = 0;
+try {
+  throw 5;
+  caught = 1;
+} catch (e1) {
+  caught = e1;
+}
+assert_eq(caught, 5);
+
+// exception is propagated from the called functions
+function fail(value) {
+  var local = value;
+  throw local;
+}
+function callFail(value) {
+  fail(value);
+  return 1;
+}
+try {
+  callFail("error");
+} catch (e2) {
+  assert_eq(e2, "error");
+}
+
+// finally is executed on both paths
+var steps = 0;
+try {
+  steps = steps + 1;
+} finally {
+  steps = steps + 10;
+}
+assert_eq(steps, 11);
+
+try {
+  try {
+    fail(1);
+  } finally {
+    steps = steps + 100;
+  }
+} catch (e3) {
+  steps = steps + e3;
+}
+assert_eq(steps, 112);
+
+// rethrow from the catch block
+try {
+  try {
+    fail(2);
+  } catch (e4) {
+    throw e4 + 1;
+  } finally {
+    steps = steps + 1000;
+  }
+} catch (e5) {
+  assert_eq(e5, 3);
+}
+assert_eq(steps, 1112);
+
+// calling a non-function value throws a TypeError
+var notFunction = 5;
+try {
+  notFunction();
+} catch (e6) {
+  assert_eq(e6.name, "TypeError");
+  assert_eq(e6.message, "5 is not a function");
+}
+
+// catch without the exception variable
+try {
+  throw null;
+} catch {
+  caught = true;
+}
+assert(caught);
+
+// return, break and continue pass through the finally block
+var log = "";
+function returnFromTry() {
+  try {
+    log = log + "t";
+    return 1;
+  } finally {
+    log = log + "f";
+  }
+  return 2;
+}
+assert_eq(returnFromTry(), 1);
+assert_eq(log, "tf");
+
+function returnFromCatch() {
+  try {
+    fail(3);
+  } catch (e7) {
+    return e7;
+  } finally {
+    log = log + "c";
+  }
+  return 0;
+}
+assert_eq(returnFromCatch(), 3);
+assert_eq(log, "tfc");
+
+function returnFromNested() {
+  var value = "n";
+  try {
+    try {
+      return value;
+    } finally {
+      log = log + "1";
+    }
+  } finally {
+    log = log + "2";
+  }
+}
+assert_eq(returnFromNested(), "n");
+assert_eq(log, "tfc12");
+
+var iterations = 0;
+for (let i = 0; i < 10; i++) {
+  try {
+    if (i == 2) {
+      continue;
+    }
+    if (i == 5) {
+      break;
+    }
+  } finally {
+    iterations = iterations + 1;
+  }
+  iterations = iterations + 2;
+}
+assert_eq(iterations, 14);
+
+var exits = 0;
+outer: for (let j = 0; j < 3; j++) {
+  try {
+    for (let k = 0; k < 3; k++) {
+      try {
+        break outer;
+      } finally {
+        exits = exits + 1;
+      }
+    }
+  } finally {
+    exits = exits + 10;
+  }
+}
+assert_eq(exits, 11);
+
+// the jump inside of the try block does not enter the finally block
+try {
+  for (let l = 0; l < 3; l++) {
+    break;
+  }
+} finally {
+  exits = exits + 100;
+}
+assert_eq(exits, 111);
+
+// setting a property of a primitive value throws a TypeError
+var primitive = 5;
+try {
+  primitive.field = 1;
+} catch (e8) {
+  assert_eq(e8.name, "TypeError");
+  assert_eq(e8.message, "Cannot create property 'field' on number '5'");
+}
+var missing;
+try {
+  missing.field += 1;
+} catch (e9) {
+  assert_eq(e9.message, "Cannot set properties of undefined (setting 'field')");
+}
@@ -0,0 +1,45 @@
+var add = function (a, b) {
+  return a + b;
+};
+assert_eq(add(1, 2), 3);
+
+let mul = (a, b) => a * b;
+assert_eq(mul(2, 3), 6);
+
+let identity = a => a;
+assert_eq(identity("hello"), "hello");
+
+let empty = () => {};
+assert_eq(empty(), undefined);
+
+let block = (a) => {
+  var b = a + 1;
+  return b * 2;
+};
+assert_eq(block(1), 4);
+
+// callbacks
+function apply(callback, value) {
+  return callback(value);
+}
+assert_eq(apply(x => x * 2, 5), 10);
+assert_eq(apply(function (x) { return x + 1; }, 5), 6);
+
+// closures
+function makeAdder(a) {
+  return b => a + b;
+}
+var add5 = makeAdder(5);
+assert_eq(add5(1), 6);
+
+let count = 0;
+let increment = () => {
+  count = count + 1;
+  return count;
+};
+increment();
+increment();
+assert_eq(count, 2);
+
+var obj = {func: x => x + 1};
+assert_eq(obj.func(1), 2);
@@ -48,3 +48,15 @@ var a = {func: bar};
 assert_eq(a.func(), undefined);
 assert_eq(a.func(5), 5);
 
+
+function baz(a) {
+  var b = {val: a};
+  if (a) {
+    var c = b.val;
+    return c;
+  }
+  return b;
+}
+assert_eq(baz(5), 5);
+var res = baz(0);
+assert_eq(res.val, 0);
@@ -59,3 +59,94 @@ function foo() {
     return a;
 }
 assert_eq(foo(), 1);
+
+var a = 0;
+for (var i = 0; i != 10; i = i + 1) {
+    a = a + i;
+}
+assert_eq(a, 45);
+
+var a = 0;
+for (;a != 10;) {
+    a = a + 1;
+}
+assert_eq(a, 10);
+
+var i = 5;
+for (let i = 0; i != 3; i = i + 1) {
+    var b = i;
+}
+assert_eq(i, 5);
+
+var a = 0;
+for (a = 5; false; a = a + 1) {
+    a = 0;
+}
+assert_eq(a, 5);
+
+function foo() {
+    for (var i = 0; i != 10; i = i + 1) {
+        if (i == 3) {
+            return i;
+        }
+    }
+    return 0;
+}
+assert_eq(foo(), 3);
+
+var a = 0;
+while (true) {
+    a = a + 1;
+    if (a == 5) {
+        break;
+    }
+}
+assert_eq(a, 5);
+
+var a = 0;
+var b = 0;
+for (var i = 0; i != 10; i = i + 1) {
+    var c = i;
+    if (i > 4) {
+        continue;
+    }
+    a = a + c;
+}
+assert_eq(a, 10);
+
+var a = 0;
+do {
+    a = a + 1;
+    if (a < 3) {
+        continue;
+    }
+    break;
+} while (true);
+assert_eq(a, 3);
+
+var a = 0;
+outer: for (var i = 0; i != 3; i = i + 1) {
+    for (var j = 0; j != 3; j = j + 1) {
+        if (j == 1) {
+            continue outer;
+        }
+        if (i == 2) {
+            break outer;
+        }
+        a = a + 1;
+    }
+}
+assert_eq(a, 2);
+
+function foo() {
+    var a = 0;
+    while (true) {
+        var b = a;
+        a = a + 1;
+        if (b == 3) {
+            break;
+        }
+    }
+    return a;
+}
+assert_eq(foo(), 4);
@@ -0,0 +1,12 @@
+assert_eq(0xFF, 255);
+assert_eq(0Xff, 255);
+assert_eq(0o17, 15);
+assert_eq(0b101, 5);
+assert_eq(1_000_000, 1000000);
+assert_eq(1e3, 1000);
+assert_eq(1.5E+2, 150);
+assert_eq(25e-2, 0.25);
+assert_eq(.5, 0.5);
+assert_eq(5., 5);
+assert_eq(0x10 + 0b1 - 0o1, 16);
+assert_eq(1e400, Infinity);
@@ -0,0 +1,64 @@
+// "var" is visible in the whole function
+if (true) {
+  var a = 1;
+}
+assert_eq(a, 1);
+
+for (var i = 0; i < 3; i++) {
+  var last = i;
+}
+assert_eq(i, 3);
+assert_eq(last, 2);
+
+// "var" is hoisted and initialized to undefined
+assert_eq(b, undefined);
+var b = 2;
+assert_eq(b, 2);
+
+function hoisted(flag) {
+  if (flag) {
+    var res = "set";
+  }
+  return res;
+}
+assert_eq(hoisted(true), "set");
+assert_eq(hoisted(false), undefined);
+
+function redeclared() {
+  var c = 1;
+  {
+    var c = 2;
+  }
+  return c;
+}
+assert_eq(redeclared(), 2);
+
+// "var" with the same name as the argument keeps its value
+function argument(d) {
+  var d;
+  return d;
+}
+assert_eq(argument(3), 3);
+
+// "let" is visible only inside the block
+let e = 1;
+{
+  let e = 2;
+  assert_eq(e, 2);
+}
+assert_eq(e, 1);
+
+while (true) {
+  let f = e + 1;
+  assert_eq(f, 2);
+  break;
+}
+
+// "let" and "const" could be used by the function declared before them,
+// if it is called after the initialization
+function later() {
+  return g + h;
+}
+let g = 3;
+const h = 4;
+assert_eq(later(), 7);
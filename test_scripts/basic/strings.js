@@ -0,0 +1,18 @@
+assert_eq('hello', "hello");
+assert_eq('say "hi"', "say \"hi\"");
+assert_eq("it's", 'it\'s');
+assert_eq("a\\b", 'a' + "\\" + 'b');
+
+// escapes produce the same symbols as the literal ones
+assert_eq("line 1\nline 2", `line 1
+line 2`);
+assert_eq("\x41B\u{43}", "ABC");
+assert_eq(`A${"\t"}`, "A\u0009");
+assert_eq("a\
+b", "ab");
+assert_eq("\u{1F600}", "😀");
+
+// unicode identifiers
+let ñame = "ñ";
+let 变量 = ñame + "€";
+assert_eq(变量, "ñ€");
@@ -0,0 +1,151 @@
+// matching case with break
+var a = 2;
+var res = 0;
+switch (a) {
+  case 1:
+    res = 1;
+    break;
+  case 2:
+    res = 2;
+    break;
+  case 3:
+    res = 3;
+    break;
+}
+assert_eq(res, 2);
+
+// fall-through into the next cases
+res = 0;
+switch (a) {
+  case 1:
+    res = res + 1;
+  case 2:
+    res = res + 2;
+  case 3:
+    res = res + 3;
+}
+assert_eq(res, 5);
+
+// default case in the middle is used only when nothing matches
+res = 0;
+switch (10) {
+  case 1:
+    res = 1;
+  default:
+    res = res + 100;
+  case 2:
+    res = res + 2;
+}
+assert_eq(res, 102);
+
+res = 0;
+switch (1) {
+  case 1:
+    res = 1;
+  default:
+    res = res + 100;
+    break;
+  case 2:
+    res = res + 2;
+}
+assert_eq(res, 101);
+
+// case tests use the strict equality
+res = 0;
+switch (1) {
+  case "1":
+    res = 1;
+    break;
+  case true:
+    res = 2;
+    break;
+  case 1:
+    res = 3;
+    break;
+}
+assert_eq(res, 3);
+
+res = 0;
+switch (NaN) {
+  case NaN:
+    res = 1;
+    break;
+  default:
+    res = 2;
+}
+assert_eq(res, 2);
+
+// objects are compared by identity
+var obj = { value: 1 };
+var same = obj;
+res = 0;
+switch (same) {
+  case { value: 1 }:
+    res = 1;
+    break;
+  case obj:
+    res = 2;
+    break;
+}
+assert_eq(res, 2);
+
+// case tests are evaluated lazily in order
+var calls = 0;
+function next(value) {
+  calls = calls + 1;
+  return value;
+}
+switch (2) {
+  case next(1):
+    break;
+  case next(2):
+    break;
+  case next(3):
+    break;
+}
+assert_eq(calls, 2);
+
+// case variables
+function describe(value) {
+  switch (value) {
+    case "a":
+      var first = "first";
+      return first;
+    case "b":
+      var second = "second";
+      return second;
+    default:
+      return "unknown";
+  }
+}
+assert_eq(describe("a"), "first");
+assert_eq(describe("b"), "second");
+assert_eq(describe("c"), "unknown");
+
+// continue inside the switch continues the loop
+var i = 0;
+var sum = 0;
+while (i != 5) {
+  i = i + 1;
+  switch (i) {
+    case 2:
+      continue;
+    case 4:
+      break;
+    default:
+      sum = sum + i;
+  }
+  sum = sum + 100;
+}
+assert_eq(sum, 409);
+
+// labeled break leaves the loop from the switch
+var j = 0;
+outer: while (true) {
+  j = j + 1;
+  switch (j) {
+    case 3:
+      break outer;
+  }
+}
+assert_eq(j, 3);
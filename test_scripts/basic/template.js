@@ -0,0 +1,19 @@
+var name = "world";
+assert_eq(`hello`, "hello");
+assert_eq(`hello ${name}!`, "hello world!");
+assert_eq(`${name}`, "world");
+assert_eq(`${1 + 2} = ${3}`, "3 = 3");
+assert_eq(`${true}, ${null}, ${undefined}`, "true, null, undefined");
+
+// substitution could contain any expression, including nested templates
+var obj = { value: 5 };
+assert_eq(`value: ${obj.value > 2 ? `big ${obj.value}` : "small"}`, "value: big 5");
+assert_eq(`${{ a: 1 }.a}`, "1");
+
+// multi-line content is kept as is
+var text = `line 1
+line 2`;
+assert_eq(text, "line 1" + `
+` + "line 2");
+assert_eq(`a` + `b`, "ab");
+assert_eq(`\${name} \``, "${name} `");
@@ -0,0 +1,51 @@
+var a = 5;
+assert_eq(-a, -5);
+assert_eq(-(a + 1), -6);
+assert_eq(- -a, 5);
+assert_eq(1 - -a, 6);
+assert_eq(-Infinity, 0 - Infinity);
+assert_eq(-NaN, NaN);
+assert_eq(-true, -1);
+assert_eq(-null, 0);
+assert_eq(-undefined, NaN);
+assert_eq(-"5", -5);
+assert_eq(-" 1.5 ", -1.5);
+assert_eq(-"", 0);
+assert_eq(-"abc", NaN);
+
+// unary plus converts to a number
+assert_eq(+a, 5);
+assert_eq(+true, 1);
+assert_eq(+false, 0);
+assert_eq(+null, 0);
+assert_eq(+undefined, NaN);
+assert_eq(+"5", 5);
+assert_eq(+"0x10", 16);
+assert_eq(+"", 0);
+assert_eq(+"abc", NaN);
+
+assert_eq(typeof undefined, "undefined");
+assert_eq(typeof null, "object");
+assert_eq(typeof 1, "number");
+assert_eq(typeof NaN, "number");
+assert_eq(typeof true, "boolean");
+assert_eq(typeof "str", "string");
+assert_eq(typeof {}, "object");
+assert_eq(typeof [1, 2], "object");
+assert_eq(typeof function () {}, "function");
+assert_eq(typeof a, "number");
+assert_eq(typeof -a, "number");
+assert_eq(typeof typeof a, "string");
+assert_eq(typeof a == "number", true);
+// undeclared variable is not an error
+assert_eq(typeof undeclared, "undefined");
+
+// the operand is evaluated, the result is undefined
+var calls = 0;
+function count() {
+  calls = calls + 1;
+  return calls;
+}
+assert_eq(void 0, undefined);
+assert_eq(void count(), undefined);
+assert_eq(calls, 1);
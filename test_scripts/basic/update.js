@@ -0,0 +1,98 @@
+var a = 10;
+a += 5;
+assert_eq(a, 15);
+a -= 3;
+assert_eq(a, 12);
+a *= 2;
+assert_eq(a, 24);
+a /= 4;
+assert_eq(a, 6);
+a += 1 + 2 * 3;
+assert_eq(a, 13);
+
+var s = "Hello";
+s += " world";
+assert_eq(s, "Hello world");
+
+// prefix returns the updated value, postfix returns the old one
+var i = 1;
+assert_eq(i++, 1);
+assert_eq(i, 2);
+assert_eq(++i, 3);
+assert_eq(i, 3);
+assert_eq(i--, 3);
+assert_eq(i, 2);
+assert_eq(--i, 1);
+assert_eq(i, 1);
+assert_eq(i++ + ++i, 4);
+assert_eq(i, 3);
+
+// the old value is converted to a number
+var u;
+assert_eq(u++, NaN);
+var t = true;
+assert_eq(t++, 1);
+assert_eq(t, 2);
+
+var sum = 0;
+for (var j = 0; j < 5; j++) {
+  sum += j;
+}
+assert_eq(sum, 10);
+
+// member expressions
+var arr = [1, 2, 3];
+arr[0] += 10;
+assert_eq(arr[0], 11);
+arr[1]++;
+assert_eq(arr[1], 3);
+var obj = { x: 1 };
+obj.x *= 5;
+assert_eq(obj.x, 5);
+assert_eq(--obj.x, 4);
+
+// the object and the key are evaluated only once
+var calls = 0;
+function index() {
+  calls = calls + 1;
+  return 2;
+}
+arr[index()] += 1;
+assert_eq(arr[2], 4);
+assert_eq(calls, 1);
+arr[index()]++;
+assert_eq(arr[2], 5);
+assert_eq(calls, 2);
+
+// logical assignments evaluate the right side only when it is assigned
+function count(value) {
+  calls = calls + 1;
+  return value;
+}
+calls = 0;
+var b = 1;
+b &&= count(2);
+assert_eq(b, 2);
+b ||= count(3);
+assert_eq(b, 2);
+b ??= count(4);
+assert_eq(b, 2);
+assert_eq(calls, 1);
+
+var c = 0;
+c &&= count(5);
+assert_eq(c, 0);
+c ||= count(6);
+assert_eq(c, 6);
+assert_eq(calls, 2);
+
+var d = null;
+d ??= count(7);
+assert_eq(d, 7);
+obj.y ??= 8;
+assert_eq(obj.y, 8);
+obj.y ??= 9;
+assert_eq(obj.y, 8);
+obj.x ||= 10;
+assert_eq(obj.x, 4);
+assert_eq(calls, 3);
@@ -19,3 +19,11 @@ assert_eq(true && "foo", "foo");
 
 assert_eq(true && 1 && "foo", "foo");
 assert_eq(0 && false && "", 0);
+
+// right operand is evaluated only if the left one is truthy
+assert_eq(false && assert(false), false);
+assert_eq(0 && assert(false), 0);
+var obj = null;
+assert_eq(obj && obj.field, null);
+obj = { field: 5 };
+assert_eq(obj && obj.field, 5);
@@ -21,3 +21,9 @@ assert_eq(true || "foo", true);
 
 assert_eq(true || 1 || "foo", true);
 assert_eq(0 || false || "foo", "foo");
+
+// right operand is evaluated only if the left one is falsy
+assert_eq(true || assert(false), true);
+assert_eq("foo" || assert(false), "foo");
+var obj = { field: 5 };
+assert_eq(obj.field || assert(false), 5);
@@ -50,3 +50,76 @@ fn array_test() {
 fn comments_test() {
     run_test("../test_scripts/basic/comments.js", "comments");
 }
+
+#[test]
+fn closures_test() {
+    run_test("../test_scripts/basic/closures.js", "closures");
+}
+
+#[test]
+fn function_expressions_test() {
+    run_test(
+        "../test_scripts/basic/function_expressions.js",
+        "function_expressions",
+    );
+}
+
+#[test]
+fn exceptions_test() {
+    run_test("../test_scripts/basic/exceptions.js", "exceptions");
+}
+
+#[test]
+fn switch_test() {
+    run_test("../test_scripts/basic/switch.js", "switch");
+}
+
+#[test]
+fn conditional_test() {
+    run_test("../test_scripts/basic/conditional.js", "conditional");
+}
+
+#[test]
+fn equality_test() {
+    run_test("../test_scripts/basic/equality.js", "equality");
+}
+
+#[test]
+fn bitwise_test() {
+    run_test("../test_scripts/basic/bitwise.js", "bitwise");
+}
+
+#[test]
+fn update_test() {
+    run_test("../test_scripts/basic/update.js", "update");
+}
+
+#[test]
+fn unary_test() {
+    run_test("../test_scripts/basic/unary.js", "unary");
+}
+
+#[test]
+fn const_test() {
+    run_test("../test_scripts/basic/const.js", "const");
+}
+
+#[test]
+fn scoping_test() {
+    run_test("../test_scripts/basic/scoping.js", "scoping");
+}
+
+#[test]
+fn template_test() {
+    run_test("../test_scripts/basic/template.js", "template");
+}
+
+#[test]
+fn strings_test() {
+    run_test("../test_scripts/basic/strings.js", "strings");
+}
+
+#[test]
+fn numbers_test() {
+    run_test("../test_scripts/basic/numbers.js", "numbers");
+}
@@ -41,6 +41,7 @@ impl CompileSuite {
     pub fn compile(&self) -> Result<(), String> {
         let out = Command::new("../target/debug/jsc")
             .args([
+                "build".to_string(),
                 format!("--input={}", &self.source_code_path),
                 format!("--binary-name={}", &self.binary_out_file),
                 "--clean".to_string(),